@@ -0,0 +1,18 @@
+use anchor_lang::prelude::Pubkey;
+
+/// Creator authority PDA of the owner for the pool. Each owner has an isolated creator authority per pool.
+pub fn derive_creator_authority(owner: Pubkey, pool: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"creator", owner.as_ref(), pool.as_ref()],
+        &cpi_example::ID,
+    )
+}
+
+pub fn derive_program_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], &cpi_example::ID)
+}
+
+/// Event authority of the CPI example program. Signs the self CPI carrying the events.
+pub fn derive_event_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"__event_authority"], &cpi_example::ID)
+}
@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::pubkey;
 use lazy_static::lazy_static;
-use solana_sdk::pubkey;
-use solana_sdk::pubkey::Pubkey;
 
 lazy_static! {
     pub static ref POOL_WITH_NON_PDA_BASED_LP_MINT: HashMap<Pubkey, Pubkey> = HashMap::from_iter([
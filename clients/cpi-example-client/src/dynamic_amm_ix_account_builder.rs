@@ -1,11 +1,10 @@
-#![allow(dead_code)]
-use super::dynamic_amm_pda::*;
-use super::dynamic_vault_pda::*;
+use crate::dynamic_amm_pda::*;
+use crate::dynamic_vault_pda::*;
+use anchor_lang::prelude::Pubkey;
 use anchor_spl::associated_token::get_associated_token_address;
 use cpi_example::dynamic_amm::types::DepegType;
 use cpi_example::dynamic_amm::types::{Depeg, TokenMultiplier};
 use cpi_example::dynamic_vault;
-use solana_sdk::pubkey::Pubkey;
 
 pub enum CurveTypeIx {
     ConstantProduct,
@@ -62,10 +61,10 @@ pub fn get_or_derive_initialize_pool_related_keys(
     let vault_a_token_vault = derive_token_vault_key(vault_a_key);
     let vault_b_token_vault = derive_token_vault_key(vault_b_key);
 
-    let vault_a_lp_mint = super::dynamic_vault_pda::derive_lp_mint_key(vault_a_key);
-    let vault_b_lp_mint = super::dynamic_vault_pda::derive_lp_mint_key(vault_b_key);
+    let vault_a_lp_mint = crate::dynamic_vault_pda::derive_lp_mint_key(vault_a_key);
+    let vault_b_lp_mint = crate::dynamic_vault_pda::derive_lp_mint_key(vault_b_key);
 
-    let lp_mint = super::dynamic_amm_pda::derive_lp_mint_key(pool_key);
+    let lp_mint = crate::dynamic_amm_pda::derive_lp_mint_key(pool_key);
 
     let protocol_token_a_fee = derive_protocol_fee_key(token_a_mint, pool_key);
     let protocol_token_b_fee = derive_protocol_fee_key(token_b_mint, pool_key);
@@ -135,38 +134,35 @@ impl IxAccountBuilder {
             payer_token_b,
         } = get_or_derive_initialize_pool_related_keys(pool_key, token_a_mint, token_b_mint, payer);
 
-        let accounts =
-            cpi_example::dynamic_amm::client::accounts::InitializePermissionlessPoolWithFeeTier {
-                pool: pool_key,
-                token_a_mint,
-                token_b_mint,
-                lp_mint,
-                a_vault: vault_a,
-                a_token_vault: vault_a_token_vault,
-                a_vault_lp: vault_a_lp,
-                a_vault_lp_mint: vault_a_lp_mint,
-                b_vault: vault_b,
-                b_token_vault: vault_b_token_vault,
-                b_vault_lp: vault_b_lp,
-                b_vault_lp_mint: vault_b_lp_mint,
-                protocol_token_a_fee,
-                protocol_token_b_fee,
-                mint_metadata,
-                payer_token_a,
-                payer_pool_lp,
-                payer_token_b,
-                payer,
-                // Deprecated field
-                fee_owner: payer,
-                vault_program: dynamic_vault::ID,
-                metadata_program: METAPLEX_PROGRAM_ID,
-                rent: solana_sdk::sysvar::rent::ID,
-                associated_token_program: anchor_spl::associated_token::ID,
-                system_program: solana_sdk::system_program::ID,
-                token_program: anchor_spl::token::ID,
-            };
-
-        accounts
+        cpi_example::dynamic_amm::client::accounts::InitializePermissionlessPoolWithFeeTier {
+            pool: pool_key,
+            token_a_mint,
+            token_b_mint,
+            lp_mint,
+            a_vault: vault_a,
+            a_token_vault: vault_a_token_vault,
+            a_vault_lp: vault_a_lp,
+            a_vault_lp_mint: vault_a_lp_mint,
+            b_vault: vault_b,
+            b_token_vault: vault_b_token_vault,
+            b_vault_lp: vault_b_lp,
+            b_vault_lp_mint: vault_b_lp_mint,
+            protocol_token_a_fee,
+            protocol_token_b_fee,
+            mint_metadata,
+            payer_token_a,
+            payer_pool_lp,
+            payer_token_b,
+            payer,
+            // Deprecated field
+            fee_owner: payer,
+            vault_program: dynamic_vault::ID,
+            metadata_program: METAPLEX_PROGRAM_ID,
+            rent: anchor_lang::solana_program::sysvar::rent::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: anchor_lang::system_program::ID,
+            token_program: anchor_spl::token::ID,
+        }
     }
 
     pub fn initialize_permissionless_pool_accounts(
@@ -196,7 +192,7 @@ impl IxAccountBuilder {
             payer_token_b,
         } = get_or_derive_initialize_pool_related_keys(pool_key, token_a_mint, token_b_mint, payer);
 
-        let accounts = cpi_example::dynamic_amm::client::accounts::InitializePermissionlessPool {
+        cpi_example::dynamic_amm::client::accounts::InitializePermissionlessPool {
             pool: pool_key,
             token_a_mint,
             token_b_mint,
@@ -220,13 +216,11 @@ impl IxAccountBuilder {
             fee_owner: payer,
             vault_program: dynamic_vault::ID,
             metadata_program: METAPLEX_PROGRAM_ID,
-            rent: solana_sdk::sysvar::rent::ID,
+            rent: anchor_lang::solana_program::sysvar::rent::ID,
             associated_token_program: anchor_spl::associated_token::ID,
-            system_program: solana_sdk::system_program::ID,
+            system_program: anchor_lang::system_program::ID,
             token_program: anchor_spl::token::ID,
-        };
-
-        accounts
+        }
     }
 
     pub fn initialize_permissionless_constant_product_pool_with_config_accounts(
@@ -259,8 +253,7 @@ impl IxAccountBuilder {
             payer_token_b,
         } = get_or_derive_initialize_pool_related_keys(pool_key, token_a_mint, token_b_mint, payer);
 
-        let accounts =
-            cpi_example::dynamic_amm::client::accounts::InitializePermissionlessConstantProductPoolWithConfig {
+        cpi_example::dynamic_amm::client::accounts::InitializePermissionlessConstantProductPoolWithConfig {
                 pool: pool_key,
                 token_a_mint,
                 token_b_mint,
@@ -283,13 +276,11 @@ impl IxAccountBuilder {
                 config,
                 vault_program: dynamic_vault::ID,
                 metadata_program: METAPLEX_PROGRAM_ID,
-                rent: solana_sdk::sysvar::rent::ID,
+                rent: anchor_lang::solana_program::sysvar::rent::ID,
                 associated_token_program: anchor_spl::associated_token::ID,
-                system_program: solana_sdk::system_program::ID,
+                system_program: anchor_lang::system_program::ID,
                 token_program: anchor_spl::token::ID,
-            };
-
-        accounts
+            }
     }
 
     pub fn initialize_customizable_permissionless_constant_product_pool(
@@ -320,8 +311,7 @@ impl IxAccountBuilder {
             payer_token_b,
         } = get_or_derive_initialize_pool_related_keys(pool_key, token_a_mint, token_b_mint, payer);
 
-        let accounts =
-            cpi_example::dynamic_amm::client::accounts::InitializeCustomizablePermissionlessConstantProductPool {
+        cpi_example::dynamic_amm::client::accounts::InitializeCustomizablePermissionlessConstantProductPool {
                 pool: pool_key,
                 token_a_mint,
                 token_b_mint,
@@ -343,13 +333,11 @@ impl IxAccountBuilder {
                 payer,
                 vault_program: dynamic_vault::ID,
                 metadata_program: METAPLEX_PROGRAM_ID,
-                rent: solana_sdk::sysvar::rent::ID,
+                rent: anchor_lang::solana_program::sysvar::rent::ID,
                 // Deprecated field
                 associated_token_program: anchor_spl::associated_token::ID,
-                system_program: solana_sdk::system_program::ID,
+                system_program: anchor_lang::system_program::ID,
                 token_program: anchor_spl::token::ID,
-            };
-
-        accounts
+            }
     }
 }
@@ -1,12 +1,11 @@
-#![allow(dead_code)]
-use super::dynamic_amm_aux_lp_mint::*;
+use crate::dynamic_amm_aux_lp_mint::*;
+use anchor_lang::prelude::Pubkey;
 use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::MAX_FEE_BASIS_POINTS;
 use cpi_example::dynamic_amm::types::CurveType;
 use cpi_example::dynamic_amm::types::PoolFees;
-use solana_sdk::pubkey::Pubkey;
 
 pub const METAPLEX_PROGRAM_ID: Pubkey =
-    solana_sdk::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+    anchor_lang::solana_program::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
 
 /// get first key, this is same as max(key1, key2)
 fn get_first_key(key1: Pubkey, key2: Pubkey) -> Pubkey {
@@ -25,7 +24,7 @@ fn get_second_key(key1: Pubkey, key2: Pubkey) -> Pubkey {
 
 fn get_curve_type(curve_type: CurveType) -> u8 {
     match curve_type {
-        CurveType::ConstantProduct {} => 0,
+        CurveType::ConstantProduct => 0,
         _ => 1,
     }
 }
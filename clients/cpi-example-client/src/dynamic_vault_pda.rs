@@ -1,6 +1,6 @@
-use super::dynamic_vault_aux_lp_mint::*;
-use solana_sdk::pubkey;
-use solana_sdk::pubkey::Pubkey;
+use crate::dynamic_vault_aux_lp_mint::*;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::pubkey;
 
 const VAULT_BASE_ADDRESS: Pubkey = pubkey!("HWzXGcGHy4tcpYfaRDCyLNzXqBTv3E6BttpCH2vJxArv");
 
@@ -0,0 +1,59 @@
+//! Decoder of the events emitted by the CPI example program. Events are emitted through a self CPI signed by the event
+//! authority, so they are carried by the inner instructions of the transaction instead of the logs.
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::CompiledInstruction;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use cpi_example::events::*;
+
+/// Event emitted by the CPI example program
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CpiExampleEvent {
+    Swap(EvtSwap),
+    InitializeLbPair(EvtInitializeLbPair),
+    InitializeDynamicAmmPool(EvtInitializeDynamicAmmPool),
+    LockLiquidity(EvtLockLiquidity),
+    ClaimFee(EvtClaimFee),
+    InitializeM3m3Vault(EvtInitializeM3m3Vault),
+    AddLiquidity(EvtAddLiquidity),
+    RemoveLiquidity(EvtRemoveLiquidity),
+}
+
+fn decode_as<T: AnchorDeserialize + Discriminator>(data: &[u8]) -> Option<T> {
+    let mut event_data = data.strip_prefix(T::DISCRIMINATOR)?;
+    T::deserialize(&mut event_data).ok()
+}
+
+impl CpiExampleEvent {
+    /// Decode the data of a self CPI instruction of the CPI example program. Returns `None` when the data is not an event.
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        let data = data.strip_prefix(EVENT_IX_TAG_LE)?;
+
+        decode_as(data)
+            .map(Self::Swap)
+            .or_else(|| decode_as(data).map(Self::InitializeLbPair))
+            .or_else(|| decode_as(data).map(Self::InitializeDynamicAmmPool))
+            .or_else(|| decode_as(data).map(Self::LockLiquidity))
+            .or_else(|| decode_as(data).map(Self::ClaimFee))
+            .or_else(|| decode_as(data).map(Self::InitializeM3m3Vault))
+            .or_else(|| decode_as(data).map(Self::AddLiquidity))
+            .or_else(|| decode_as(data).map(Self::RemoveLiquidity))
+    }
+}
+
+/// Decode the events of the CPI example program from the inner instructions of a transaction, in emission order.
+///
+/// `account_keys` are the account keys of the transaction message, followed by the keys loaded from the address lookup
+/// tables, which resolve the program of the compiled inner instructions.
+pub fn decode_events<'a>(
+    account_keys: &[Pubkey],
+    inner_instructions: impl IntoIterator<Item = &'a CompiledInstruction>,
+) -> Vec<CpiExampleEvent> {
+    inner_instructions
+        .into_iter()
+        .filter(|instruction| {
+            account_keys.get(usize::from(instruction.program_id_index)) == Some(&cpi_example::ID)
+        })
+        .filter_map(|instruction| CpiExampleEvent::decode(&instruction.data))
+        .collect()
+}
@@ -0,0 +1,90 @@
+use crate::cpi_example_pda::{derive_creator_authority, derive_program_config};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::bpf_loader_upgradeable::get_program_data_address;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+
+/// Initialize the program config. The upgrade authority of the program pays for the config.
+pub fn initialize_config(upgrade_authority: Pubkey, admin: Pubkey) -> Instruction {
+    let accounts = cpi_example::accounts::InitializeConfig {
+        program_config: derive_program_config().0,
+        program_data: get_program_data_address(&cpi_example::ID),
+        upgrade_authority,
+        system_program: anchor_lang::system_program::ID,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::InitializeConfig { admin }.data(),
+        accounts,
+    }
+}
+
+/// Propose `new_admin` as the admin of the program config
+pub fn propose_admin(admin: Pubkey, new_admin: Pubkey) -> Instruction {
+    let accounts = cpi_example::accounts::ProposeAdmin {
+        program_config: derive_program_config().0,
+        admin,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::ProposeAdmin { new_admin }.data(),
+        accounts,
+    }
+}
+
+/// Accept the admin transfer of the program config
+pub fn accept_admin(pending_admin: Pubkey) -> Instruction {
+    let accounts = cpi_example::accounts::AcceptAdmin {
+        program_config: derive_program_config().0,
+        pending_admin,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::AcceptAdmin {}.data(),
+        accounts,
+    }
+}
+
+/// Sweep the lamports of the creator authority PDA of the owner for the pool above the rent exempt minimum to `destination`.
+/// With `token` as (mint, destination token account), also sweep the creator authority associated token account of the mint.
+pub fn sweep_creator_authority(
+    owner: Pubkey,
+    pool: Pubkey,
+    cpi_example_admin: Pubkey,
+    destination: Pubkey,
+    token: Option<(Pubkey, Pubkey)>,
+    close_creator_token: bool,
+) -> Instruction {
+    let (creator_authority, _bump) = derive_creator_authority(owner, pool);
+
+    let accounts = cpi_example::accounts::SweepCreatorAuthority {
+        creator_authority,
+        owner,
+        pool,
+        creator_token: token
+            .map(|(mint, _)| get_associated_token_address(&creator_authority, &mint)),
+        destination_token: token.map(|(_, destination_token)| destination_token),
+        destination,
+        program_config: derive_program_config().0,
+        cpi_example_admin,
+        token_program: anchor_spl::token::ID,
+        system_program: anchor_lang::system_program::ID,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::SweepCreatorAuthority {
+            close_creator_token,
+        }
+        .data(),
+        accounts,
+    }
+}
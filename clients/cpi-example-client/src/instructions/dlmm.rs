@@ -0,0 +1,615 @@
+use crate::cpi_example_pda::{
+    derive_creator_authority, derive_event_authority, derive_program_config,
+};
+use crate::dlmm_pda::*;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use cpi_example::dlmm;
+use cpi_example::dlmm::accounts::LbPair;
+use cpi_example::dlmm::types::{
+    AddLiquiditySingleSidePreciseParameter, CustomizableParams, LiquidityParameterByStrategy,
+    LiquidityParameterByStrategyOneSide,
+};
+use cpi_example::dlmm_utils::{bin_id_to_bin_array_index, position_upper_bin_id};
+use cpi_example::state::IntegratorFee;
+
+/// Accounts of a DLMM pool required by the CPI example instructions
+pub struct DlmmPoolKeys {
+    pub lb_pair: Pubkey,
+    pub token_x_mint: Pubkey,
+    pub token_y_mint: Pubkey,
+    pub reserve_x: Pubkey,
+    pub reserve_y: Pubkey,
+    pub oracle: Pubkey,
+    pub bin_array_bitmap_extension: Option<Pubkey>,
+}
+
+impl DlmmPoolKeys {
+    /// Keys of an existing pool
+    pub fn new(lb_pair: Pubkey, pool_state: &LbPair) -> Self {
+        Self {
+            lb_pair,
+            token_x_mint: pool_state.token_x_mint,
+            token_y_mint: pool_state.token_y_mint,
+            reserve_x: pool_state.reserve_x,
+            reserve_y: pool_state.reserve_y,
+            oracle: pool_state.oracle,
+            bin_array_bitmap_extension: None,
+        }
+    }
+
+    /// Keys of the customizable permissionless pool of the token pair
+    pub fn derive_customizable_permissionless(token_x_mint: Pubkey, token_y_mint: Pubkey) -> Self {
+        let (lb_pair, _bump) =
+            derive_customizable_permissionless_lb_pair(token_x_mint, token_y_mint);
+
+        Self {
+            lb_pair,
+            token_x_mint,
+            token_y_mint,
+            reserve_x: derive_reserve_pda(token_x_mint, lb_pair).0,
+            reserve_y: derive_reserve_pda(token_y_mint, lb_pair).0,
+            oracle: derive_oracle_pda(lb_pair).0,
+            bin_array_bitmap_extension: None,
+        }
+    }
+}
+
+/// Accounts of a DLMM position owned by the creator authority PDA
+pub struct DlmmPositionKeys {
+    pub lb_pair: Pubkey,
+    pub lower_bin_id: i32,
+    pub width: i32,
+    pub owner: Pubkey,
+    pub creator_authority: Pubkey,
+    pub position: Pubkey,
+    pub bin_array_lower: Pubkey,
+    pub bin_array_upper: Pubkey,
+}
+
+impl DlmmPositionKeys {
+    pub fn derive_pda_creator(
+        lb_pair: Pubkey,
+        owner: Pubkey,
+        lower_bin_id: i32,
+        width: i32,
+    ) -> Self {
+        let (creator_authority, _bump) = derive_creator_authority(owner, lb_pair);
+        let (position, _bump) =
+            derive_position_pda(lb_pair, creator_authority, lower_bin_id, width);
+
+        let (bin_array_lower, _bump) =
+            derive_bin_array_pda(lb_pair, bin_id_to_bin_array_index(lower_bin_id));
+        let (bin_array_upper, _bump) = derive_bin_array_pda(
+            lb_pair,
+            bin_id_to_bin_array_index(position_upper_bin_id(lower_bin_id, width)),
+        );
+
+        Self {
+            lb_pair,
+            lower_bin_id,
+            width,
+            owner,
+            creator_authority,
+            position,
+            bin_array_lower,
+            bin_array_upper,
+        }
+    }
+}
+
+fn swap_accounts(
+    pool: &DlmmPoolKeys,
+    user: Pubkey,
+    user_token_in: Pubkey,
+    user_token_out: Pubkey,
+    integrator_fee_token: Option<Pubkey>,
+    bin_arrays: &[Pubkey],
+) -> Vec<AccountMeta> {
+    let mut accounts = cpi_example::accounts::DlmmSwap {
+        lb_pair: pool.lb_pair,
+        bin_array_bitmap_extension: pool.bin_array_bitmap_extension,
+        reserve_x: pool.reserve_x,
+        reserve_y: pool.reserve_y,
+        user_token_in,
+        user_token_out,
+        token_x_mint: pool.token_x_mint,
+        token_y_mint: pool.token_y_mint,
+        oracle: pool.oracle,
+        host_fee_in: None,
+        integrator_fee_token,
+        user,
+        dlmm_program: dlmm::ID,
+        dlmm_event_authority: derive_event_authority_pda().0,
+        token_x_program: anchor_spl::token::ID,
+        token_y_program: anchor_spl::token::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None);
+
+    // Bin arrays must be ordered by the swap direction, starting from the active bin array
+    accounts.extend(
+        bin_arrays
+            .iter()
+            .map(|bin_array| AccountMeta::new(*bin_array, false)),
+    );
+
+    accounts
+}
+
+/// `integrator_fee` is the integrator fee, and the associated token account of the integrator for the charged mint.
+#[allow(clippy::too_many_arguments)]
+pub fn dlmm_swap(
+    pool: &DlmmPoolKeys,
+    user: Pubkey,
+    user_token_in: Pubkey,
+    user_token_out: Pubkey,
+    bin_arrays: &[Pubkey],
+    amount_in: u64,
+    min_amount_out: u64,
+    integrator_fee: Option<(IntegratorFee, Pubkey)>,
+) -> Instruction {
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DlmmSwap {
+            amount_in,
+            min_amount_out,
+            integrator_fee: integrator_fee.map(|(fee, _)| fee),
+        }
+        .data(),
+        accounts: swap_accounts(
+            pool,
+            user,
+            user_token_in,
+            user_token_out,
+            integrator_fee.map(|(_, fee_token)| fee_token),
+            bin_arrays,
+        ),
+    }
+}
+
+pub fn dlmm_swap_exact_out(
+    pool: &DlmmPoolKeys,
+    user: Pubkey,
+    user_token_in: Pubkey,
+    user_token_out: Pubkey,
+    bin_arrays: &[Pubkey],
+    max_in_amount: u64,
+    out_amount: u64,
+) -> Instruction {
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DlmmSwapExactOut {
+            max_in_amount,
+            out_amount,
+        }
+        .data(),
+        accounts: swap_accounts(pool, user, user_token_in, user_token_out, None, bin_arrays),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn dlmm_swap_with_price_impact(
+    pool: &DlmmPoolKeys,
+    user: Pubkey,
+    user_token_in: Pubkey,
+    user_token_out: Pubkey,
+    bin_arrays: &[Pubkey],
+    amount_in: u64,
+    active_id: Option<i32>,
+    max_price_impact_bps: u16,
+) -> Instruction {
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DlmmSwapWithPriceImpact {
+            amount_in,
+            active_id,
+            max_price_impact_bps,
+        }
+        .data(),
+        accounts: swap_accounts(pool, user, user_token_in, user_token_out, None, bin_arrays),
+    }
+}
+
+/// The payer funds the creator authority PDA with token X of the payer associated token account
+pub fn dlmm_initialize_customizable_permissionless_lb_pair_pda_creator(
+    pool: &DlmmPoolKeys,
+    owner: Pubkey,
+    payer: Pubkey,
+    params: CustomizableParams,
+) -> Instruction {
+    let (creator_authority, _bump) = derive_creator_authority(owner, pool.lb_pair);
+    let (bin_array, _bump) =
+        derive_bin_array_pda(pool.lb_pair, bin_id_to_bin_array_index(params.active_id));
+
+    let accounts =
+        cpi_example::accounts::DlmmInitializeCustomizablePermissionlessLbPairPdaCreator {
+            program_config: derive_program_config().0,
+            creator_authority,
+            owner,
+            creator_token_x: get_associated_token_address(&creator_authority, &pool.token_x_mint),
+            payer_token_x: get_associated_token_address(&payer, &pool.token_x_mint),
+            lb_pair: pool.lb_pair,
+            bin_array_bitmap_extension: pool.bin_array_bitmap_extension,
+            token_mint_x: pool.token_x_mint,
+            token_mint_y: pool.token_y_mint,
+            reserve_x: pool.reserve_x,
+            reserve_y: pool.reserve_y,
+            oracle: pool.oracle,
+            bin_array,
+            payer,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: anchor_lang::system_program::ID,
+            rent: anchor_lang::solana_program::sysvar::rent::ID,
+            dlmm_program: dlmm::ID,
+            dlmm_event_authority: derive_event_authority_pda().0,
+            event_authority: derive_event_authority().0,
+            program: cpi_example::ID,
+        }
+        .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DlmmInitializeCustomizablePermissionlessLbPairPdaCreator {
+            params,
+        }
+        .data(),
+        accounts,
+    }
+}
+
+pub fn dlmm_initialize_position_pda_creator(
+    position: &DlmmPositionKeys,
+    payer: Pubkey,
+) -> Instruction {
+    let accounts = cpi_example::accounts::DlmmInitializePositionPdaCreator {
+        program_config: derive_program_config().0,
+        creator_authority: position.creator_authority,
+        owner: position.owner,
+        position: position.position,
+        lb_pair: position.lb_pair,
+        payer,
+        system_program: anchor_lang::system_program::ID,
+        rent: anchor_lang::solana_program::sysvar::rent::ID,
+        dlmm_program: dlmm::ID,
+        dlmm_event_authority: derive_event_authority_pda().0,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DlmmInitializePositionPdaCreator {
+            lower_bin_id: position.lower_bin_id,
+            width: position.width,
+        }
+        .data(),
+        accounts,
+    }
+}
+
+/// The payer funds the creator authority PDA with the payer associated token accounts
+pub fn dlmm_add_liquidity_by_strategy_pda_creator(
+    pool: &DlmmPoolKeys,
+    position: &DlmmPositionKeys,
+    payer: Pubkey,
+    liquidity_parameter: LiquidityParameterByStrategy,
+) -> Instruction {
+    let accounts = cpi_example::accounts::DlmmAddLiquidityByStrategyPdaCreator {
+        program_config: derive_program_config().0,
+        creator_authority: position.creator_authority,
+        owner: position.owner,
+        creator_token_x: get_associated_token_address(
+            &position.creator_authority,
+            &pool.token_x_mint,
+        ),
+        creator_token_y: get_associated_token_address(
+            &position.creator_authority,
+            &pool.token_y_mint,
+        ),
+        payer_token_x: get_associated_token_address(&payer, &pool.token_x_mint),
+        payer_token_y: get_associated_token_address(&payer, &pool.token_y_mint),
+        position: position.position,
+        lb_pair: pool.lb_pair,
+        bin_array_bitmap_extension: pool.bin_array_bitmap_extension,
+        reserve_x: pool.reserve_x,
+        reserve_y: pool.reserve_y,
+        token_x_mint: pool.token_x_mint,
+        token_y_mint: pool.token_y_mint,
+        bin_array_lower: position.bin_array_lower,
+        bin_array_upper: position.bin_array_upper,
+        payer,
+        token_program: anchor_spl::token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        system_program: anchor_lang::system_program::ID,
+        dlmm_program: dlmm::ID,
+        dlmm_event_authority: derive_event_authority_pda().0,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DlmmAddLiquidityByStrategyPdaCreator {
+            lower_bin_id: position.lower_bin_id,
+            width: position.width,
+            liquidity_parameter,
+        }
+        .data(),
+        accounts,
+    }
+}
+
+fn add_liquidity_one_side_accounts(
+    pool: &DlmmPoolKeys,
+    position: &DlmmPositionKeys,
+    payer: Pubkey,
+    token_mint: Pubkey,
+) -> Vec<AccountMeta> {
+    let reserve = if token_mint == pool.token_x_mint {
+        pool.reserve_x
+    } else {
+        pool.reserve_y
+    };
+
+    cpi_example::accounts::DlmmAddLiquidityOneSidePdaCreator {
+        program_config: derive_program_config().0,
+        creator_authority: position.creator_authority,
+        owner: position.owner,
+        creator_token: get_associated_token_address(&position.creator_authority, &token_mint),
+        payer_token: get_associated_token_address(&payer, &token_mint),
+        position: position.position,
+        lb_pair: pool.lb_pair,
+        bin_array_bitmap_extension: pool.bin_array_bitmap_extension,
+        reserve,
+        token_mint,
+        bin_array_lower: position.bin_array_lower,
+        bin_array_upper: position.bin_array_upper,
+        payer,
+        token_program: anchor_spl::token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        system_program: anchor_lang::system_program::ID,
+        dlmm_program: dlmm::ID,
+        dlmm_event_authority: derive_event_authority_pda().0,
+    }
+    .to_account_metas(None)
+}
+
+/// Token X deposits above the active bin, token Y deposits below the active bin
+pub fn dlmm_add_liquidity_by_strategy_one_side_pda_creator(
+    pool: &DlmmPoolKeys,
+    position: &DlmmPositionKeys,
+    payer: Pubkey,
+    token_mint: Pubkey,
+    liquidity_parameter: LiquidityParameterByStrategyOneSide,
+) -> Instruction {
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DlmmAddLiquidityByStrategyOneSidePdaCreator {
+            lower_bin_id: position.lower_bin_id,
+            width: position.width,
+            liquidity_parameter,
+        }
+        .data(),
+        accounts: add_liquidity_one_side_accounts(pool, position, payer, token_mint),
+    }
+}
+
+/// Token X deposits above the active bin, token Y deposits below the active bin
+pub fn dlmm_add_liquidity_one_side_precise_pda_creator(
+    pool: &DlmmPoolKeys,
+    position: &DlmmPositionKeys,
+    payer: Pubkey,
+    token_mint: Pubkey,
+    parameter: AddLiquiditySingleSidePreciseParameter,
+) -> Instruction {
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DlmmAddLiquidityOneSidePrecisePdaCreator {
+            lower_bin_id: position.lower_bin_id,
+            width: position.width,
+            parameter,
+        }
+        .data(),
+        accounts: add_liquidity_one_side_accounts(pool, position, payer, token_mint),
+    }
+}
+
+fn remove_liquidity_accounts(
+    pool: &DlmmPoolKeys,
+    position: &DlmmPositionKeys,
+    payer: Pubkey,
+    receiver_token_x: Pubkey,
+    receiver_token_y: Pubkey,
+) -> Vec<AccountMeta> {
+    cpi_example::accounts::DlmmRemoveLiquidityPdaCreator {
+        program_config: derive_program_config().0,
+        creator_authority: position.creator_authority,
+        owner: position.owner,
+        position: position.position,
+        lb_pair: pool.lb_pair,
+        bin_array_bitmap_extension: pool.bin_array_bitmap_extension,
+        receiver_token_x,
+        receiver_token_y,
+        reserve_x: pool.reserve_x,
+        reserve_y: pool.reserve_y,
+        token_x_mint: pool.token_x_mint,
+        token_y_mint: pool.token_y_mint,
+        bin_array_lower: position.bin_array_lower,
+        bin_array_upper: position.bin_array_upper,
+        payer,
+        token_program: anchor_spl::token::ID,
+        dlmm_program: dlmm::ID,
+        dlmm_event_authority: derive_event_authority_pda().0,
+    }
+    .to_account_metas(None)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn dlmm_remove_liquidity_by_range_pda_creator(
+    pool: &DlmmPoolKeys,
+    position: &DlmmPositionKeys,
+    payer: Pubkey,
+    receiver_token_x: Pubkey,
+    receiver_token_y: Pubkey,
+    from_bin_id: i32,
+    to_bin_id: i32,
+    bps_to_remove: u16,
+) -> Instruction {
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DlmmRemoveLiquidityByRangePdaCreator {
+            lower_bin_id: position.lower_bin_id,
+            width: position.width,
+            from_bin_id,
+            to_bin_id,
+            bps_to_remove,
+        }
+        .data(),
+        accounts: remove_liquidity_accounts(
+            pool,
+            position,
+            payer,
+            receiver_token_x,
+            receiver_token_y,
+        ),
+    }
+}
+
+pub fn dlmm_remove_all_liquidity_pda_creator(
+    pool: &DlmmPoolKeys,
+    position: &DlmmPositionKeys,
+    payer: Pubkey,
+    receiver_token_x: Pubkey,
+    receiver_token_y: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DlmmRemoveAllLiquidityPdaCreator {
+            lower_bin_id: position.lower_bin_id,
+            width: position.width,
+        }
+        .data(),
+        accounts: remove_liquidity_accounts(
+            pool,
+            position,
+            payer,
+            receiver_token_x,
+            receiver_token_y,
+        ),
+    }
+}
+
+pub fn dlmm_close_position_pda_creator(position: &DlmmPositionKeys, payer: Pubkey) -> Instruction {
+    let accounts = cpi_example::accounts::DlmmClosePositionPdaCreator {
+        program_config: derive_program_config().0,
+        creator_authority: position.creator_authority,
+        owner: position.owner,
+        position: position.position,
+        lb_pair: position.lb_pair,
+        bin_array_lower: position.bin_array_lower,
+        bin_array_upper: position.bin_array_upper,
+        payer,
+        dlmm_program: dlmm::ID,
+        dlmm_event_authority: derive_event_authority_pda().0,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DlmmClosePositionPdaCreator {
+            lower_bin_id: position.lower_bin_id,
+            width: position.width,
+        }
+        .data(),
+        accounts,
+    }
+}
+
+/// Fees are claimed to the creator authority PDA associated token accounts
+pub fn dlmm_claim_fee(
+    pool: &DlmmPoolKeys,
+    position: &DlmmPositionKeys,
+    cpi_example_admin: Pubkey,
+) -> Instruction {
+    let accounts = cpi_example::accounts::DlmmClaimFeePdaCreator {
+        program_config: derive_program_config().0,
+        creator_authority: position.creator_authority,
+        owner: position.owner,
+        position: position.position,
+        lb_pair: pool.lb_pair,
+        bin_array_lower: position.bin_array_lower,
+        bin_array_upper: position.bin_array_upper,
+        cpi_example_admin,
+        reserve_x: pool.reserve_x,
+        reserve_y: pool.reserve_y,
+        creator_token_x: get_associated_token_address(
+            &position.creator_authority,
+            &pool.token_x_mint,
+        ),
+        creator_token_y: get_associated_token_address(
+            &position.creator_authority,
+            &pool.token_y_mint,
+        ),
+        token_x_mint: pool.token_x_mint,
+        token_y_mint: pool.token_y_mint,
+        token_program: anchor_spl::token::ID,
+        dlmm_program: dlmm::ID,
+        dlmm_event_authority: derive_event_authority_pda().0,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DlmmClaimFee {
+            lower_bin_id: position.lower_bin_id,
+            width: position.width,
+        }
+        .data(),
+        accounts,
+    }
+}
+
+/// Rewards are claimed to the creator authority PDA associated token account
+pub fn dlmm_claim_reward(
+    position: &DlmmPositionKeys,
+    cpi_example_admin: Pubkey,
+    reward_index: u64,
+    reward_mint: Pubkey,
+) -> Instruction {
+    let accounts = cpi_example::accounts::DlmmClaimRewardPdaCreator {
+        program_config: derive_program_config().0,
+        creator_authority: position.creator_authority,
+        owner: position.owner,
+        position: position.position,
+        lb_pair: position.lb_pair,
+        bin_array_lower: position.bin_array_lower,
+        bin_array_upper: position.bin_array_upper,
+        cpi_example_admin,
+        reward_vault: derive_reward_vault_pda(position.lb_pair, reward_index).0,
+        reward_mint,
+        creator_reward_token: get_associated_token_address(
+            &position.creator_authority,
+            &reward_mint,
+        ),
+        token_program: anchor_spl::token::ID,
+        dlmm_program: dlmm::ID,
+        dlmm_event_authority: derive_event_authority_pda().0,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DlmmClaimReward {
+            lower_bin_id: position.lower_bin_id,
+            width: position.width,
+            reward_index,
+        }
+        .data(),
+        accounts,
+    }
+}
@@ -0,0 +1,1003 @@
+use crate::cpi_example_pda::{
+    derive_creator_authority, derive_event_authority, derive_program_config,
+};
+use crate::dynamic_amm_ix_account_builder::IxAccountBuilder;
+use crate::dynamic_amm_pda::*;
+use crate::dynamic_vault_pda::{derive_token_vault_key, derive_vault_key};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use cpi_example::dynamic_amm::accounts::Pool;
+use cpi_example::dynamic_amm::types::CustomizableParams;
+use cpi_example::dynamic_vault::accounts::Vault;
+use cpi_example::state::IntegratorFee;
+use cpi_example::{dynamic_amm, dynamic_vault};
+
+/// Accounts of a dynamic AMM pool and its vaults required by the CPI example instructions
+pub struct DynamicAmmPoolKeys {
+    pub pool: Pubkey,
+    pub lp_mint: Pubkey,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub a_vault: Pubkey,
+    pub b_vault: Pubkey,
+    pub a_token_vault: Pubkey,
+    pub b_token_vault: Pubkey,
+    pub a_vault_lp_mint: Pubkey,
+    pub b_vault_lp_mint: Pubkey,
+    pub a_vault_lp: Pubkey,
+    pub b_vault_lp: Pubkey,
+    pub protocol_token_a_fee: Pubkey,
+    pub protocol_token_b_fee: Pubkey,
+}
+
+impl DynamicAmmPoolKeys {
+    /// Keys of an existing pool
+    pub fn new(
+        pool: Pubkey,
+        pool_state: &Pool,
+        a_vault_state: &Vault,
+        b_vault_state: &Vault,
+    ) -> Self {
+        Self {
+            pool,
+            lp_mint: pool_state.lp_mint,
+            token_a_mint: pool_state.token_a_mint,
+            token_b_mint: pool_state.token_b_mint,
+            a_vault: pool_state.a_vault,
+            b_vault: pool_state.b_vault,
+            a_token_vault: a_vault_state.token_vault,
+            b_token_vault: b_vault_state.token_vault,
+            a_vault_lp_mint: a_vault_state.lp_mint,
+            b_vault_lp_mint: b_vault_state.lp_mint,
+            a_vault_lp: pool_state.a_vault_lp,
+            b_vault_lp: pool_state.b_vault_lp,
+            protocol_token_a_fee: pool_state.protocol_token_a_fee,
+            protocol_token_b_fee: pool_state.protocol_token_b_fee,
+        }
+    }
+
+    /// Keys of a pool created with the derived vaults of the token pair
+    pub fn derive(pool: Pubkey, token_a_mint: Pubkey, token_b_mint: Pubkey) -> Self {
+        let a_vault = derive_vault_key(token_a_mint);
+        let b_vault = derive_vault_key(token_b_mint);
+
+        Self {
+            pool,
+            lp_mint: derive_lp_mint_key(pool),
+            token_a_mint,
+            token_b_mint,
+            a_vault,
+            b_vault,
+            a_token_vault: derive_token_vault_key(a_vault),
+            b_token_vault: derive_token_vault_key(b_vault),
+            a_vault_lp_mint: crate::dynamic_vault_pda::derive_lp_mint_key(a_vault),
+            b_vault_lp_mint: crate::dynamic_vault_pda::derive_lp_mint_key(b_vault),
+            a_vault_lp: derive_vault_lp_key(a_vault, pool),
+            b_vault_lp: derive_vault_lp_key(b_vault, pool),
+            protocol_token_a_fee: derive_protocol_fee_key(token_a_mint, pool),
+            protocol_token_b_fee: derive_protocol_fee_key(token_b_mint, pool),
+        }
+    }
+
+    /// Keys of the customizable permissionless constant product pool of the token pair
+    pub fn derive_customizable_permissionless(token_a_mint: Pubkey, token_b_mint: Pubkey) -> Self {
+        let pool = derive_customizable_permissionless_constant_product_pool_key(
+            token_a_mint,
+            token_b_mint,
+        );
+        Self::derive(pool, token_a_mint, token_b_mint)
+    }
+
+    /// Keys of the permissionless constant product pool of the token pair created with the config
+    pub fn derive_with_config(token_a_mint: Pubkey, token_b_mint: Pubkey, config: Pubkey) -> Self {
+        let pool = derive_permissionless_constant_product_pool_with_config_key(
+            token_a_mint,
+            token_b_mint,
+            config,
+        );
+        Self::derive(pool, token_a_mint, token_b_mint)
+    }
+}
+
+/// The payer deposits from and receives the LP to its associated token accounts
+pub fn initialize_dynamic_amm_customizable_permissionless_pool(
+    token_a_mint: Pubkey,
+    token_b_mint: Pubkey,
+    payer: Pubkey,
+    token_a_amount: u64,
+    token_b_amount: u64,
+    params: CustomizableParams,
+) -> Instruction {
+    let init_pool_accounts =
+        IxAccountBuilder::initialize_customizable_permissionless_constant_product_pool(
+            token_a_mint,
+            token_b_mint,
+            payer,
+        );
+
+    let accounts = cpi_example::accounts::DynamicAmmInitializeCustomizablePermissionlessPool {
+        pool: init_pool_accounts.pool,
+        lp_mint: init_pool_accounts.lp_mint,
+        token_a_mint: init_pool_accounts.token_a_mint,
+        token_b_mint: init_pool_accounts.token_b_mint,
+        a_vault: init_pool_accounts.a_vault,
+        b_vault: init_pool_accounts.b_vault,
+        a_token_vault: init_pool_accounts.a_token_vault,
+        b_token_vault: init_pool_accounts.b_token_vault,
+        a_vault_lp_mint: init_pool_accounts.a_vault_lp_mint,
+        b_vault_lp_mint: init_pool_accounts.b_vault_lp_mint,
+        a_vault_lp: init_pool_accounts.a_vault_lp,
+        b_vault_lp: init_pool_accounts.b_vault_lp,
+        payer_token_a: init_pool_accounts.payer_token_a,
+        payer_token_b: init_pool_accounts.payer_token_b,
+        payer_pool_lp: init_pool_accounts.payer_pool_lp,
+        protocol_token_a_fee: init_pool_accounts.protocol_token_a_fee,
+        protocol_token_b_fee: init_pool_accounts.protocol_token_b_fee,
+        payer,
+        rent: anchor_lang::solana_program::sysvar::rent::ID,
+        mint_metadata: init_pool_accounts.mint_metadata,
+        metadata_program: METAPLEX_PROGRAM_ID,
+        vault_program: dynamic_vault::ID,
+        token_program: anchor_spl::token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        system_program: anchor_lang::system_program::ID,
+        dynamic_amm_program: dynamic_amm::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::InitializeDynamicAmmCustomizablePermissionlessPool {
+            token_a_amount,
+            token_b_amount,
+            params,
+        }
+        .data(),
+        accounts,
+    }
+}
+
+/// The payer funds the creator authority PDA from its associated token accounts. The creator authority PDA receives the LP.
+pub fn initialize_dynamic_amm_customizable_permissionless_pool_pda_creator(
+    token_a_mint: Pubkey,
+    token_b_mint: Pubkey,
+    owner: Pubkey,
+    payer: Pubkey,
+    token_a_amount: u64,
+    token_b_amount: u64,
+    params: CustomizableParams,
+) -> Instruction {
+    let pool =
+        derive_customizable_permissionless_constant_product_pool_key(token_a_mint, token_b_mint);
+    let (creator_authority, _bump) = derive_creator_authority(owner, pool);
+
+    let init_pool_accounts =
+        IxAccountBuilder::initialize_customizable_permissionless_constant_product_pool(
+            token_a_mint,
+            token_b_mint,
+            creator_authority,
+        );
+
+    let accounts =
+        cpi_example::accounts::DynamicAmmInitializeCustomizablePermissionlessPoolPdaCreator {
+            program_config: derive_program_config().0,
+            creator_authority,
+            owner,
+            creator_token_a: init_pool_accounts.payer_token_a,
+            creator_token_b: init_pool_accounts.payer_token_b,
+            pool: init_pool_accounts.pool,
+            lp_mint: init_pool_accounts.lp_mint,
+            token_a_mint: init_pool_accounts.token_a_mint,
+            token_b_mint: init_pool_accounts.token_b_mint,
+            a_vault: init_pool_accounts.a_vault,
+            b_vault: init_pool_accounts.b_vault,
+            a_token_vault: init_pool_accounts.a_token_vault,
+            b_token_vault: init_pool_accounts.b_token_vault,
+            a_vault_lp_mint: init_pool_accounts.a_vault_lp_mint,
+            b_vault_lp_mint: init_pool_accounts.b_vault_lp_mint,
+            a_vault_lp: init_pool_accounts.a_vault_lp,
+            b_vault_lp: init_pool_accounts.b_vault_lp,
+            payer_token_a: get_associated_token_address(&payer, &token_a_mint),
+            payer_token_b: get_associated_token_address(&payer, &token_b_mint),
+            creator_pool_lp: init_pool_accounts.payer_pool_lp,
+            protocol_token_a_fee: init_pool_accounts.protocol_token_a_fee,
+            protocol_token_b_fee: init_pool_accounts.protocol_token_b_fee,
+            payer,
+            rent: anchor_lang::solana_program::sysvar::rent::ID,
+            mint_metadata: init_pool_accounts.mint_metadata,
+            metadata_program: METAPLEX_PROGRAM_ID,
+            vault_program: dynamic_vault::ID,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: anchor_lang::system_program::ID,
+            dynamic_amm_program: dynamic_amm::ID,
+            event_authority: derive_event_authority().0,
+            program: cpi_example::ID,
+        }
+        .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data:
+            cpi_example::instruction::InitializeDynamicAmmCustomizablePermissionlessPoolPdaCreator {
+                token_a_amount,
+                token_b_amount,
+                params,
+            }
+            .data(),
+        accounts,
+    }
+}
+
+/// The payer deposits from and receives the LP to its associated token accounts
+pub fn initialize_dynamic_amm_permission_pool_with_config(
+    token_a_mint: Pubkey,
+    token_b_mint: Pubkey,
+    config: Pubkey,
+    payer: Pubkey,
+    token_a_amount: u64,
+    token_b_amount: u64,
+    activation_point: Option<u64>,
+) -> Instruction {
+    let init_pool_accounts =
+        IxAccountBuilder::initialize_permissionless_constant_product_pool_with_config_accounts(
+            token_a_mint,
+            token_b_mint,
+            config,
+            payer,
+        );
+
+    let accounts = cpi_example::accounts::DynamicAmmInitializePermissionlessPoolWithConfig {
+        pool: init_pool_accounts.pool,
+        config,
+        lp_mint: init_pool_accounts.lp_mint,
+        token_a_mint: init_pool_accounts.token_a_mint,
+        token_b_mint: init_pool_accounts.token_b_mint,
+        a_vault: init_pool_accounts.a_vault,
+        b_vault: init_pool_accounts.b_vault,
+        a_token_vault: init_pool_accounts.a_token_vault,
+        b_token_vault: init_pool_accounts.b_token_vault,
+        a_vault_lp_mint: init_pool_accounts.a_vault_lp_mint,
+        b_vault_lp_mint: init_pool_accounts.b_vault_lp_mint,
+        a_vault_lp: init_pool_accounts.a_vault_lp,
+        b_vault_lp: init_pool_accounts.b_vault_lp,
+        payer_token_a: init_pool_accounts.payer_token_a,
+        payer_token_b: init_pool_accounts.payer_token_b,
+        payer_pool_lp: init_pool_accounts.payer_pool_lp,
+        protocol_token_a_fee: init_pool_accounts.protocol_token_a_fee,
+        protocol_token_b_fee: init_pool_accounts.protocol_token_b_fee,
+        payer,
+        rent: anchor_lang::solana_program::sysvar::rent::ID,
+        mint_metadata: init_pool_accounts.mint_metadata,
+        metadata_program: METAPLEX_PROGRAM_ID,
+        vault_program: dynamic_vault::ID,
+        token_program: anchor_spl::token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        system_program: anchor_lang::system_program::ID,
+        dynamic_amm_program: dynamic_amm::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::InitializeDynamicAmmPermissionPoolWithConfig {
+            token_a_amount,
+            token_b_amount,
+            activation_point,
+        }
+        .data(),
+        accounts,
+    }
+}
+
+/// The payer funds the creator authority PDA from its associated token accounts. The creator authority PDA receives the LP.
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_dynamic_amm_permission_pool_with_config_pda_creator(
+    token_a_mint: Pubkey,
+    token_b_mint: Pubkey,
+    config: Pubkey,
+    owner: Pubkey,
+    payer: Pubkey,
+    token_a_amount: u64,
+    token_b_amount: u64,
+    activation_point: Option<u64>,
+) -> Instruction {
+    let pool = derive_permissionless_constant_product_pool_with_config_key(
+        token_a_mint,
+        token_b_mint,
+        config,
+    );
+    let (creator_authority, _bump) = derive_creator_authority(owner, pool);
+
+    let init_pool_accounts =
+        IxAccountBuilder::initialize_permissionless_constant_product_pool_with_config_accounts(
+            token_a_mint,
+            token_b_mint,
+            config,
+            creator_authority,
+        );
+
+    let accounts =
+        cpi_example::accounts::DynamicAmmInitializePermissionlessPoolWithConfigPdaCreator {
+            program_config: derive_program_config().0,
+            creator_authority,
+            owner,
+            creator_token_a: init_pool_accounts.payer_token_a,
+            creator_token_b: init_pool_accounts.payer_token_b,
+            pool: init_pool_accounts.pool,
+            config,
+            lp_mint: init_pool_accounts.lp_mint,
+            token_a_mint: init_pool_accounts.token_a_mint,
+            token_b_mint: init_pool_accounts.token_b_mint,
+            a_vault: init_pool_accounts.a_vault,
+            b_vault: init_pool_accounts.b_vault,
+            a_token_vault: init_pool_accounts.a_token_vault,
+            b_token_vault: init_pool_accounts.b_token_vault,
+            a_vault_lp_mint: init_pool_accounts.a_vault_lp_mint,
+            b_vault_lp_mint: init_pool_accounts.b_vault_lp_mint,
+            a_vault_lp: init_pool_accounts.a_vault_lp,
+            b_vault_lp: init_pool_accounts.b_vault_lp,
+            payer_token_a: get_associated_token_address(&payer, &token_a_mint),
+            payer_token_b: get_associated_token_address(&payer, &token_b_mint),
+            creator_pool_lp: init_pool_accounts.payer_pool_lp,
+            protocol_token_a_fee: init_pool_accounts.protocol_token_a_fee,
+            protocol_token_b_fee: init_pool_accounts.protocol_token_b_fee,
+            payer,
+            rent: anchor_lang::solana_program::sysvar::rent::ID,
+            mint_metadata: init_pool_accounts.mint_metadata,
+            metadata_program: METAPLEX_PROGRAM_ID,
+            vault_program: dynamic_vault::ID,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: anchor_lang::system_program::ID,
+            dynamic_amm_program: dynamic_amm::ID,
+            event_authority: derive_event_authority().0,
+            program: cpi_example::ID,
+        }
+        .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::InitializeDynamicAmmPermissionPoolWithConfigPdaCreator {
+            token_a_amount,
+            token_b_amount,
+            activation_point,
+        }
+        .data(),
+        accounts,
+    }
+}
+
+/// Swap token A to token B when `a_to_b` is true, otherwise token B to token A
+///
+/// `integrator_fee` is the integrator fee, and the associated token account of the integrator for the charged mint.
+#[allow(clippy::too_many_arguments)]
+pub fn dynamic_amm_swap(
+    pool: &DynamicAmmPoolKeys,
+    user: Pubkey,
+    user_source_token: Pubkey,
+    user_destination_token: Pubkey,
+    a_to_b: bool,
+    amount_in: u64,
+    min_amount_out: u64,
+    integrator_fee: Option<(IntegratorFee, Pubkey)>,
+) -> Instruction {
+    let protocol_token_fee = if a_to_b {
+        pool.protocol_token_a_fee
+    } else {
+        pool.protocol_token_b_fee
+    };
+
+    let accounts = cpi_example::accounts::DynamicAmmSwap {
+        pool: pool.pool,
+        user_source_token,
+        user_destination_token,
+        a_vault: pool.a_vault,
+        b_vault: pool.b_vault,
+        a_token_vault: pool.a_token_vault,
+        b_token_vault: pool.b_token_vault,
+        a_vault_lp_mint: pool.a_vault_lp_mint,
+        b_vault_lp_mint: pool.b_vault_lp_mint,
+        a_vault_lp: pool.a_vault_lp,
+        b_vault_lp: pool.b_vault_lp,
+        protocol_token_fee,
+        integrator_fee_token: integrator_fee.map(|(_, fee_token)| fee_token),
+        user,
+        vault_program: dynamic_vault::ID,
+        token_program: anchor_spl::token::ID,
+        dynamic_amm_program: dynamic_amm::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DynamicAmmSwap {
+            amount_in,
+            min_amount_out,
+            integrator_fee: integrator_fee.map(|(fee, _)| fee),
+        }
+        .data(),
+        accounts,
+    }
+}
+
+/// Same as [`dynamic_amm_swap`], but the CPI example program validates the accounts against the pool state
+pub fn dynamic_amm_swap_strict(
+    pool: &DynamicAmmPoolKeys,
+    user: Pubkey,
+    user_source_token: Pubkey,
+    user_destination_token: Pubkey,
+    a_to_b: bool,
+    amount_in: u64,
+    min_amount_out: u64,
+) -> Instruction {
+    let protocol_token_fee = if a_to_b {
+        pool.protocol_token_a_fee
+    } else {
+        pool.protocol_token_b_fee
+    };
+
+    let accounts = cpi_example::accounts::DynamicAmmSwapStrict {
+        pool: pool.pool,
+        user_source_token,
+        user_destination_token,
+        a_vault: pool.a_vault,
+        b_vault: pool.b_vault,
+        a_token_vault: pool.a_token_vault,
+        b_token_vault: pool.b_token_vault,
+        a_vault_lp_mint: pool.a_vault_lp_mint,
+        b_vault_lp_mint: pool.b_vault_lp_mint,
+        a_vault_lp: pool.a_vault_lp,
+        b_vault_lp: pool.b_vault_lp,
+        protocol_token_fee,
+        user,
+        vault_program: dynamic_vault::ID,
+        token_program: anchor_spl::token::ID,
+        dynamic_amm_program: dynamic_amm::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DynamicAmmSwapStrict {
+            amount_in,
+            min_amount_out,
+        }
+        .data(),
+        accounts,
+    }
+}
+
+/// Owner, lock escrow and escrow vault of each lock recipient, in the order of the allocations
+fn lock_recipient_accounts(pool: &DynamicAmmPoolKeys, owners: &[Pubkey]) -> Vec<AccountMeta> {
+    owners
+        .iter()
+        .flat_map(|owner| {
+            let lock_escrow = derive_lock_escrow_key(pool.pool, *owner);
+            [
+                AccountMeta::new_readonly(*owner, false),
+                AccountMeta::new(lock_escrow, false),
+                AccountMeta::new(
+                    get_associated_token_address(&lock_escrow, &pool.lp_mint),
+                    false,
+                ),
+            ]
+        })
+        .collect()
+}
+
+/// Lock the LP of `source_lp_tokens` to the lock escrows of the users. `allocations` are in the order of `users`.
+pub fn dynamic_amm_lock_liquidity(
+    pool: &DynamicAmmPoolKeys,
+    payer: Pubkey,
+    source_lp_tokens: Pubkey,
+    users: &[Pubkey],
+    allocations: Vec<u16>,
+) -> Instruction {
+    let mut accounts = cpi_example::accounts::DynamicAmmLockLiquidity {
+        pool: pool.pool,
+        lp_mint: pool.lp_mint,
+        source_lp_tokens,
+        payer,
+        a_vault: pool.a_vault,
+        b_vault: pool.b_vault,
+        a_vault_lp: pool.a_vault_lp,
+        b_vault_lp: pool.b_vault_lp,
+        a_vault_lp_mint: pool.a_vault_lp_mint,
+        b_vault_lp_mint: pool.b_vault_lp_mint,
+        dynamic_amm_program: dynamic_amm::ID,
+        system_program: anchor_lang::system_program::ID,
+        token_program: anchor_spl::token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None);
+
+    accounts.extend(lock_recipient_accounts(pool, users));
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DynamicAmmLockLiquidity { allocations }.data(),
+        accounts,
+    }
+}
+
+/// Lock the LP held by the creator authority PDA to the lock escrows of the creator authority PDA and the users.
+/// The first allocation is for the creator authority PDA, followed by the allocations in the order of `users`.
+pub fn dynamic_amm_lock_liquidity_pda_creator(
+    pool: &DynamicAmmPoolKeys,
+    owner: Pubkey,
+    payer: Pubkey,
+    users: &[Pubkey],
+    allocations: Vec<u16>,
+) -> Instruction {
+    let (creator_authority, _bump) = derive_creator_authority(owner, pool.pool);
+
+    let mut accounts = cpi_example::accounts::DynamicAmmLockLiquidityPdaCreator {
+        program_config: derive_program_config().0,
+        pool: pool.pool,
+        lp_mint: pool.lp_mint,
+        creator_authority,
+        owner,
+        source_lp_tokens: get_associated_token_address(&creator_authority, &pool.lp_mint),
+        payer,
+        a_vault: pool.a_vault,
+        b_vault: pool.b_vault,
+        a_vault_lp: pool.a_vault_lp,
+        b_vault_lp: pool.b_vault_lp,
+        a_vault_lp_mint: pool.a_vault_lp_mint,
+        b_vault_lp_mint: pool.b_vault_lp_mint,
+        dynamic_amm_program: dynamic_amm::ID,
+        system_program: anchor_lang::system_program::ID,
+        token_program: anchor_spl::token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None);
+
+    let recipients: Vec<Pubkey> = std::iter::once(creator_authority)
+        .chain(users.iter().copied())
+        .collect();
+    accounts.extend(lock_recipient_accounts(pool, &recipients));
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DynamicAmmLockLiquidityPdaCreator { allocations }.data(),
+        accounts,
+    }
+}
+
+/// Claim the fee of the owner lock escrow
+pub fn dynamic_amm_claim_fee(
+    pool: &DynamicAmmPoolKeys,
+    owner: Pubkey,
+    user_a_token: Pubkey,
+    user_b_token: Pubkey,
+) -> Instruction {
+    let lock_escrow = derive_lock_escrow_key(pool.pool, owner);
+
+    let accounts = cpi_example::accounts::DynamicAmmClaimFee {
+        pool: pool.pool,
+        lp_mint: pool.lp_mint,
+        lock_escrow,
+        owner,
+        escrow_vault: get_associated_token_address(&lock_escrow, &pool.lp_mint),
+        a_token_vault: pool.a_token_vault,
+        b_token_vault: pool.b_token_vault,
+        a_vault: pool.a_vault,
+        b_vault: pool.b_vault,
+        a_vault_lp: pool.a_vault_lp,
+        b_vault_lp: pool.b_vault_lp,
+        a_vault_lp_mint: pool.a_vault_lp_mint,
+        b_vault_lp_mint: pool.b_vault_lp_mint,
+        user_a_token,
+        user_b_token,
+        token_program: anchor_spl::token::ID,
+        dynamic_amm: dynamic_amm::ID,
+        dynamic_vault: dynamic_vault::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DynamicAmmClaimFee {}.data(),
+        accounts,
+    }
+}
+
+/// Claim the fee of the creator authority PDA lock escrow to the creator authority PDA associated token accounts
+pub fn dynamic_amm_claim_fee_pda_creator(
+    pool: &DynamicAmmPoolKeys,
+    owner: Pubkey,
+    cpi_example_admin: Pubkey,
+) -> Instruction {
+    let (creator_authority, _bump) = derive_creator_authority(owner, pool.pool);
+    let lock_escrow = derive_lock_escrow_key(pool.pool, creator_authority);
+
+    let accounts = cpi_example::accounts::DynamicAmmClaimFeePdaCreator {
+        program_config: derive_program_config().0,
+        pool: pool.pool,
+        lp_mint: pool.lp_mint,
+        creator_authority,
+        owner,
+        lock_escrow,
+        cpi_example_admin,
+        escrow_vault: get_associated_token_address(&lock_escrow, &pool.lp_mint),
+        a_token_vault: pool.a_token_vault,
+        b_token_vault: pool.b_token_vault,
+        a_vault: pool.a_vault,
+        b_vault: pool.b_vault,
+        a_vault_lp: pool.a_vault_lp,
+        b_vault_lp: pool.b_vault_lp,
+        a_vault_lp_mint: pool.a_vault_lp_mint,
+        b_vault_lp_mint: pool.b_vault_lp_mint,
+        creator_a_token: get_associated_token_address(&creator_authority, &pool.token_a_mint),
+        creator_b_token: get_associated_token_address(&creator_authority, &pool.token_b_mint),
+        token_program: anchor_spl::token::ID,
+        dynamic_amm: dynamic_amm::ID,
+        dynamic_vault: dynamic_vault::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DynamicAmmClaimFeePdaCreator {}.data(),
+        accounts,
+    }
+}
+
+fn balance_liquidity_accounts(pool: &DynamicAmmPoolKeys, user: Pubkey) -> Vec<AccountMeta> {
+    cpi_example::accounts::DynamicAmmBalanceLiquidity {
+        pool: pool.pool,
+        lp_mint: pool.lp_mint,
+        user_pool_lp: get_associated_token_address(&user, &pool.lp_mint),
+        a_vault_lp: pool.a_vault_lp,
+        b_vault_lp: pool.b_vault_lp,
+        a_vault: pool.a_vault,
+        b_vault: pool.b_vault,
+        a_vault_lp_mint: pool.a_vault_lp_mint,
+        b_vault_lp_mint: pool.b_vault_lp_mint,
+        a_token_vault: pool.a_token_vault,
+        b_token_vault: pool.b_token_vault,
+        user_a_token: get_associated_token_address(&user, &pool.token_a_mint),
+        user_b_token: get_associated_token_address(&user, &pool.token_b_mint),
+        user,
+        vault_program: dynamic_vault::ID,
+        token_program: anchor_spl::token::ID,
+        dynamic_amm_program: dynamic_amm::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None)
+}
+
+fn balance_liquidity_pda_creator_accounts(
+    pool: &DynamicAmmPoolKeys,
+    owner: Pubkey,
+    cpi_example_admin: Pubkey,
+) -> Vec<AccountMeta> {
+    let (creator_authority, _bump) = derive_creator_authority(owner, pool.pool);
+
+    cpi_example::accounts::DynamicAmmBalanceLiquidityPdaCreator {
+        pool: pool.pool,
+        lp_mint: pool.lp_mint,
+        creator_authority,
+        owner,
+        program_config: derive_program_config().0,
+        cpi_example_admin,
+        creator_pool_lp: get_associated_token_address(&creator_authority, &pool.lp_mint),
+        a_vault_lp: pool.a_vault_lp,
+        b_vault_lp: pool.b_vault_lp,
+        a_vault: pool.a_vault,
+        b_vault: pool.b_vault,
+        a_vault_lp_mint: pool.a_vault_lp_mint,
+        b_vault_lp_mint: pool.b_vault_lp_mint,
+        a_token_vault: pool.a_token_vault,
+        b_token_vault: pool.b_token_vault,
+        creator_a_token: get_associated_token_address(&creator_authority, &pool.token_a_mint),
+        creator_b_token: get_associated_token_address(&creator_authority, &pool.token_b_mint),
+        vault_program: dynamic_vault::ID,
+        token_program: anchor_spl::token::ID,
+        dynamic_amm_program: dynamic_amm::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None)
+}
+
+/// Deposit the user tokens in a balanced ratio. The LP is minted to the associated token account of the user.
+pub fn dynamic_amm_add_balance_liquidity(
+    pool: &DynamicAmmPoolKeys,
+    user: Pubkey,
+    pool_token_amount: u64,
+    maximum_token_a_amount: u64,
+    maximum_token_b_amount: u64,
+) -> Instruction {
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DynamicAmmAddBalanceLiquidity {
+            pool_token_amount,
+            maximum_token_a_amount,
+            maximum_token_b_amount,
+        }
+        .data(),
+        accounts: balance_liquidity_accounts(pool, user),
+    }
+}
+
+/// Withdraw the user liquidity in a balanced ratio to the associated token accounts of the user
+pub fn dynamic_amm_remove_balance_liquidity(
+    pool: &DynamicAmmPoolKeys,
+    user: Pubkey,
+    pool_token_amount: u64,
+    minimum_a_token_out: u64,
+    minimum_b_token_out: u64,
+) -> Instruction {
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DynamicAmmRemoveBalanceLiquidity {
+            pool_token_amount,
+            minimum_a_token_out,
+            minimum_b_token_out,
+        }
+        .data(),
+        accounts: balance_liquidity_accounts(pool, user),
+    }
+}
+
+/// Deposit the tokens of the creator authority PDA in a balanced ratio. The creator authority PDA holds the LP.
+pub fn dynamic_amm_add_balance_liquidity_pda_creator(
+    pool: &DynamicAmmPoolKeys,
+    owner: Pubkey,
+    cpi_example_admin: Pubkey,
+    pool_token_amount: u64,
+    maximum_token_a_amount: u64,
+    maximum_token_b_amount: u64,
+) -> Instruction {
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DynamicAmmAddBalanceLiquidityPdaCreator {
+            pool_token_amount,
+            maximum_token_a_amount,
+            maximum_token_b_amount,
+        }
+        .data(),
+        accounts: balance_liquidity_pda_creator_accounts(pool, owner, cpi_example_admin),
+    }
+}
+
+/// Withdraw the liquidity of the creator authority PDA in a balanced ratio. The creator authority PDA holds the tokens.
+pub fn dynamic_amm_remove_balance_liquidity_pda_creator(
+    pool: &DynamicAmmPoolKeys,
+    owner: Pubkey,
+    cpi_example_admin: Pubkey,
+    pool_token_amount: u64,
+    minimum_a_token_out: u64,
+    minimum_b_token_out: u64,
+) -> Instruction {
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DynamicAmmRemoveBalanceLiquidityPdaCreator {
+            pool_token_amount,
+            minimum_a_token_out,
+            minimum_b_token_out,
+        }
+        .data(),
+        accounts: balance_liquidity_pda_creator_accounts(pool, owner, cpi_example_admin),
+    }
+}
+
+/// Deposit the user tokens in any ratio. The LP is minted to the associated token account of the user.
+pub fn dynamic_amm_add_imbalance_liquidity(
+    pool: &DynamicAmmPoolKeys,
+    user: Pubkey,
+    minimum_pool_token_amount: u64,
+    token_a_amount: u64,
+    token_b_amount: u64,
+) -> Instruction {
+    let accounts = cpi_example::accounts::DynamicAmmAddImbalanceLiquidity {
+        pool: pool.pool,
+        lp_mint: pool.lp_mint,
+        user_pool_lp: get_associated_token_address(&user, &pool.lp_mint),
+        user_a_token: get_associated_token_address(&user, &pool.token_a_mint),
+        user_b_token: get_associated_token_address(&user, &pool.token_b_mint),
+        a_vault: pool.a_vault,
+        b_vault: pool.b_vault,
+        a_token_vault: pool.a_token_vault,
+        b_token_vault: pool.b_token_vault,
+        a_vault_lp_mint: pool.a_vault_lp_mint,
+        b_vault_lp_mint: pool.b_vault_lp_mint,
+        a_vault_lp: pool.a_vault_lp,
+        b_vault_lp: pool.b_vault_lp,
+        user,
+        vault_program: dynamic_vault::ID,
+        token_program: anchor_spl::token::ID,
+        dynamic_amm_program: dynamic_amm::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DynamicAmmAddImbalanceLiquidity {
+            minimum_pool_token_amount,
+            token_a_amount,
+            token_b_amount,
+        }
+        .data(),
+        accounts,
+    }
+}
+
+/// Withdraw the user liquidity as the destination mint to the associated token account of the user
+pub fn dynamic_amm_remove_liquidity_single_side(
+    pool: &DynamicAmmPoolKeys,
+    user: Pubkey,
+    destination_mint: Pubkey,
+    pool_token_amount: u64,
+    minimum_out_amount: u64,
+) -> Instruction {
+    let accounts = cpi_example::accounts::DynamicAmmRemoveLiquiditySingleSide {
+        pool: pool.pool,
+        lp_mint: pool.lp_mint,
+        user_pool_lp: get_associated_token_address(&user, &pool.lp_mint),
+        user_destination_token: get_associated_token_address(&user, &destination_mint),
+        a_vault: pool.a_vault,
+        b_vault: pool.b_vault,
+        a_token_vault: pool.a_token_vault,
+        b_token_vault: pool.b_token_vault,
+        a_vault_lp_mint: pool.a_vault_lp_mint,
+        b_vault_lp_mint: pool.b_vault_lp_mint,
+        a_vault_lp: pool.a_vault_lp,
+        b_vault_lp: pool.b_vault_lp,
+        user,
+        vault_program: dynamic_vault::ID,
+        token_program: anchor_spl::token::ID,
+        dynamic_amm_program: dynamic_amm::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DynamicAmmRemoveLiquiditySingleSide {
+            pool_token_amount,
+            minimum_out_amount,
+        }
+        .data(),
+        accounts,
+    }
+}
+
+/// Zap the user token A (`a_to_b`) or token B into the pool. The LP is minted to the associated token account of the user,
+/// and is locked to the lock escrow of the user when `lock` is set.
+pub fn dynamic_amm_zap_in(
+    pool: &DynamicAmmPoolKeys,
+    user: Pubkey,
+    a_to_b: bool,
+    in_amount: u64,
+    minimum_pool_token_amount: u64,
+    lock: bool,
+) -> Instruction {
+    let protocol_token_fee = if a_to_b {
+        pool.protocol_token_a_fee
+    } else {
+        pool.protocol_token_b_fee
+    };
+
+    let (lock_escrow, escrow_vault) = if lock {
+        let lock_escrow = derive_lock_escrow_key(pool.pool, user);
+        (
+            Some(lock_escrow),
+            Some(get_associated_token_address(&lock_escrow, &pool.lp_mint)),
+        )
+    } else {
+        (None, None)
+    };
+
+    let accounts = cpi_example::accounts::DynamicAmmZapIn {
+        pool: pool.pool,
+        lp_mint: pool.lp_mint,
+        user_pool_lp: get_associated_token_address(&user, &pool.lp_mint),
+        user_a_token: get_associated_token_address(&user, &pool.token_a_mint),
+        user_b_token: get_associated_token_address(&user, &pool.token_b_mint),
+        a_vault: pool.a_vault,
+        b_vault: pool.b_vault,
+        a_token_vault: pool.a_token_vault,
+        b_token_vault: pool.b_token_vault,
+        a_vault_lp_mint: pool.a_vault_lp_mint,
+        b_vault_lp_mint: pool.b_vault_lp_mint,
+        a_vault_lp: pool.a_vault_lp,
+        b_vault_lp: pool.b_vault_lp,
+        protocol_token_fee,
+        lock_escrow,
+        escrow_vault,
+        user,
+        vault_program: dynamic_vault::ID,
+        token_program: anchor_spl::token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        system_program: anchor_lang::system_program::ID,
+        dynamic_amm_program: dynamic_amm::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DynamicAmmZapIn {
+            in_amount,
+            a_to_b,
+            minimum_pool_token_amount,
+        }
+        .data(),
+        accounts,
+    }
+}
+
+pub fn dynamic_amm_zap_out(
+    pool: &DynamicAmmPoolKeys,
+    user: Pubkey,
+    a_to_b: bool,
+    pool_token_amount: u64,
+    min_amount_out: u64,
+) -> Instruction {
+    let protocol_token_fee = if a_to_b {
+        pool.protocol_token_a_fee
+    } else {
+        pool.protocol_token_b_fee
+    };
+
+    let accounts = cpi_example::accounts::DynamicAmmZapOut {
+        pool: pool.pool,
+        lp_mint: pool.lp_mint,
+        user_pool_lp: get_associated_token_address(&user, &pool.lp_mint),
+        user_a_token: get_associated_token_address(&user, &pool.token_a_mint),
+        user_b_token: get_associated_token_address(&user, &pool.token_b_mint),
+        a_vault: pool.a_vault,
+        b_vault: pool.b_vault,
+        a_token_vault: pool.a_token_vault,
+        b_token_vault: pool.b_token_vault,
+        a_vault_lp_mint: pool.a_vault_lp_mint,
+        b_vault_lp_mint: pool.b_vault_lp_mint,
+        a_vault_lp: pool.a_vault_lp,
+        b_vault_lp: pool.b_vault_lp,
+        protocol_token_fee,
+        user,
+        vault_program: dynamic_vault::ID,
+        token_program: anchor_spl::token::ID,
+        dynamic_amm_program: dynamic_amm::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::DynamicAmmZapOut {
+            pool_token_amount,
+            a_to_b,
+            min_amount_out,
+        }
+        .data(),
+        accounts,
+    }
+}
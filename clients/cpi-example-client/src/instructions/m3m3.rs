@@ -0,0 +1,69 @@
+use crate::cpi_example_pda::derive_event_authority;
+use crate::dynamic_amm_pda::derive_lock_escrow_key;
+use crate::instructions::DynamicAmmPoolKeys;
+use crate::m3m3_pda::*;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use cpi_example::m3m3::types::InitializeVaultParams;
+use cpi_example::{dynamic_amm, m3m3};
+
+/// Lock the LP of the payer associated token account to the m3m3 vault. The other token of the pool is the quote token.
+pub fn initialize_m3m3_vault(
+    pool: &DynamicAmmPoolKeys,
+    payer: Pubkey,
+    stake_mint: Pubkey,
+    max_amount: u64,
+    vault_params: InitializeVaultParams,
+) -> Instruction {
+    let quote_mint = if stake_mint == pool.token_a_mint {
+        pool.token_b_mint
+    } else {
+        pool.token_a_mint
+    };
+
+    let m3m3_vault = derive_m3m3_vault_key(pool.pool);
+    let lock_escrow = derive_lock_escrow_key(pool.pool, m3m3_vault);
+
+    let accounts = cpi_example::accounts::InitializeM3m3Vault {
+        pool: pool.pool,
+        lock_escrow,
+        lp_mint: pool.lp_mint,
+        source_lp_tokens: get_associated_token_address(&payer, &pool.lp_mint),
+        escrow_vault: get_associated_token_address(&lock_escrow, &pool.lp_mint),
+        payer,
+        a_vault: pool.a_vault,
+        b_vault: pool.b_vault,
+        a_vault_lp: pool.a_vault_lp,
+        b_vault_lp: pool.b_vault_lp,
+        a_vault_lp_mint: pool.a_vault_lp_mint,
+        b_vault_lp_mint: pool.b_vault_lp_mint,
+        m3m3_vault,
+        stake_token_vault: get_associated_token_address(&m3m3_vault, &stake_mint),
+        quote_token_vault: get_associated_token_address(&m3m3_vault, &quote_mint),
+        top_staker_list: derive_top_staker_list_key(m3m3_vault),
+        full_balance_list: derive_full_balance_list_key(m3m3_vault),
+        stake_mint,
+        quote_mint,
+        m3m3_event_authority: derive_m3m3_event_authority_key(),
+        dynamic_amm_program: dynamic_amm::ID,
+        m3m3_program: m3m3::ID,
+        system_program: anchor_lang::system_program::ID,
+        token_program: anchor_spl::token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        event_authority: derive_event_authority().0,
+        program: cpi_example::ID,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: cpi_example::ID,
+        data: cpi_example::instruction::InitializeM3m3Vault {
+            max_amount,
+            vault_params,
+        }
+        .data(),
+        accounts,
+    }
+}
@@ -0,0 +1,9 @@
+mod admin;
+mod dlmm;
+mod dynamic_amm;
+mod m3m3;
+
+pub use admin::*;
+pub use dlmm::*;
+pub use dynamic_amm::*;
+pub use m3m3::*;
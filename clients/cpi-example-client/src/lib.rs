@@ -0,0 +1,15 @@
+//! Off-chain client of the CPI example program. Derives the PDAs of the Meteora programs and builds
+//! ready-to-sign instructions for every entry point of the CPI example program, and decodes its events.
+pub use cpi_example;
+
+pub mod cpi_example_pda;
+pub mod dlmm_pda;
+pub mod dynamic_amm_ix_account_builder;
+pub mod dynamic_amm_pda;
+pub mod dynamic_vault_pda;
+pub mod events;
+pub mod instructions;
+pub mod m3m3_pda;
+
+mod dynamic_amm_aux_lp_mint;
+mod dynamic_vault_aux_lp_mint;
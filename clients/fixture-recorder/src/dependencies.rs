@@ -0,0 +1,83 @@
+use anchor_lang::{AccountDeserialize, Discriminator};
+use anyhow::Result;
+use cpi_example_client::cpi_example::dlmm::accounts::LbPair;
+use cpi_example_client::cpi_example::dlmm_utils::bin_id_to_bin_array_index;
+use cpi_example_client::cpi_example::dynamic_amm::accounts::Pool;
+use cpi_example_client::cpi_example::dynamic_vault::accounts::Vault;
+use cpi_example_client::cpi_example::{dlmm, dynamic_amm, dynamic_vault};
+use cpi_example_client::dlmm_pda::*;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+/// Accounts which must be recorded together with an account to replay it offline
+#[derive(Default)]
+pub struct Dependencies {
+    /// Accounts which must exist on the cluster
+    pub required: Vec<Pubkey>,
+    /// Accounts which are recorded only when they exist, such as bin arrays around the active bin
+    pub optional: Vec<Pubkey>,
+}
+
+fn has_discriminator(account: &Account, discriminator: &[u8]) -> bool {
+    account.data.starts_with(discriminator)
+}
+
+/// Dependencies of dynamic AMM pools, dynamic vaults and DLMM pairs. Other accounts have no dependencies.
+pub fn account_dependencies(
+    key: Pubkey,
+    account: &Account,
+    bin_array_radius: i64,
+) -> Result<Dependencies> {
+    if account.owner == dynamic_amm::ID && has_discriminator(account, Pool::DISCRIMINATOR) {
+        let pool = Pool::try_deserialize(&mut account.data.as_ref())?;
+
+        return Ok(Dependencies {
+            required: vec![
+                pool.lp_mint,
+                pool.token_a_mint,
+                pool.token_b_mint,
+                pool.a_vault,
+                pool.b_vault,
+                pool.a_vault_lp,
+                pool.b_vault_lp,
+                pool.protocol_token_a_fee,
+                pool.protocol_token_b_fee,
+            ],
+            optional: vec![],
+        });
+    }
+
+    if account.owner == dynamic_vault::ID && has_discriminator(account, Vault::DISCRIMINATOR) {
+        let vault = Vault::try_deserialize(&mut account.data.as_ref())?;
+
+        return Ok(Dependencies {
+            required: vec![vault.token_mint, vault.lp_mint, vault.token_vault],
+            optional: vec![],
+        });
+    }
+
+    if account.owner == dlmm::ID && has_discriminator(account, LbPair::DISCRIMINATOR) {
+        let data = &account.data[8..8 + std::mem::size_of::<LbPair>()];
+        let lb_pair: LbPair = bytemuck::pod_read_unaligned(data);
+
+        let active_bin_array_index = bin_id_to_bin_array_index(lb_pair.active_id);
+
+        let mut optional = vec![derive_bin_array_bitmap_extension(key).0];
+        optional.extend(
+            (active_bin_array_index - bin_array_radius..=active_bin_array_index + bin_array_radius)
+                .map(|index| derive_bin_array_pda(key, index).0),
+        );
+
+        return Ok(Dependencies {
+            required: vec![
+                lb_pair.oracle,
+                lb_pair.token_x_mint,
+                lb_pair.token_y_mint,
+                lb_pair.reserve_x,
+                lb_pair.reserve_y,
+            ],
+            optional,
+        });
+    }
+
+    Ok(Dependencies::default())
+}
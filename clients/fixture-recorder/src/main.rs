@@ -0,0 +1,188 @@
+//! Records cluster accounts, and the accounts they depend on, as offline fixtures of the integration tests.
+//!
+//! Usage: fixture-recorder [OPTIONS] <PUBKEY>...
+//!
+//! Options:
+//!   --url <URL>               RPC url [default: https://api.mainnet-beta.solana.com]
+//!   --out <DIR>               Fixtures directory [default: programs/cpi-example/tests/fixtures]
+//!   --vault-mint <MINT>       Record the dynamic vault of the mint
+//!   --bin-array-radius <N>    Bin arrays recorded on each side of the active bin array [default: 1]
+//!   --programs                Dump the programs called by the CPI example program
+
+mod dependencies;
+
+use anyhow::{bail, Context, Result};
+use cpi_example_client::cpi_example::{dlmm, dynamic_amm, dynamic_vault, m3m3};
+use cpi_example_client::dynamic_amm_pda::METAPLEX_PROGRAM_ID;
+use cpi_example_client::dynamic_vault_pda::derive_vault_key;
+use dependencies::account_dependencies;
+use solana_account_decoder::{encode_ui_account, UiAccountEncoding};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use solana_sdk::{account::Account, commitment_config::CommitmentConfig, pubkey::Pubkey};
+use std::collections::{BTreeMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const DEFAULT_RPC: &str = "https://api.mainnet-beta.solana.com";
+
+const PROGRAMS: [(&str, Pubkey); 5] = [
+    ("dlmm", dlmm::ID),
+    ("dynamic_vault", dynamic_vault::ID),
+    ("dynamic_amm", dynamic_amm::ID),
+    ("metaplex", METAPLEX_PROGRAM_ID),
+    ("m3m3", m3m3::ID),
+];
+
+struct Args {
+    url: String,
+    out: PathBuf,
+    bin_array_radius: i64,
+    dump_programs: bool,
+    keys: Vec<Pubkey>,
+}
+
+fn parse_pubkey(value: &str) -> Result<Pubkey> {
+    Pubkey::from_str(value).with_context(|| format!("Invalid pubkey {value}"))
+}
+
+fn parse_args() -> Result<Args> {
+    let mut args = Args {
+        url: DEFAULT_RPC.to_owned(),
+        out: PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../../programs/cpi-example/tests/fixtures"),
+        bin_array_radius: 1,
+        dump_programs: false,
+        keys: vec![],
+    };
+
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        let mut value = || {
+            iter.next()
+                .with_context(|| format!("Missing value of {arg}"))
+        };
+
+        match arg.as_str() {
+            "--url" => args.url = value()?,
+            "--out" => args.out = PathBuf::from(value()?),
+            "--vault-mint" => args.keys.push(derive_vault_key(parse_pubkey(&value()?)?)),
+            "--bin-array-radius" => args.bin_array_radius = value()?.parse()?,
+            "--programs" => args.dump_programs = true,
+            flag if flag.starts_with("--") => bail!("Unknown option {flag}"),
+            key => args.keys.push(parse_pubkey(key)?),
+        }
+    }
+
+    if args.keys.is_empty() && !args.dump_programs {
+        bail!("Usage: fixture-recorder [--url <URL>] [--out <DIR>] [--vault-mint <MINT>]... [--bin-array-radius <N>] [--programs] <PUBKEY>...");
+    }
+
+    Ok(args)
+}
+
+fn get_account(rpc_client: &RpcClient, key: &Pubkey) -> Result<Option<Account>> {
+    Ok(rpc_client
+        .get_account_with_commitment(key, CommitmentConfig::confirmed())?
+        .value)
+}
+
+/// Fetch the accounts and walk their dependencies. Missing optional dependencies are skipped.
+fn record_accounts(
+    rpc_client: &RpcClient,
+    keys: &[Pubkey],
+    bin_array_radius: i64,
+) -> Result<BTreeMap<Pubkey, Account>> {
+    let mut recorded = BTreeMap::new();
+    let mut queue: VecDeque<(Pubkey, bool)> = keys.iter().map(|key| (*key, true)).collect();
+
+    while let Some((key, required)) = queue.pop_front() {
+        if recorded.contains_key(&key) {
+            continue;
+        }
+
+        let Some(account) = get_account(rpc_client, &key)? else {
+            if required {
+                bail!("Account {key} not found");
+            }
+            continue;
+        };
+
+        let dependencies = account_dependencies(key, &account, bin_array_radius)?;
+        queue.extend(dependencies.required.into_iter().map(|key| (key, true)));
+        queue.extend(dependencies.optional.into_iter().map(|key| (key, false)));
+
+        recorded.insert(key, account);
+    }
+
+    Ok(recorded)
+}
+
+/// Write the account in the format of `solana account --output json`
+fn write_account_fixture(dir: &Path, key: &Pubkey, account: &Account) -> Result<()> {
+    let ui_account = encode_ui_account(key, account, UiAccountEncoding::Base64, None, None);
+    let fixture = serde_json::json!({
+        "pubkey": key.to_string(),
+        "account": ui_account,
+    });
+
+    let path = dir.join(format!("{key}.json"));
+    std::fs::write(&path, serde_json::to_string_pretty(&fixture)?)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Dump the executable of the program, like `solana program dump`
+fn dump_program(rpc_client: &RpcClient, dir: &Path, name: &str, program_id: Pubkey) -> Result<()> {
+    let program = get_account(rpc_client, &program_id)?
+        .with_context(|| format!("Program {program_id} not found"))?;
+
+    let executable = if program.owner == bpf_loader_upgradeable::ID {
+        let UpgradeableLoaderState::Program {
+            programdata_address,
+        } = bincode::deserialize(&program.data)?
+        else {
+            bail!("Program {program_id} is not an upgradeable program");
+        };
+
+        let program_data = get_account(rpc_client, &programdata_address)?
+            .with_context(|| format!("Program data {programdata_address} not found"))?;
+
+        program_data.data[UpgradeableLoaderState::size_of_programdata_metadata()..].to_vec()
+    } else {
+        program.data
+    };
+
+    let path = dir.join(format!("{name}.so"));
+    std::fs::write(&path, executable).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn main() -> Result<()> {
+    let args = parse_args()?;
+    let rpc_client = RpcClient::new(args.url);
+
+    std::fs::create_dir_all(&args.out)
+        .with_context(|| format!("Failed to create {}", args.out.display()))?;
+
+    if args.dump_programs {
+        for (name, program_id) in PROGRAMS {
+            dump_program(&rpc_client, &args.out, name, program_id)?;
+            println!("Dumped {name} {program_id}");
+        }
+    }
+
+    let accounts_dir = args.out.join("accounts");
+    std::fs::create_dir_all(&accounts_dir)?;
+
+    let accounts = record_accounts(&rpc_client, &args.keys, args.bin_array_radius)?;
+    for (key, account) in accounts.iter() {
+        write_account_fixture(&accounts_dir, key, account)?;
+    }
+
+    println!(
+        "Recorded {} accounts to {}",
+        accounts.len(),
+        accounts_dir.display()
+    );
+
+    Ok(())
+}
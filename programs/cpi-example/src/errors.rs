@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum CpiExampleError {
+    #[msg("Bin range is out of the position range")]
+    InvalidBinRange,
+
+    #[msg("Bin arrays are required in remaining accounts")]
+    MissingBinArray,
+
+    #[msg("Remaining account is not a bin array of the pool")]
+    InvalidBinArray,
+
+    #[msg("Bin arrays are not ordered by the swap direction")]
+    InvalidBinArrayOrder,
+
+    #[msg("Signer is not the program admin")]
+    InvalidAdmin,
+
+    #[msg("Signer is not the pending admin")]
+    InvalidPendingAdmin,
+
+    #[msg("Signer is not the upgrade authority of the program")]
+    InvalidUpgradeAuthority,
+
+    #[msg("Creator token account and destination token account must be provided together")]
+    MissingSweepTokenAccount,
+
+    #[msg("Allocations must add up to 10_000 bps")]
+    InvalidAllocation,
+
+    #[msg("Math operation overflow")]
+    MathOverflow,
+
+    #[msg("Token account is not the associated token account of the creator authority")]
+    InvalidCreatorTokenAccount,
+
+    #[msg("Protocol fee token account does not belong to the pool")]
+    InvalidProtocolTokenFee,
+
+    #[msg("Swap output is less than the minimum amount out")]
+    ExceededSlippage,
+
+    #[msg("Integrator fee exceeds the maximum fee bps")]
+    InvalidIntegratorFee,
+
+    #[msg("Integrator fee token account is required to charge the integrator fee")]
+    MissingIntegratorFeeToken,
+
+    #[msg("Integrator fee token account is not an associated token account of the charged mint")]
+    InvalidIntegratorFeeToken,
+
+    #[msg("Minted LP is less than the minimum pool token amount")]
+    ExceededLpSlippage,
+
+    #[msg("Lock escrow and escrow vault must be provided together")]
+    MissingLockEscrow,
+
+    #[msg("Number of lock recipients must be between 1 and 20")]
+    InvalidLockRecipientCount,
+
+    #[msg("Remaining accounts must be the owner, lock escrow and escrow vault of each recipient")]
+    InvalidLockRecipientAccounts,
+
+    #[msg("Destination token account is not of the mint of the creator token account")]
+    InvalidSweepDestinationToken,
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+
+/// Swap routed through the CPI example program
+#[event]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EvtSwap {
+    /// Pool of the swap
+    pub pool: Pubkey,
+    /// User who executed the swap
+    pub user: Pubkey,
+    /// Mint of the token sold
+    pub input_mint: Pubkey,
+    /// Mint of the token bought
+    pub output_mint: Pubkey,
+    /// Amount debited from the user token in account
+    pub amount_in: u64,
+    /// Amount credited to the user token out account
+    pub amount_out: u64,
+}
+
+/// DLMM pool created through the CPI example program
+#[event]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EvtInitializeLbPair {
+    /// Pool created
+    pub lb_pair: Pubkey,
+    /// Creator and funder of the pool
+    pub creator: Pubkey,
+    /// Mint of token X
+    pub token_x_mint: Pubkey,
+    /// Mint of token Y
+    pub token_y_mint: Pubkey,
+    /// Initial active bin id
+    pub active_id: i32,
+    /// Bin step of the pool
+    pub bin_step: u16,
+}
+
+/// Dynamic AMM pool created through the CPI example program
+#[event]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EvtInitializeDynamicAmmPool {
+    /// Pool created
+    pub pool: Pubkey,
+    /// Creator of the pool. Holder of the initial LP.
+    pub creator: Pubkey,
+    /// Mint of token A
+    pub token_a_mint: Pubkey,
+    /// Mint of token B
+    pub token_b_mint: Pubkey,
+    /// Initial liquidity of token A
+    pub token_a_amount: u64,
+    /// Initial liquidity of token B
+    pub token_b_amount: u64,
+}
+
+/// LP locked to the lock escrow of an owner. Emitted once per allocation.
+#[event]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EvtLockLiquidity {
+    /// Pool of the LP
+    pub pool: Pubkey,
+    /// Owner of the lock escrow
+    pub owner: Pubkey,
+    /// Lock escrow receiving the LP
+    pub lock_escrow: Pubkey,
+    /// Amount of LP locked
+    pub lp_amount: u64,
+}
+
+/// Fee claimed through the CPI example program
+#[event]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EvtClaimFee {
+    /// Pool of the fee
+    pub pool: Pubkey,
+    /// Owner of the position or the lock escrow
+    pub owner: Pubkey,
+    /// Amount of token A (token X for DLMM) received
+    pub token_a_amount: u64,
+    /// Amount of token B (token Y for DLMM) received
+    pub token_b_amount: u64,
+}
+
+/// M3M3 vault created through the CPI example program
+#[event]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EvtInitializeM3m3Vault {
+    /// M3M3 vault created
+    pub m3m3_vault: Pubkey,
+    /// Dynamic AMM pool of the vault
+    pub pool: Pubkey,
+    /// Lock escrow of the vault
+    pub lock_escrow: Pubkey,
+    /// Amount of LP locked to the lock escrow of the vault
+    pub lp_amount: u64,
+}
+
+/// Liquidity deposited to a Dynamic AMM pool through the CPI example program
+#[event]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EvtAddLiquidity {
+    /// Pool of the liquidity
+    pub pool: Pubkey,
+    /// Owner of the deposited tokens. Holder of the minted LP.
+    pub owner: Pubkey,
+    /// Amount of LP minted
+    pub lp_amount: u64,
+    /// Amount of token A deposited
+    pub token_a_amount: u64,
+    /// Amount of token B deposited
+    pub token_b_amount: u64,
+}
+
+/// Liquidity withdrawn from a Dynamic AMM pool through the CPI example program
+#[event]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EvtRemoveLiquidity {
+    /// Pool of the liquidity
+    pub pool: Pubkey,
+    /// Owner of the burned LP. Receiver of the withdrawn tokens.
+    pub owner: Pubkey,
+    /// Amount of LP burned
+    pub lp_amount: u64,
+    /// Amount of token A withdrawn
+    pub token_a_amount: u64,
+    /// Amount of token B withdrawn
+    pub token_b_amount: u64,
+}
@@ -0,0 +1,35 @@
+use crate::errors::CpiExampleError;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// Program config. PDA.
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = program_config.bump,
+        constraint = program_config.pending_admin == pending_admin.key() @ CpiExampleError::InvalidPendingAdmin
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Admin proposed by the current admin.
+    pub pending_admin: Signer<'info>,
+}
+
+/// Accepts the admin transfer proposed by the current admin.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.admin = program_config.pending_admin;
+    program_config.pending_admin = Pubkey::default();
+
+    Ok(())
+}
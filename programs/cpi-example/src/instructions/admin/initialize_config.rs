@@ -0,0 +1,52 @@
+use crate::errors::CpiExampleError;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    /// Program config. PDA.
+    #[account(
+        init,
+        seeds = [b"config"],
+        bump,
+        payer = upgrade_authority,
+        space = 8 + ProgramConfig::INIT_SPACE
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Program data of the CPI example program. Only the upgrade authority can initialize the config.
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ CpiExampleError::InvalidUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// Upgrade authority of the CPI example program. Also funder for account rental.
+    #[account(mut)]
+    pub upgrade_authority: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Initializes the program config with the admin of the creator authority PDA instructions.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `admin` - The admin of the creator authority PDA instructions.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_initialize_config(ctx: Context<InitializeConfig>, admin: Pubkey) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.admin = admin;
+    program_config.pending_admin = Pubkey::default();
+    program_config.bump = ctx.bumps.program_config;
+
+    Ok(())
+}
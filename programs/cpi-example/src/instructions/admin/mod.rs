@@ -0,0 +1,11 @@
+pub mod initialize_config;
+pub use initialize_config::*;
+
+pub mod propose_admin;
+pub use propose_admin::*;
+
+pub mod accept_admin;
+pub use accept_admin::*;
+
+pub mod sweep_creator_authority;
+pub use sweep_creator_authority::*;
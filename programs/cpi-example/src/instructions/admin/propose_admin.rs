@@ -0,0 +1,34 @@
+use crate::errors::CpiExampleError;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    /// Program config. PDA.
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = program_config.bump,
+        has_one = admin @ CpiExampleError::InvalidAdmin
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Current admin.
+    pub admin: Signer<'info>,
+}
+
+/// Proposes a new admin. The transfer completes when the new admin accepts it.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `new_admin` - The proposed admin.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    ctx.accounts.program_config.pending_admin = new_admin;
+
+    Ok(())
+}
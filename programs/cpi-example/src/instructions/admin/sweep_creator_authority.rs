@@ -0,0 +1,143 @@
+use crate::errors::CpiExampleError;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::Transfer as NativeSolTransfer;
+use anchor_spl::token::{CloseAccount, Token, TokenAccount, Transfer as TokenTransfer};
+
+#[derive(Accounts)]
+pub struct SweepCreatorAuthority<'info> {
+    /// CHECK: Creator authority. PDA. Holder of the swept assets.
+    #[account(
+        mut,
+        seeds = [b"creator", owner.key().as_ref(), pool.key().as_ref()],
+        bump
+    )]
+    pub creator_authority: UncheckedAccount<'info>,
+
+    /// Owner of the creator authority PDA.
+    pub owner: Signer<'info>,
+
+    /// CHECK: Pool of the creator authority PDA. Only used as seed.
+    pub pool: UncheckedAccount<'info>,
+
+    /// Token account of the creator authority to sweep.
+    #[account(
+        mut,
+        token::authority = creator_authority,
+    )]
+    pub creator_token: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Destination token account of the swept tokens. Chosen by the admin. Must be of the mint of the creator token account.
+    #[account(
+        mut,
+        constraint = creator_token
+            .iter()
+            .all(|creator_token| creator_token.mint == destination_token.mint)
+            @ CpiExampleError::InvalidSweepDestinationToken
+    )]
+    pub destination_token: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Destination of the swept lamports and the rent of the closed token account. Chosen by the admin.
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// Program config. Stores the admin of the creator authority PDA instructions.
+    #[account(seeds = [b"config"], bump = program_config.bump)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+
+    /// CHECK: Only admin can sweep the creator authority PDA.
+    #[account(
+        constraint = program_config.admin == cpi_example_admin.key() @ CpiExampleError::InvalidAdmin
+    )]
+    pub cpi_example_admin: Signer<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Sweeps the assets held by the creator authority PDA to the destination.
+///
+/// When the token accounts are provided, the whole token balance is transferred to the destination token account,
+/// and the creator token account can be closed to recover its rent. The lamports of the creator authority PDA above the
+/// rent exempt minimum are transferred to the destination, so the PDA stays usable by the pool creation instructions.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `close_creator_token` - Whether to close the creator token account after the sweep.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_sweep_creator_authority(
+    ctx: Context<SweepCreatorAuthority>,
+    close_creator_token: bool,
+) -> Result<()> {
+    let owner_key = ctx.accounts.owner.key();
+    let pool_key = ctx.accounts.pool.key();
+    let seeds = [
+        b"creator".as_ref(),
+        owner_key.as_ref(),
+        pool_key.as_ref(),
+        &[ctx.bumps.creator_authority],
+    ];
+
+    let signer_seeds = &[&seeds[..]];
+
+    match (&ctx.accounts.creator_token, &ctx.accounts.destination_token) {
+        (Some(creator_token), Some(destination_token)) => {
+            if creator_token.amount > 0 {
+                anchor_spl::token::transfer(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        TokenTransfer {
+                            from: creator_token.to_account_info(),
+                            to: destination_token.to_account_info(),
+                            authority: ctx.accounts.creator_authority.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    creator_token.amount,
+                )?;
+            }
+
+            if close_creator_token {
+                anchor_spl::token::close_account(CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    CloseAccount {
+                        account: creator_token.to_account_info(),
+                        destination: ctx.accounts.destination.to_account_info(),
+                        authority: ctx.accounts.creator_authority.to_account_info(),
+                    },
+                    signer_seeds,
+                ))?;
+            }
+        }
+        (None, None) => {}
+        _ => return Err(CpiExampleError::MissingSweepTokenAccount.into()),
+    }
+
+    // The creator authority PDA holds no data. Its lamports above the rent exempt minimum are left over from the funding.
+    let lamports = ctx
+        .accounts
+        .creator_authority
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    if lamports > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                NativeSolTransfer {
+                    from: ctx.accounts.creator_authority.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            lamports,
+        )?;
+    }
+
+    Ok(())
+}
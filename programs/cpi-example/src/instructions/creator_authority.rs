@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::Transfer as NativeSolTransfer;
+
+/// Accounts moving the lamports for account rental between the payer and the creator authority PDA
+pub struct CreatorAuthorityLamportsAccounts<'b, 'info> {
+    pub creator_authority: &'b AccountInfo<'info>,
+    pub payer: &'b Signer<'info>,
+    pub system_program: &'b Program<'info, System>,
+}
+
+/// Fund the creator authority with the lamports paying for the rental of the accounts created by the pool creation.
+pub fn fund_creator_authority_lamports(
+    lamports: u64,
+    accounts: &CreatorAuthorityLamportsAccounts,
+) -> Result<()> {
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            accounts.system_program.to_account_info(),
+            NativeSolTransfer {
+                from: accounts.payer.to_account_info(),
+                to: accounts.creator_authority.to_account_info(),
+            },
+        ),
+        lamports,
+    )
+}
+
+/// Return the lamports funded to the creator authority but not spent by the pool creation to the payer.
+///
+/// The creator PDA can't keep the unused lamports, as a balance below the rent exempt minimum fails the transaction.
+pub fn refund_creator_authority(
+    lamports_before_funding: u64,
+    accounts: &CreatorAuthorityLamportsAccounts,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let unused_lamports = accounts
+        .creator_authority
+        .lamports()
+        .saturating_sub(lamports_before_funding);
+
+    if unused_lamports > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                accounts.system_program.to_account_info(),
+                NativeSolTransfer {
+                    from: accounts.creator_authority.to_account_info(),
+                    to: accounts.payer.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            unused_lamports,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,235 @@
+use crate::dlmm;
+use crate::dlmm::accounts::LbPair;
+use crate::dlmm::constants::{BIN_ARRAY, POSITION};
+use crate::dlmm::types::LiquidityParameterByStrategy;
+use crate::dlmm_utils::{
+    bin_id_to_bin_array_index, is_valid_position_range, is_within_position_range,
+    position_upper_bin_id,
+};
+use crate::errors::CpiExampleError;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount, Transfer as TokenTransfer},
+};
+
+#[derive(Accounts)]
+#[instruction(lower_bin_id: i32, width: i32, liquidity_parameter: LiquidityParameterByStrategy)]
+pub struct DlmmAddLiquidityByStrategyPdaCreator<'info> {
+    /// CHECK: Creator authority. PDA. Owner of the position.
+    #[account(
+        seeds = [b"creator", owner.key().as_ref(), lb_pair.key().as_ref()],
+        bump
+    )]
+    pub creator_authority: UncheckedAccount<'info>,
+
+    /// Owner of the creator authority PDA. Creator authorities are isolated per owner and pool.
+    pub owner: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        associated_token::mint = token_x_mint,
+        associated_token::authority = creator_authority,
+        payer = payer
+    )]
+    pub creator_token_x: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        associated_token::mint = token_y_mint,
+        associated_token::authority = creator_authority,
+        payer = payer
+    )]
+    pub creator_token_y: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    /// CHECK: Payer token account of token X. Used to fund the creator authority.
+    pub payer_token_x: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Payer token account of token Y. Used to fund the creator authority.
+    pub payer_token_y: UncheckedAccount<'info>,
+
+    /// CHECK: Position account. PDA of DLMM program derived from the pool, creator authority and bin range.
+    #[account(
+        mut,
+        seeds = [
+            POSITION,
+            lb_pair.key().as_ref(),
+            creator_authority.key().as_ref(),
+            lower_bin_id.to_le_bytes().as_ref(),
+            width.to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+        constraint = is_valid_position_range(lower_bin_id, width),
+        constraint = is_within_position_range(
+            lower_bin_id,
+            width,
+            liquidity_parameter.strategy_parameters.min_bin_id,
+            liquidity_parameter.strategy_parameters.max_bin_id,
+        )
+    )]
+    pub position: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// The pool account
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    #[account(mut)]
+    /// CHECK: Bin array extension account of the pool
+    pub bin_array_bitmap_extension: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Reserve account of token X
+    pub reserve_x: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Reserve account of token Y
+    pub reserve_y: UncheckedAccount<'info>,
+
+    /// Mint account of token X
+    pub token_x_mint: Box<Account<'info, Mint>>,
+    /// Mint account of token Y
+    pub token_y_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Bin array account containing the lower bin of the position
+    #[account(
+        mut,
+        seeds = [
+            BIN_ARRAY,
+            lb_pair.key().as_ref(),
+            bin_id_to_bin_array_index(lower_bin_id).to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+    )]
+    pub bin_array_lower: UncheckedAccount<'info>,
+
+    /// CHECK: Bin array account containing the upper bin of the position
+    #[account(
+        mut,
+        seeds = [
+            BIN_ARRAY,
+            lb_pair.key().as_ref(),
+            bin_id_to_bin_array_index(position_upper_bin_id(lower_bin_id, width)).to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+    )]
+    pub bin_array_upper: UncheckedAccount<'info>,
+
+    /// Program config. Stores the admin of the creator authority PDA instructions.
+    #[account(seeds = [b"config"], bump = program_config.bump)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+
+    /// CHECK: CPI example program admin. Only admin can call this instruction. Also funder for account rental.
+    #[account(
+        mut,
+        constraint = program_config.admin == payer.key() @ CpiExampleError::InvalidAdmin
+    )]
+    pub payer: Signer<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+    /// Associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    #[account(address = dlmm::ID)]
+    /// CHECK: DLMM program
+    pub dlmm_program: UncheckedAccount<'info>,
+
+    /// CHECK: DLMM program event authority for event CPI
+    pub dlmm_event_authority: UncheckedAccount<'info>,
+}
+
+/// Adds liquidity by strategy to a DLMM position owned by the creator authority PDA. The creator authority is funded by the payer.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `lower_bin_id` - The lower bin id of the position.
+/// * `width` - The number of bins covered by the position.
+/// * `liquidity_parameter` - The amounts, active bin and strategy to deposit with. The strategy bin range must be within the position.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_dlmm_add_liquidity_by_strategy_pda_creator(
+    ctx: Context<DlmmAddLiquidityByStrategyPdaCreator>,
+    _lower_bin_id: i32,
+    _width: i32,
+    liquidity_parameter: LiquidityParameterByStrategy,
+) -> Result<()> {
+    // Fund creator PDA with token X and token Y
+    for (amount, creator_token, payer_token) in [
+        (
+            liquidity_parameter.amount_x,
+            &ctx.accounts.creator_token_x,
+            &ctx.accounts.payer_token_x,
+        ),
+        (
+            liquidity_parameter.amount_y,
+            &ctx.accounts.creator_token_y,
+            &ctx.accounts.payer_token_y,
+        ),
+    ] {
+        if amount > creator_token.amount {
+            anchor_spl::token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    TokenTransfer {
+                        from: payer_token.to_account_info(),
+                        to: creator_token.to_account_info(),
+                        authority: ctx.accounts.payer.to_account_info(),
+                    },
+                ),
+                amount - creator_token.amount,
+            )?;
+        }
+    }
+
+    let accounts = dlmm::cpi::accounts::AddLiquidityByStrategy {
+        position: ctx.accounts.position.to_account_info(),
+        lb_pair: ctx.accounts.lb_pair.to_account_info(),
+        bin_array_bitmap_extension: ctx
+            .accounts
+            .bin_array_bitmap_extension
+            .as_ref()
+            .map(|account| account.to_account_info()),
+        user_token_x: ctx.accounts.creator_token_x.to_account_info(),
+        user_token_y: ctx.accounts.creator_token_y.to_account_info(),
+        reserve_x: ctx.accounts.reserve_x.to_account_info(),
+        reserve_y: ctx.accounts.reserve_y.to_account_info(),
+        token_x_mint: ctx.accounts.token_x_mint.to_account_info(),
+        token_y_mint: ctx.accounts.token_y_mint.to_account_info(),
+        bin_array_lower: ctx.accounts.bin_array_lower.to_account_info(),
+        bin_array_upper: ctx.accounts.bin_array_upper.to_account_info(),
+        sender: ctx.accounts.creator_authority.to_account_info(),
+        token_x_program: ctx.accounts.token_program.to_account_info(),
+        token_y_program: ctx.accounts.token_program.to_account_info(),
+        event_authority: ctx.accounts.dlmm_event_authority.to_account_info(),
+        program: ctx.accounts.dlmm_program.to_account_info(),
+    };
+
+    let owner_key = ctx.accounts.owner.key();
+    let lb_pair_key = ctx.accounts.lb_pair.key();
+    let seeds = [
+        b"creator".as_ref(),
+        owner_key.as_ref(),
+        lb_pair_key.as_ref(),
+        &[ctx.bumps.creator_authority],
+    ];
+
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.dlmm_program.to_account_info(),
+        accounts,
+        signer_seeds,
+    );
+
+    dlmm::cpi::add_liquidity_by_strategy(cpi_context, liquidity_parameter)
+}
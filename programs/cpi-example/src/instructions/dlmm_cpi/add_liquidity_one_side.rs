@@ -0,0 +1,292 @@
+use crate::dlmm;
+use crate::dlmm::accounts::LbPair;
+use crate::dlmm::constants::{BIN_ARRAY, POSITION};
+use crate::dlmm::types::{
+    AddLiquiditySingleSidePreciseParameter, LiquidityParameterByStrategyOneSide,
+};
+use crate::dlmm_utils::{
+    bin_id_to_bin_array_index, is_valid_position_range, is_within_position_range,
+    position_upper_bin_id,
+};
+use crate::errors::CpiExampleError;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount, Transfer as TokenTransfer},
+};
+
+#[derive(Accounts)]
+#[instruction(lower_bin_id: i32, width: i32)]
+pub struct DlmmAddLiquidityOneSidePdaCreator<'info> {
+    /// CHECK: Creator authority. PDA. Owner of the position.
+    #[account(
+        seeds = [b"creator", owner.key().as_ref(), lb_pair.key().as_ref()],
+        bump
+    )]
+    pub creator_authority: UncheckedAccount<'info>,
+
+    /// Owner of the creator authority PDA. Creator authorities are isolated per owner and pool.
+    pub owner: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        associated_token::mint = token_mint,
+        associated_token::authority = creator_authority,
+        payer = payer
+    )]
+    pub creator_token: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    /// CHECK: Payer token account of the deposited token. Used to fund the creator authority.
+    pub payer_token: UncheckedAccount<'info>,
+
+    /// CHECK: Position account. PDA of DLMM program derived from the pool, creator authority and bin range.
+    #[account(
+        mut,
+        seeds = [
+            POSITION,
+            lb_pair.key().as_ref(),
+            creator_authority.key().as_ref(),
+            lower_bin_id.to_le_bytes().as_ref(),
+            width.to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+        constraint = is_valid_position_range(lower_bin_id, width),
+    )]
+    pub position: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// The pool account
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    #[account(mut)]
+    /// CHECK: Bin array extension account of the pool
+    pub bin_array_bitmap_extension: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Reserve account of the deposited token
+    pub reserve: UncheckedAccount<'info>,
+
+    /// Mint account of the deposited token. Token X deposits above the active bin, token Y deposits below the active bin.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Bin array account containing the lower bin of the position
+    #[account(
+        mut,
+        seeds = [
+            BIN_ARRAY,
+            lb_pair.key().as_ref(),
+            bin_id_to_bin_array_index(lower_bin_id).to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+    )]
+    pub bin_array_lower: UncheckedAccount<'info>,
+
+    /// CHECK: Bin array account containing the upper bin of the position
+    #[account(
+        mut,
+        seeds = [
+            BIN_ARRAY,
+            lb_pair.key().as_ref(),
+            bin_id_to_bin_array_index(position_upper_bin_id(lower_bin_id, width)).to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+    )]
+    pub bin_array_upper: UncheckedAccount<'info>,
+
+    /// Program config. Stores the admin of the creator authority PDA instructions.
+    #[account(seeds = [b"config"], bump = program_config.bump)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+
+    /// CHECK: CPI example program admin. Only admin can call this instruction. Also funder for account rental.
+    #[account(
+        mut,
+        constraint = program_config.admin == payer.key() @ CpiExampleError::InvalidAdmin
+    )]
+    pub payer: Signer<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+    /// Associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    #[account(address = dlmm::ID)]
+    /// CHECK: DLMM program
+    pub dlmm_program: UncheckedAccount<'info>,
+
+    /// CHECK: DLMM program event authority for event CPI
+    pub dlmm_event_authority: UncheckedAccount<'info>,
+}
+
+impl<'info> DlmmAddLiquidityOneSidePdaCreator<'info> {
+    /// Fund creator PDA with the deposited token
+    fn fund_creator_token(&self, amount: u64) -> Result<()> {
+        if amount > self.creator_token.amount {
+            anchor_spl::token::transfer(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    TokenTransfer {
+                        from: self.payer_token.to_account_info(),
+                        to: self.creator_token.to_account_info(),
+                        authority: self.payer.to_account_info(),
+                    },
+                ),
+                amount - self.creator_token.amount,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds one side liquidity by strategy to a DLMM position owned by the creator authority PDA. The creator authority is funded by the payer.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `lower_bin_id` - The lower bin id of the position.
+/// * `width` - The number of bins covered by the position.
+/// * `liquidity_parameter` - The amount, active bin and one side strategy to deposit with. The strategy bin range must be within the position.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_dlmm_add_liquidity_by_strategy_one_side_pda_creator(
+    ctx: Context<DlmmAddLiquidityOneSidePdaCreator>,
+    lower_bin_id: i32,
+    width: i32,
+    liquidity_parameter: LiquidityParameterByStrategyOneSide,
+) -> Result<()> {
+    require!(
+        is_within_position_range(
+            lower_bin_id,
+            width,
+            liquidity_parameter.strategy_parameters.min_bin_id,
+            liquidity_parameter.strategy_parameters.max_bin_id,
+        ),
+        CpiExampleError::InvalidBinRange
+    );
+
+    ctx.accounts
+        .fund_creator_token(liquidity_parameter.amount)?;
+
+    let accounts = dlmm::cpi::accounts::AddLiquidityByStrategyOneSide {
+        position: ctx.accounts.position.to_account_info(),
+        lb_pair: ctx.accounts.lb_pair.to_account_info(),
+        bin_array_bitmap_extension: ctx
+            .accounts
+            .bin_array_bitmap_extension
+            .as_ref()
+            .map(|account| account.to_account_info()),
+        user_token: ctx.accounts.creator_token.to_account_info(),
+        reserve: ctx.accounts.reserve.to_account_info(),
+        token_mint: ctx.accounts.token_mint.to_account_info(),
+        bin_array_lower: ctx.accounts.bin_array_lower.to_account_info(),
+        bin_array_upper: ctx.accounts.bin_array_upper.to_account_info(),
+        sender: ctx.accounts.creator_authority.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        event_authority: ctx.accounts.dlmm_event_authority.to_account_info(),
+        program: ctx.accounts.dlmm_program.to_account_info(),
+    };
+
+    let owner_key = ctx.accounts.owner.key();
+    let lb_pair_key = ctx.accounts.lb_pair.key();
+    let seeds = [
+        b"creator".as_ref(),
+        owner_key.as_ref(),
+        lb_pair_key.as_ref(),
+        &[ctx.bumps.creator_authority],
+    ];
+
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.dlmm_program.to_account_info(),
+        accounts,
+        signer_seeds,
+    );
+
+    dlmm::cpi::add_liquidity_by_strategy_one_side(cpi_context, liquidity_parameter)
+}
+
+/// Adds one side liquidity with precise amount per bin to a DLMM position owned by the creator authority PDA. The creator authority is funded by the payer.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `lower_bin_id` - The lower bin id of the position.
+/// * `width` - The number of bins covered by the position.
+/// * `parameter` - The compressed amount to deposit for each bin. All bins must be within the position.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_dlmm_add_liquidity_one_side_precise_pda_creator(
+    ctx: Context<DlmmAddLiquidityOneSidePdaCreator>,
+    lower_bin_id: i32,
+    width: i32,
+    parameter: AddLiquiditySingleSidePreciseParameter,
+) -> Result<()> {
+    let mut amount: u64 = 0;
+
+    for bin in parameter.bins.iter() {
+        require!(
+            is_within_position_range(lower_bin_id, width, bin.bin_id, bin.bin_id),
+            CpiExampleError::InvalidBinRange
+        );
+
+        amount = amount
+            .checked_add(
+                u64::from(bin.amount)
+                    .checked_mul(parameter.decompress_multiplier)
+                    .ok_or(CpiExampleError::MathOverflow)?,
+            )
+            .ok_or(CpiExampleError::MathOverflow)?;
+    }
+
+    ctx.accounts.fund_creator_token(amount)?;
+
+    let accounts = dlmm::cpi::accounts::AddLiquidityOneSidePrecise {
+        position: ctx.accounts.position.to_account_info(),
+        lb_pair: ctx.accounts.lb_pair.to_account_info(),
+        bin_array_bitmap_extension: ctx
+            .accounts
+            .bin_array_bitmap_extension
+            .as_ref()
+            .map(|account| account.to_account_info()),
+        user_token: ctx.accounts.creator_token.to_account_info(),
+        reserve: ctx.accounts.reserve.to_account_info(),
+        token_mint: ctx.accounts.token_mint.to_account_info(),
+        bin_array_lower: ctx.accounts.bin_array_lower.to_account_info(),
+        bin_array_upper: ctx.accounts.bin_array_upper.to_account_info(),
+        sender: ctx.accounts.creator_authority.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        event_authority: ctx.accounts.dlmm_event_authority.to_account_info(),
+        program: ctx.accounts.dlmm_program.to_account_info(),
+    };
+
+    let owner_key = ctx.accounts.owner.key();
+    let lb_pair_key = ctx.accounts.lb_pair.key();
+    let seeds = [
+        b"creator".as_ref(),
+        owner_key.as_ref(),
+        lb_pair_key.as_ref(),
+        &[ctx.bumps.creator_authority],
+    ];
+
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.dlmm_program.to_account_info(),
+        accounts,
+        signer_seeds,
+    );
+
+    dlmm::cpi::add_liquidity_one_side_precise(cpi_context, parameter)
+}
@@ -0,0 +1,210 @@
+use crate::dlmm;
+use crate::dlmm::accounts::LbPair;
+use crate::dlmm::constants::{BIN_ARRAY, POSITION};
+use crate::dlmm_utils::{bin_id_to_bin_array_index, position_upper_bin_id};
+use crate::errors::CpiExampleError;
+use crate::events::EvtClaimFee;
+use crate::state::ProgramConfig;
+use crate::token_utils::load_token_account;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token::Token;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(lower_bin_id: i32, width: i32)]
+pub struct DlmmClaimFeePdaCreator<'info> {
+    /// CHECK: Creator authority. PDA. Owner of the position.
+    #[account(
+        seeds = [b"creator", owner.key().as_ref(), lb_pair.key().as_ref()],
+        bump
+    )]
+    pub creator_authority: UncheckedAccount<'info>,
+
+    /// Owner of the creator authority PDA. Creator authorities are isolated per owner and pool.
+    pub owner: Signer<'info>,
+
+    /// CHECK: Position account. PDA of DLMM program derived from the pool, creator authority and bin range.
+    #[account(
+        mut,
+        seeds = [
+            POSITION,
+            lb_pair.key().as_ref(),
+            creator_authority.key().as_ref(),
+            lower_bin_id.to_le_bytes().as_ref(),
+            width.to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+    )]
+    pub position: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// The pool account
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    /// CHECK: Bin array account containing the lower bin of the position
+    #[account(
+        mut,
+        seeds = [
+            BIN_ARRAY,
+            lb_pair.key().as_ref(),
+            bin_id_to_bin_array_index(lower_bin_id).to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+    )]
+    pub bin_array_lower: UncheckedAccount<'info>,
+
+    /// CHECK: Bin array account containing the upper bin of the position
+    #[account(
+        mut,
+        seeds = [
+            BIN_ARRAY,
+            lb_pair.key().as_ref(),
+            bin_id_to_bin_array_index(position_upper_bin_id(lower_bin_id, width)).to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+    )]
+    pub bin_array_upper: UncheckedAccount<'info>,
+
+    /// Program config. Stores the admin of the creator authority PDA instructions.
+    #[account(seeds = [b"config"], bump = program_config.bump)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+
+    /// CHECK: Only admin can claim fee for creator PDA.
+    #[account(
+        constraint = program_config.admin == cpi_example_admin.key() @ CpiExampleError::InvalidAdmin
+    )]
+    pub cpi_example_admin: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Reserve account of token X
+    pub reserve_x: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Reserve account of token Y
+    pub reserve_y: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Creator token X account. Used to receive fee
+    pub creator_token_x: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Creator token Y account. Used to receive fee
+    pub creator_token_y: UncheckedAccount<'info>,
+
+    /// CHECK: Mint account of token X
+    pub token_x_mint: UncheckedAccount<'info>,
+    /// CHECK: Mint account of token Y
+    pub token_y_mint: UncheckedAccount<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = dlmm::ID)]
+    /// CHECK: DLMM program
+    pub dlmm_program: UncheckedAccount<'info>,
+
+    /// CHECK: DLMM program event authority for event CPI
+    pub dlmm_event_authority: UncheckedAccount<'info>,
+}
+
+/// Claims swap fee of a DLMM position owned by the creator authority PDA.
+///
+/// The claimed fee will be hold by creator PDA.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `lower_bin_id` - The lower bin id of the position.
+/// * `width` - The number of bins covered by the position.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_dlmm_claim_fee_pda_creator(
+    ctx: Context<DlmmClaimFeePdaCreator>,
+    _lower_bin_id: i32,
+    _width: i32,
+) -> Result<()> {
+    // Token mints are validated against the pool by DLMM program
+    let creator_token_x_key = get_associated_token_address_with_program_id(
+        &ctx.accounts.creator_authority.key(),
+        &ctx.accounts.token_x_mint.key(),
+        &ctx.accounts.token_program.key(),
+    );
+
+    let creator_token_y_key = get_associated_token_address_with_program_id(
+        &ctx.accounts.creator_authority.key(),
+        &ctx.accounts.token_y_mint.key(),
+        &ctx.accounts.token_program.key(),
+    );
+
+    require_keys_eq!(
+        creator_token_x_key,
+        ctx.accounts.creator_token_x.key(),
+        CpiExampleError::InvalidCreatorTokenAccount
+    );
+    require_keys_eq!(
+        creator_token_y_key,
+        ctx.accounts.creator_token_y.key(),
+        CpiExampleError::InvalidCreatorTokenAccount
+    );
+
+    let token_a_amount_before = load_token_account(&ctx.accounts.creator_token_x)?.amount;
+    let token_b_amount_before = load_token_account(&ctx.accounts.creator_token_y)?.amount;
+
+    let accounts = dlmm::cpi::accounts::ClaimFee {
+        lb_pair: ctx.accounts.lb_pair.to_account_info(),
+        position: ctx.accounts.position.to_account_info(),
+        bin_array_lower: ctx.accounts.bin_array_lower.to_account_info(),
+        bin_array_upper: ctx.accounts.bin_array_upper.to_account_info(),
+        sender: ctx.accounts.creator_authority.to_account_info(),
+        reserve_x: ctx.accounts.reserve_x.to_account_info(),
+        reserve_y: ctx.accounts.reserve_y.to_account_info(),
+        user_token_x: ctx.accounts.creator_token_x.to_account_info(),
+        user_token_y: ctx.accounts.creator_token_y.to_account_info(),
+        token_x_mint: ctx.accounts.token_x_mint.to_account_info(),
+        token_y_mint: ctx.accounts.token_y_mint.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        event_authority: ctx.accounts.dlmm_event_authority.to_account_info(),
+        program: ctx.accounts.dlmm_program.to_account_info(),
+    };
+
+    let owner_key = ctx.accounts.owner.key();
+    let lb_pair_key = ctx.accounts.lb_pair.key();
+    let seeds = [
+        b"creator".as_ref(),
+        owner_key.as_ref(),
+        lb_pair_key.as_ref(),
+        &[ctx.bumps.creator_authority],
+    ];
+
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.dlmm_program.to_account_info(),
+        accounts,
+        signer_seeds,
+    );
+
+    dlmm::cpi::claim_fee(cpi_context)?;
+
+    let token_a_amount = load_token_account(&ctx.accounts.creator_token_x)?
+        .amount
+        .checked_sub(token_a_amount_before)
+        .ok_or(CpiExampleError::MathOverflow)?;
+    let token_b_amount = load_token_account(&ctx.accounts.creator_token_y)?
+        .amount
+        .checked_sub(token_b_amount_before)
+        .ok_or(CpiExampleError::MathOverflow)?;
+
+    emit_cpi!(EvtClaimFee {
+        pool: ctx.accounts.lb_pair.key(),
+        owner: ctx.accounts.creator_authority.key(),
+        token_a_amount,
+        token_b_amount,
+    });
+
+    Ok(())
+}
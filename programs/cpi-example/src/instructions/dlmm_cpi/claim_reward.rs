@@ -0,0 +1,166 @@
+use crate::dlmm;
+use crate::dlmm::accounts::LbPair;
+use crate::dlmm::constants::{BIN_ARRAY, POSITION};
+use crate::dlmm_utils::{bin_id_to_bin_array_index, position_upper_bin_id};
+use crate::errors::CpiExampleError;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token::Token;
+
+#[derive(Accounts)]
+#[instruction(lower_bin_id: i32, width: i32)]
+pub struct DlmmClaimRewardPdaCreator<'info> {
+    /// CHECK: Creator authority. PDA. Owner of the position.
+    #[account(
+        seeds = [b"creator", owner.key().as_ref(), lb_pair.key().as_ref()],
+        bump
+    )]
+    pub creator_authority: UncheckedAccount<'info>,
+
+    /// Owner of the creator authority PDA. Creator authorities are isolated per owner and pool.
+    pub owner: Signer<'info>,
+
+    /// CHECK: Position account. PDA of DLMM program derived from the pool, creator authority and bin range.
+    #[account(
+        mut,
+        seeds = [
+            POSITION,
+            lb_pair.key().as_ref(),
+            creator_authority.key().as_ref(),
+            lower_bin_id.to_le_bytes().as_ref(),
+            width.to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+    )]
+    pub position: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// The pool account
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    /// CHECK: Bin array account containing the lower bin of the position
+    #[account(
+        mut,
+        seeds = [
+            BIN_ARRAY,
+            lb_pair.key().as_ref(),
+            bin_id_to_bin_array_index(lower_bin_id).to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+    )]
+    pub bin_array_lower: UncheckedAccount<'info>,
+
+    /// CHECK: Bin array account containing the upper bin of the position
+    #[account(
+        mut,
+        seeds = [
+            BIN_ARRAY,
+            lb_pair.key().as_ref(),
+            bin_id_to_bin_array_index(position_upper_bin_id(lower_bin_id, width)).to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+    )]
+    pub bin_array_upper: UncheckedAccount<'info>,
+
+    /// Program config. Stores the admin of the creator authority PDA instructions.
+    #[account(seeds = [b"config"], bump = program_config.bump)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+
+    /// CHECK: Only admin can claim reward for creator PDA.
+    #[account(
+        constraint = program_config.admin == cpi_example_admin.key() @ CpiExampleError::InvalidAdmin
+    )]
+    pub cpi_example_admin: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Reward vault of the farming reward
+    pub reward_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Mint account of the farming reward
+    pub reward_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Creator reward token account. Used to receive reward
+    pub creator_reward_token: UncheckedAccount<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = dlmm::ID)]
+    /// CHECK: DLMM program
+    pub dlmm_program: UncheckedAccount<'info>,
+
+    /// CHECK: DLMM program event authority for event CPI
+    pub dlmm_event_authority: UncheckedAccount<'info>,
+}
+
+/// Claims farming reward of a DLMM position owned by the creator authority PDA.
+///
+/// The claimed reward will be hold by creator PDA.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `lower_bin_id` - The lower bin id of the position.
+/// * `width` - The number of bins covered by the position.
+/// * `reward_index` - The index of the farming reward in the pool.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_dlmm_claim_reward_pda_creator(
+    ctx: Context<DlmmClaimRewardPdaCreator>,
+    _lower_bin_id: i32,
+    _width: i32,
+    reward_index: u64,
+) -> Result<()> {
+    // Reward mint and vault are validated against the pool by DLMM program
+    let creator_reward_token_key = get_associated_token_address_with_program_id(
+        &ctx.accounts.creator_authority.key(),
+        &ctx.accounts.reward_mint.key(),
+        &ctx.accounts.token_program.key(),
+    );
+
+    require_keys_eq!(
+        creator_reward_token_key,
+        ctx.accounts.creator_reward_token.key(),
+        CpiExampleError::InvalidCreatorTokenAccount
+    );
+
+    let accounts = dlmm::cpi::accounts::ClaimReward {
+        lb_pair: ctx.accounts.lb_pair.to_account_info(),
+        position: ctx.accounts.position.to_account_info(),
+        bin_array_lower: ctx.accounts.bin_array_lower.to_account_info(),
+        bin_array_upper: ctx.accounts.bin_array_upper.to_account_info(),
+        sender: ctx.accounts.creator_authority.to_account_info(),
+        reward_vault: ctx.accounts.reward_vault.to_account_info(),
+        reward_mint: ctx.accounts.reward_mint.to_account_info(),
+        user_token_account: ctx.accounts.creator_reward_token.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        event_authority: ctx.accounts.dlmm_event_authority.to_account_info(),
+        program: ctx.accounts.dlmm_program.to_account_info(),
+    };
+
+    let owner_key = ctx.accounts.owner.key();
+    let lb_pair_key = ctx.accounts.lb_pair.key();
+    let seeds = [
+        b"creator".as_ref(),
+        owner_key.as_ref(),
+        lb_pair_key.as_ref(),
+        &[ctx.bumps.creator_authority],
+    ];
+
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.dlmm_program.to_account_info(),
+        accounts,
+        signer_seeds,
+    );
+
+    dlmm::cpi::claim_reward(cpi_context, reward_index)
+}
@@ -0,0 +1,260 @@
+use crate::creator_authority::{
+    fund_creator_authority_lamports, refund_creator_authority, CreatorAuthorityLamportsAccounts,
+};
+use crate::dlmm;
+use crate::dlmm::accounts::{BinArray, BinArrayBitmapExtension, LbPair, Oracle};
+use crate::dlmm::constants::{BIN_ARRAY, BIN_ARRAY_BITMAP_SEED};
+use crate::dlmm::types::{CustomizableParams, Observation};
+use crate::dlmm_utils::bin_id_to_bin_array_index;
+use crate::errors::CpiExampleError;
+use crate::events::EvtInitializeLbPair;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount, Transfer as TokenTransfer},
+};
+
+/// Number of observations allocated by DLMM program when the oracle is initialized
+const DEFAULT_OBSERVATION_LENGTH: usize = 100;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: CustomizableParams)]
+pub struct DlmmInitializeCustomizablePermissionlessLbPairPdaCreator<'info> {
+    /// CHECK: Creator authority. PDA. Creator and funder of the pool.
+    #[account(
+        mut,
+        seeds = [b"creator", owner.key().as_ref(), lb_pair.key().as_ref()],
+        bump
+    )]
+    pub creator_authority: UncheckedAccount<'info>,
+
+    /// Owner of the creator authority PDA. Creator authorities are isolated per owner and pool.
+    pub owner: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        associated_token::mint = token_mint_x,
+        associated_token::authority = creator_authority,
+        payer = payer
+    )]
+    pub creator_token_x: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    /// CHECK: Payer token account of token X. Used to fund the creator authority.
+    pub payer_token_x: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Pool account. PDA of DLMM program. DLMM do the checking.
+    pub lb_pair: UncheckedAccount<'info>,
+
+    /// CHECK: Bin array bitmap extension account of the pool. Only required when the active bin is out of the pool bitmap range.
+    #[account(
+        mut,
+        seeds = [
+            BIN_ARRAY_BITMAP_SEED,
+            lb_pair.key().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+    )]
+    pub bin_array_bitmap_extension: Option<UncheckedAccount<'info>>,
+
+    /// Mint account of token X
+    pub token_mint_x: Box<Account<'info, Mint>>,
+    /// Mint account of token Y
+    pub token_mint_y: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    /// CHECK: Reserve account of token X. DLMM do the checking.
+    pub reserve_x: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Reserve account of token Y. DLMM do the checking.
+    pub reserve_y: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Oracle account of the pool. DLMM do the checking.
+    pub oracle: UncheckedAccount<'info>,
+
+    /// CHECK: Bin array account containing the active bin
+    #[account(
+        mut,
+        seeds = [
+            BIN_ARRAY,
+            lb_pair.key().as_ref(),
+            bin_id_to_bin_array_index(params.active_id).to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+    )]
+    pub bin_array: UncheckedAccount<'info>,
+
+    /// Program config. Stores the admin of the creator authority PDA instructions.
+    #[account(seeds = [b"config"], bump = program_config.bump)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+
+    /// CHECK: CPI example program admin. Only admin can call this instruction. Fund the creator authority for account rental.
+    #[account(
+        mut,
+        constraint = program_config.admin == payer.key() @ CpiExampleError::InvalidAdmin
+    )]
+    pub payer: Signer<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+    /// Associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+    /// Rent account.
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(address = dlmm::ID)]
+    /// CHECK: DLMM program
+    pub dlmm_program: UncheckedAccount<'info>,
+
+    /// CHECK: DLMM program event authority for event CPI
+    pub dlmm_event_authority: UncheckedAccount<'info>,
+}
+
+impl<'info> DlmmInitializeCustomizablePermissionlessLbPairPdaCreator<'info> {
+    /// Lamports paying for the rental of the accounts created by the pool creation. The estimate can exceed the actual
+    /// rental, so the unused lamports are refunded after the pool creation.
+    fn creator_authority_rent_lamports(&self) -> Result<u64> {
+        let rent = Rent::get()?;
+
+        // Pool
+        let mut lamports = rent.minimum_balance(8 + std::mem::size_of::<LbPair>());
+        // Reserve X + reserve Y
+        lamports += rent.minimum_balance(TokenAccount::LEN) * 2;
+        // Oracle
+        lamports += rent.minimum_balance(
+            8 + std::mem::size_of::<Oracle>()
+                + DEFAULT_OBSERVATION_LENGTH * std::mem::size_of::<Observation>(),
+        );
+        // Active bin array
+        lamports += rent.minimum_balance(8 + std::mem::size_of::<BinArray>());
+        // Bin array bitmap extension
+        if self.bin_array_bitmap_extension.is_some() {
+            lamports += rent.minimum_balance(8 + std::mem::size_of::<BinArrayBitmapExtension>());
+        }
+
+        Ok(lamports)
+    }
+}
+
+/// Executes a DLMM initialize customizable permissionless pool with PDA as creator / funder. The active bin array and
+/// the bin array bitmap extension (if provided) are initialized in the same instruction.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `params` - The parameters for the pool.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_dlmm_initialize_customizable_permissionless_lb_pair_pda_creator(
+    ctx: Context<DlmmInitializeCustomizablePermissionlessLbPairPdaCreator>,
+    params: CustomizableParams,
+) -> Result<()> {
+    // DLMM requires the pool creator to hold token X as proof of token launch owner
+    if ctx.accounts.creator_token_x.amount == 0 {
+        anchor_spl::token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TokenTransfer {
+                    from: ctx.accounts.payer_token_x.to_account_info(),
+                    to: ctx.accounts.creator_token_x.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+    }
+
+    // Fund creator PDA with SOL to pay for account rental
+    let lamports_accounts = CreatorAuthorityLamportsAccounts {
+        creator_authority: &ctx.accounts.creator_authority,
+        payer: &ctx.accounts.payer,
+        system_program: &ctx.accounts.system_program,
+    };
+    let creator_authority_lamports = ctx.accounts.creator_authority.lamports();
+
+    fund_creator_authority_lamports(
+        ctx.accounts.creator_authority_rent_lamports()?,
+        &lamports_accounts,
+    )?;
+
+    let owner_key = ctx.accounts.owner.key();
+    let lb_pair_key = ctx.accounts.lb_pair.key();
+    let seeds = [
+        b"creator".as_ref(),
+        owner_key.as_ref(),
+        lb_pair_key.as_ref(),
+        &[ctx.bumps.creator_authority],
+    ];
+
+    let signer_seeds = &[&seeds[..]];
+
+    // 1. Initialize pool and bin array bitmap extension
+    let accounts = dlmm::cpi::accounts::InitializeCustomizablePermissionlessLbPair {
+        lb_pair: ctx.accounts.lb_pair.to_account_info(),
+        // Bin array bitmap extension will be initialized by DLMM program if provided
+        bin_array_bitmap_extension: ctx
+            .accounts
+            .bin_array_bitmap_extension
+            .as_ref()
+            .map(|account| account.to_account_info()),
+        token_mint_x: ctx.accounts.token_mint_x.to_account_info(),
+        token_mint_y: ctx.accounts.token_mint_y.to_account_info(),
+        reserve_x: ctx.accounts.reserve_x.to_account_info(),
+        reserve_y: ctx.accounts.reserve_y.to_account_info(),
+        oracle: ctx.accounts.oracle.to_account_info(),
+        user_token_x: ctx.accounts.creator_token_x.to_account_info(),
+        funder: ctx.accounts.creator_authority.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+        event_authority: ctx.accounts.dlmm_event_authority.to_account_info(),
+        program: ctx.accounts.dlmm_program.to_account_info(),
+    };
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.dlmm_program.to_account_info(),
+        accounts,
+        signer_seeds,
+    );
+
+    dlmm::cpi::initialize_customizable_permissionless_lb_pair(cpi_context, params)?;
+
+    // 2. Initialize active bin array
+    let accounts = dlmm::cpi::accounts::InitializeBinArray {
+        lb_pair: ctx.accounts.lb_pair.to_account_info(),
+        bin_array: ctx.accounts.bin_array.to_account_info(),
+        funder: ctx.accounts.creator_authority.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.dlmm_program.to_account_info(),
+        accounts,
+        signer_seeds,
+    );
+
+    dlmm::cpi::initialize_bin_array(cpi_context, bin_id_to_bin_array_index(params.active_id))?;
+
+    refund_creator_authority(creator_authority_lamports, &lamports_accounts, signer_seeds)?;
+
+    emit_cpi!(EvtInitializeLbPair {
+        lb_pair: ctx.accounts.lb_pair.key(),
+        creator: ctx.accounts.creator_authority.key(),
+        token_x_mint: ctx.accounts.token_mint_x.key(),
+        token_y_mint: ctx.accounts.token_mint_y.key(),
+        active_id: params.active_id,
+        bin_step: params.bin_step,
+    });
+
+    Ok(())
+}
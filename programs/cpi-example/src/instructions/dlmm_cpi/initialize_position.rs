@@ -0,0 +1,112 @@
+use crate::dlmm;
+use crate::dlmm::accounts::LbPair;
+use crate::dlmm::constants::POSITION;
+use crate::dlmm_utils::is_valid_position_range;
+use crate::errors::CpiExampleError;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(lower_bin_id: i32, width: i32)]
+pub struct DlmmInitializePositionPdaCreator<'info> {
+    /// CHECK: Creator authority. PDA. Owner and base of the position.
+    #[account(
+        seeds = [b"creator", owner.key().as_ref(), lb_pair.key().as_ref()],
+        bump
+    )]
+    pub creator_authority: UncheckedAccount<'info>,
+
+    /// Owner of the creator authority PDA. Creator authorities are isolated per owner and pool.
+    pub owner: Signer<'info>,
+
+    /// CHECK: Position account. PDA of DLMM program derived from the pool, creator authority and bin range.
+    #[account(
+        mut,
+        seeds = [
+            POSITION,
+            lb_pair.key().as_ref(),
+            creator_authority.key().as_ref(),
+            lower_bin_id.to_le_bytes().as_ref(),
+            width.to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+        constraint = is_valid_position_range(lower_bin_id, width)
+    )]
+    pub position: UncheckedAccount<'info>,
+
+    /// The pool account
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    /// Program config. Stores the admin of the creator authority PDA instructions.
+    #[account(seeds = [b"config"], bump = program_config.bump)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+
+    /// CHECK: CPI example program admin. Only admin can call this instruction. Also funder for account rental.
+    #[account(
+        mut,
+        constraint = program_config.admin == payer.key() @ CpiExampleError::InvalidAdmin
+    )]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    /// Rent account.
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(address = dlmm::ID)]
+    /// CHECK: DLMM program
+    pub dlmm_program: UncheckedAccount<'info>,
+
+    /// CHECK: DLMM program event authority for event CPI
+    pub dlmm_event_authority: UncheckedAccount<'info>,
+}
+
+/// Initializes a DLMM position owned by the creator authority PDA.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `lower_bin_id` - The lower bin id of the position.
+/// * `width` - The number of bins covered by the position.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_dlmm_initialize_position_pda_creator(
+    ctx: Context<DlmmInitializePositionPdaCreator>,
+    lower_bin_id: i32,
+    width: i32,
+) -> Result<()> {
+    let accounts = dlmm::cpi::accounts::InitializePositionPda {
+        payer: ctx.accounts.payer.to_account_info(),
+        base: ctx.accounts.creator_authority.to_account_info(),
+        position: ctx.accounts.position.to_account_info(),
+        lb_pair: ctx.accounts.lb_pair.to_account_info(),
+        owner: ctx.accounts.creator_authority.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+        event_authority: ctx.accounts.dlmm_event_authority.to_account_info(),
+        program: ctx.accounts.dlmm_program.to_account_info(),
+    };
+
+    let owner_key = ctx.accounts.owner.key();
+    let lb_pair_key = ctx.accounts.lb_pair.key();
+    let seeds = [
+        b"creator".as_ref(),
+        owner_key.as_ref(),
+        lb_pair_key.as_ref(),
+        &[ctx.bumps.creator_authority],
+    ];
+
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.dlmm_program.to_account_info(),
+        accounts,
+        signer_seeds,
+    );
+
+    dlmm::cpi::initialize_position_pda(cpi_context, lower_bin_id, width)
+}
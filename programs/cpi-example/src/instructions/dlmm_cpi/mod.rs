@@ -1,5 +1,60 @@
+mod add_liquidity;
+mod add_liquidity_one_side;
+mod claim_fee;
+mod claim_reward;
+mod close_position;
+mod initialize_lb_pair;
+mod initialize_position;
+mod remove_liquidity;
 mod swap;
+mod swap_exact_out;
+mod swap_with_price_impact;
+mod utils;
 
 pub mod dlmm_swap {
     pub use super::swap::*;
 }
+
+pub mod dlmm_swap_exact_out {
+    pub use super::swap_exact_out::*;
+}
+
+pub mod dlmm_swap_with_price_impact {
+    pub use super::swap_with_price_impact::*;
+}
+
+pub mod dlmm_initialize_lb_pair {
+    pub use super::initialize_lb_pair::*;
+}
+
+pub mod dlmm_initialize_position {
+    pub use super::initialize_position::*;
+}
+
+pub mod dlmm_add_liquidity {
+    pub use super::add_liquidity::*;
+}
+
+pub mod dlmm_add_liquidity_one_side {
+    pub use super::add_liquidity_one_side::*;
+}
+
+pub mod dlmm_remove_liquidity {
+    pub use super::remove_liquidity::*;
+}
+
+pub mod dlmm_close_position {
+    pub use super::close_position::*;
+}
+
+pub mod dlmm_claim_fee {
+    pub use super::claim_fee::*;
+}
+
+pub mod dlmm_claim_reward {
+    pub use super::claim_reward::*;
+}
+
+pub mod dlmm_utils {
+    pub use super::utils::*;
+}
@@ -0,0 +1,274 @@
+use crate::dlmm;
+use crate::dlmm::accounts::LbPair;
+use crate::dlmm::constants::{BIN_ARRAY, POSITION};
+use crate::dlmm_utils::{bin_id_to_bin_array_index, position_upper_bin_id};
+use crate::errors::CpiExampleError;
+use crate::state::{ProgramConfig, RemoveLiquidityResult};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(lower_bin_id: i32, width: i32)]
+pub struct DlmmRemoveLiquidityPdaCreator<'info> {
+    /// CHECK: Creator authority. PDA. Owner of the position.
+    #[account(
+        seeds = [b"creator", owner.key().as_ref(), lb_pair.key().as_ref()],
+        bump
+    )]
+    pub creator_authority: UncheckedAccount<'info>,
+
+    /// Owner of the creator authority PDA. Creator authorities are isolated per owner and pool.
+    pub owner: Signer<'info>,
+
+    /// CHECK: Position account. PDA of DLMM program derived from the pool, creator authority and bin range.
+    #[account(
+        mut,
+        seeds = [
+            POSITION,
+            lb_pair.key().as_ref(),
+            creator_authority.key().as_ref(),
+            lower_bin_id.to_le_bytes().as_ref(),
+            width.to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+    )]
+    pub position: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// The pool account
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    #[account(mut)]
+    /// CHECK: Bin array extension account of the pool
+    pub bin_array_bitmap_extension: Option<UncheckedAccount<'info>>,
+
+    /// Destination token account of token X. Chosen by the payer.
+    #[account(
+        mut,
+        token::mint = token_x_mint,
+        token::token_program = token_program,
+    )]
+    pub receiver_token_x: Box<Account<'info, TokenAccount>>,
+
+    /// Destination token account of token Y. Chosen by the payer.
+    #[account(
+        mut,
+        token::mint = token_y_mint,
+        token::token_program = token_program,
+    )]
+    pub receiver_token_y: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    /// CHECK: Reserve account of token X
+    pub reserve_x: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Reserve account of token Y
+    pub reserve_y: UncheckedAccount<'info>,
+
+    /// Mint account of token X
+    pub token_x_mint: Box<Account<'info, Mint>>,
+    /// Mint account of token Y
+    pub token_y_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Bin array account containing the lower bin of the position
+    #[account(
+        mut,
+        seeds = [
+            BIN_ARRAY,
+            lb_pair.key().as_ref(),
+            bin_id_to_bin_array_index(lower_bin_id).to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+    )]
+    pub bin_array_lower: UncheckedAccount<'info>,
+
+    /// CHECK: Bin array account containing the upper bin of the position
+    #[account(
+        mut,
+        seeds = [
+            BIN_ARRAY,
+            lb_pair.key().as_ref(),
+            bin_id_to_bin_array_index(position_upper_bin_id(lower_bin_id, width)).to_le_bytes().as_ref(),
+        ],
+        bump,
+        seeds::program = dlmm::ID,
+    )]
+    pub bin_array_upper: UncheckedAccount<'info>,
+
+    /// Program config. Stores the admin of the creator authority PDA instructions.
+    #[account(seeds = [b"config"], bump = program_config.bump)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+
+    /// CHECK: CPI example program admin. Only admin can call this instruction.
+    #[account(
+        constraint = program_config.admin == payer.key() @ CpiExampleError::InvalidAdmin
+    )]
+    pub payer: Signer<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = dlmm::ID)]
+    /// CHECK: DLMM program
+    pub dlmm_program: UncheckedAccount<'info>,
+
+    /// CHECK: DLMM program event authority for event CPI
+    pub dlmm_event_authority: UncheckedAccount<'info>,
+}
+
+impl<'info> DlmmRemoveLiquidityPdaCreator<'info> {
+    /// Current balances of the destination token accounts
+    fn receiver_balances(&self) -> (u64, u64) {
+        (self.receiver_token_x.amount, self.receiver_token_y.amount)
+    }
+
+    /// Reloads the destination token accounts and returns the withdrawn amounts
+    fn withdrawn_amounts(&mut self, before: (u64, u64)) -> Result<RemoveLiquidityResult> {
+        self.receiver_token_x.reload()?;
+        self.receiver_token_y.reload()?;
+
+        let (after_x, after_y) = self.receiver_balances();
+        let amount_x = after_x
+            .checked_sub(before.0)
+            .ok_or(CpiExampleError::MathOverflow)?;
+        let amount_y = after_y
+            .checked_sub(before.1)
+            .ok_or(CpiExampleError::MathOverflow)?;
+
+        Ok(RemoveLiquidityResult { amount_x, amount_y })
+    }
+}
+
+/// Removes liquidity of a bin range from a DLMM position owned by the creator authority PDA. The withdrawn tokens are sent to the receiver token accounts.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `lower_bin_id` - The lower bin id of the position.
+/// * `width` - The number of bins covered by the position.
+/// * `from_bin_id` - The first bin id to withdraw from.
+/// * `to_bin_id` - The last bin id to withdraw from.
+/// * `bps_to_remove` - The share of liquidity to withdraw from each bin, in basis points.
+///
+/// # Returns
+///
+/// Returns the [`RemoveLiquidityResult`], which is also set as the return data of the instruction.
+pub fn handle_dlmm_remove_liquidity_by_range_pda_creator(
+    ctx: Context<DlmmRemoveLiquidityPdaCreator>,
+    _lower_bin_id: i32,
+    _width: i32,
+    from_bin_id: i32,
+    to_bin_id: i32,
+    bps_to_remove: u16,
+) -> Result<RemoveLiquidityResult> {
+    let before = ctx.accounts.receiver_balances();
+
+    let accounts = dlmm::cpi::accounts::RemoveLiquidityByRange {
+        position: ctx.accounts.position.to_account_info(),
+        lb_pair: ctx.accounts.lb_pair.to_account_info(),
+        bin_array_bitmap_extension: ctx
+            .accounts
+            .bin_array_bitmap_extension
+            .as_ref()
+            .map(|account| account.to_account_info()),
+        user_token_x: ctx.accounts.receiver_token_x.to_account_info(),
+        user_token_y: ctx.accounts.receiver_token_y.to_account_info(),
+        reserve_x: ctx.accounts.reserve_x.to_account_info(),
+        reserve_y: ctx.accounts.reserve_y.to_account_info(),
+        token_x_mint: ctx.accounts.token_x_mint.to_account_info(),
+        token_y_mint: ctx.accounts.token_y_mint.to_account_info(),
+        bin_array_lower: ctx.accounts.bin_array_lower.to_account_info(),
+        bin_array_upper: ctx.accounts.bin_array_upper.to_account_info(),
+        sender: ctx.accounts.creator_authority.to_account_info(),
+        token_x_program: ctx.accounts.token_program.to_account_info(),
+        token_y_program: ctx.accounts.token_program.to_account_info(),
+        event_authority: ctx.accounts.dlmm_event_authority.to_account_info(),
+        program: ctx.accounts.dlmm_program.to_account_info(),
+    };
+
+    let owner_key = ctx.accounts.owner.key();
+    let lb_pair_key = ctx.accounts.lb_pair.key();
+    let seeds = [
+        b"creator".as_ref(),
+        owner_key.as_ref(),
+        lb_pair_key.as_ref(),
+        &[ctx.bumps.creator_authority],
+    ];
+
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.dlmm_program.to_account_info(),
+        accounts,
+        signer_seeds,
+    );
+
+    dlmm::cpi::remove_liquidity_by_range(cpi_context, from_bin_id, to_bin_id, bps_to_remove)?;
+
+    ctx.accounts.withdrawn_amounts(before)
+}
+
+/// Removes all liquidity from a DLMM position owned by the creator authority PDA. The withdrawn tokens are sent to the receiver token accounts.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `lower_bin_id` - The lower bin id of the position.
+/// * `width` - The number of bins covered by the position.
+///
+/// # Returns
+///
+/// Returns the [`RemoveLiquidityResult`], which is also set as the return data of the instruction.
+pub fn handle_dlmm_remove_all_liquidity_pda_creator(
+    ctx: Context<DlmmRemoveLiquidityPdaCreator>,
+    _lower_bin_id: i32,
+    _width: i32,
+) -> Result<RemoveLiquidityResult> {
+    let before = ctx.accounts.receiver_balances();
+
+    let accounts = dlmm::cpi::accounts::RemoveAllLiquidity {
+        position: ctx.accounts.position.to_account_info(),
+        lb_pair: ctx.accounts.lb_pair.to_account_info(),
+        bin_array_bitmap_extension: ctx
+            .accounts
+            .bin_array_bitmap_extension
+            .as_ref()
+            .map(|account| account.to_account_info()),
+        user_token_x: ctx.accounts.receiver_token_x.to_account_info(),
+        user_token_y: ctx.accounts.receiver_token_y.to_account_info(),
+        reserve_x: ctx.accounts.reserve_x.to_account_info(),
+        reserve_y: ctx.accounts.reserve_y.to_account_info(),
+        token_x_mint: ctx.accounts.token_x_mint.to_account_info(),
+        token_y_mint: ctx.accounts.token_y_mint.to_account_info(),
+        bin_array_lower: ctx.accounts.bin_array_lower.to_account_info(),
+        bin_array_upper: ctx.accounts.bin_array_upper.to_account_info(),
+        sender: ctx.accounts.creator_authority.to_account_info(),
+        token_x_program: ctx.accounts.token_program.to_account_info(),
+        token_y_program: ctx.accounts.token_program.to_account_info(),
+        event_authority: ctx.accounts.dlmm_event_authority.to_account_info(),
+        program: ctx.accounts.dlmm_program.to_account_info(),
+    };
+
+    let owner_key = ctx.accounts.owner.key();
+    let lb_pair_key = ctx.accounts.lb_pair.key();
+    let seeds = [
+        b"creator".as_ref(),
+        owner_key.as_ref(),
+        lb_pair_key.as_ref(),
+        &[ctx.bumps.creator_authority],
+    ];
+
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.dlmm_program.to_account_info(),
+        accounts,
+        signer_seeds,
+    );
+
+    dlmm::cpi::remove_all_liquidity(cpi_context)?;
+
+    ctx.accounts.withdrawn_amounts(before)
+}
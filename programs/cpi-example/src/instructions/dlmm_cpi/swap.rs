@@ -1,11 +1,19 @@
 use crate::dlmm;
+use crate::dlmm::accounts::LbPair;
+use crate::dlmm_utils::{get_active_id, validate_swap_bin_arrays};
+use crate::errors::CpiExampleError;
+use crate::events::EvtSwap;
+use crate::state::{IntegratorFee, SwapResult};
+use crate::token_utils::{load_mint, load_token_account, IntegratorFeeCharge, SwapBalances};
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TransferChecked;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DlmmSwap<'info> {
     #[account(mut)]
-    /// CHECK: The pool account
-    pub lb_pair: UncheckedAccount<'info>,
+    /// The pool account
+    pub lb_pair: AccountLoader<'info, LbPair>,
 
     /// CHECK: Bin array extension account of the pool
     pub bin_array_bitmap_extension: Option<UncheckedAccount<'info>>,
@@ -34,9 +42,13 @@ pub struct DlmmSwap<'info> {
     pub oracle: UncheckedAccount<'info>,
 
     #[account(mut)]
-    /// CHECK: Referral fee account
+    /// CHECK: Referral fee account. Defaults to the integrator fee token account when the integrator fee is taken from the input token.
     pub host_fee_in: Option<UncheckedAccount<'info>>,
 
+    #[account(mut)]
+    /// CHECK: Associated token account of the integrator receiving the integrator fee. Validated by the handler. Only used by dlmm_swap, other swap instructions reject it.
+    pub integrator_fee_token: Option<UncheckedAccount<'info>>,
+
     /// CHECK: User who's executing the swap
     pub user: Signer<'info>,
 
@@ -45,31 +57,89 @@ pub struct DlmmSwap<'info> {
     pub dlmm_program: UncheckedAccount<'info>,
 
     /// CHECK: DLMM program event authority for event CPI
-    pub event_authority: UncheckedAccount<'info>,
+    pub dlmm_event_authority: UncheckedAccount<'info>,
 
     /// CHECK: Token program of mint X
     pub token_x_program: UncheckedAccount<'info>,
     /// CHECK: Token program of mint Y
     pub token_y_program: UncheckedAccount<'info>,
-    // Bin arrays need to be passed using remaining accounts
+    // Bin arrays need to be passed using remaining accounts, ordered by the swap direction starting from the active bin array
 }
 
-/// Executes a DLMM swap
+/// Executes a DLMM swap, and returns the actual amounts of the swap measured from the user token balances.
+///
+/// When the integrator fee is charged, the fee is transferred to the integrator fee token account from the input token
+/// before the swap, or from the output token after the swap. The returned amounts and the slippage check include the fee.
 ///
 /// # Arguments
 ///
 /// * `ctx` - The context containing accounts and programs.
-/// * `amount_in` - The amount of input tokens to be swapped.
+/// * `amount_in` - The amount of input tokens to be swapped, including the integrator fee.
 /// * `min_amount_out` - The minimum amount of output tokens expected a.k.a slippage
+/// * `integrator_fee` - The optional integrator fee charged on the swap.
 ///
 /// # Returns
 ///
-/// Returns a `Result` indicating success or failure.
+/// Returns the [`SwapResult`], which is also set as the return data of the instruction.
 pub fn handle_dlmm_swap<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, DlmmSwap<'info>>,
     amount_in: u64,
     min_amount_out: u64,
-) -> Result<()> {
+    integrator_fee: Option<IntegratorFee>,
+) -> Result<SwapResult> {
+    let balances_before =
+        SwapBalances::load(&ctx.accounts.user_token_in, &ctx.accounts.user_token_out)?;
+    let swap_for_y = balances_before.input_mint == ctx.accounts.token_x_mint.key();
+
+    let active_id = get_active_id(&ctx.accounts.lb_pair)?;
+
+    validate_swap_bin_arrays(
+        ctx.accounts.lb_pair.key(),
+        active_id,
+        swap_for_y,
+        ctx.remaining_accounts,
+    )?;
+
+    let integrator_fee_charge = IntegratorFeeCharge::new(
+        integrator_fee,
+        ctx.accounts
+            .integrator_fee_token
+            .as_ref()
+            .map(|account| account.as_ref()),
+        &balances_before,
+    )?;
+
+    let mut swap_amount_in = amount_in;
+    if let Some(charge) = integrator_fee_charge
+        .as_ref()
+        .filter(|charge| charge.is_input_fee())
+    {
+        let fee_amount = charge.fee_amount(amount_in)?;
+        transfer_integrator_fee(
+            ctx.accounts,
+            &ctx.accounts.user_token_in,
+            charge,
+            swap_for_y,
+            fee_amount,
+        )?;
+        swap_amount_in = amount_in
+            .checked_sub(fee_amount)
+            .ok_or(CpiExampleError::MathOverflow)?;
+    }
+
+    // The integrator also earns the referral share of the DLMM protocol fee, which is paid in the input token
+    let host_fee_in = ctx
+        .accounts
+        .host_fee_in
+        .as_ref()
+        .map(|account| account.to_account_info())
+        .or_else(|| {
+            integrator_fee_charge
+                .as_ref()
+                .filter(|charge| charge.is_input_fee())
+                .map(|charge| charge.fee_token.clone())
+        });
+
     let accounts = dlmm::cpi::accounts::Swap {
         lb_pair: ctx.accounts.lb_pair.to_account_info(),
         bin_array_bitmap_extension: ctx
@@ -84,19 +154,82 @@ pub fn handle_dlmm_swap<'a, 'b, 'c, 'info>(
         token_x_mint: ctx.accounts.token_x_mint.to_account_info(),
         token_y_mint: ctx.accounts.token_y_mint.to_account_info(),
         oracle: ctx.accounts.oracle.to_account_info(),
-        host_fee_in: ctx
-            .accounts
-            .host_fee_in
-            .as_ref()
-            .map(|account| account.to_account_info()),
+        host_fee_in,
         user: ctx.accounts.user.to_account_info(),
         token_x_program: ctx.accounts.token_x_program.to_account_info(),
         token_y_program: ctx.accounts.token_y_program.to_account_info(),
-        event_authority: ctx.accounts.event_authority.to_account_info(),
+        event_authority: ctx.accounts.dlmm_event_authority.to_account_info(),
         program: ctx.accounts.dlmm_program.to_account_info(),
     };
 
     let cpi_context = CpiContext::new(ctx.accounts.dlmm_program.to_account_info(), accounts)
         .with_remaining_accounts(ctx.remaining_accounts.to_vec());
-    dlmm::cpi::swap(cpi_context, amount_in, min_amount_out)
+    dlmm::cpi::swap(cpi_context, swap_amount_in, min_amount_out)?;
+
+    if let Some(charge) = integrator_fee_charge
+        .as_ref()
+        .filter(|charge| !charge.is_input_fee())
+    {
+        let user_token_out_amount = load_token_account(&ctx.accounts.user_token_out)?.amount;
+        let swap_amount_out = user_token_out_amount
+            .checked_sub(balances_before.user_token_out_amount)
+            .ok_or(CpiExampleError::MathOverflow)?;
+        transfer_integrator_fee(
+            ctx.accounts,
+            &ctx.accounts.user_token_out,
+            charge,
+            !swap_for_y,
+            charge.fee_amount(swap_amount_out)?,
+        )?;
+    }
+
+    let swap_result = balances_before.swap_result(
+        &ctx.accounts.user_token_in,
+        &ctx.accounts.user_token_out,
+        min_amount_out,
+    )?;
+
+    emit_cpi!(EvtSwap {
+        pool: ctx.accounts.lb_pair.key(),
+        user: ctx.accounts.user.key(),
+        input_mint: balances_before.input_mint,
+        output_mint: balances_before.output_mint,
+        amount_in: swap_result.amount_in,
+        amount_out: swap_result.amount_out,
+    });
+
+    Ok(swap_result)
+}
+
+/// Transfers the integrator fee from the user token account of the charged mint
+fn transfer_integrator_fee<'info>(
+    accounts: &DlmmSwap<'info>,
+    from: &UncheckedAccount<'info>,
+    charge: &IntegratorFeeCharge<'info>,
+    is_token_x: bool,
+    fee_amount: u64,
+) -> Result<()> {
+    if fee_amount == 0 {
+        return Ok(());
+    }
+
+    let (mint, token_program) = if is_token_x {
+        (&accounts.token_x_mint, &accounts.token_x_program)
+    } else {
+        (&accounts.token_y_mint, &accounts.token_y_program)
+    };
+
+    anchor_spl::token_interface::transfer_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: charge.fee_token.clone(),
+                authority: accounts.user.to_account_info(),
+            },
+        ),
+        fee_amount,
+        load_mint(mint)?.decimals,
+    )
 }
@@ -0,0 +1,80 @@
+use crate::dlmm;
+use crate::dlmm_swap::DlmmSwap;
+use crate::errors::CpiExampleError;
+use crate::events::EvtSwap;
+use crate::token_utils::SwapBalances;
+use anchor_lang::prelude::*;
+
+/// Executes a DLMM swap with exact output amount
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `max_in_amount` - The maximum amount of input tokens to be swapped a.k.a slippage
+/// * `out_amount` - The exact amount of output tokens to be received.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_dlmm_swap_exact_out<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, DlmmSwap<'info>>,
+    max_in_amount: u64,
+    out_amount: u64,
+) -> Result<()> {
+    // The integrator fee is only charged by dlmm_swap
+    require!(
+        ctx.accounts.integrator_fee_token.is_none(),
+        CpiExampleError::InvalidIntegratorFee
+    );
+
+    let balances_before =
+        SwapBalances::load(&ctx.accounts.user_token_in, &ctx.accounts.user_token_out)?;
+
+    let accounts = dlmm::cpi::accounts::SwapExactOut {
+        lb_pair: ctx.accounts.lb_pair.to_account_info(),
+        bin_array_bitmap_extension: ctx
+            .accounts
+            .bin_array_bitmap_extension
+            .as_ref()
+            .map(|account| account.to_account_info()),
+        reserve_x: ctx.accounts.reserve_x.to_account_info(),
+        reserve_y: ctx.accounts.reserve_y.to_account_info(),
+        user_token_in: ctx.accounts.user_token_in.to_account_info(),
+        user_token_out: ctx.accounts.user_token_out.to_account_info(),
+        token_x_mint: ctx.accounts.token_x_mint.to_account_info(),
+        token_y_mint: ctx.accounts.token_y_mint.to_account_info(),
+        oracle: ctx.accounts.oracle.to_account_info(),
+        host_fee_in: ctx
+            .accounts
+            .host_fee_in
+            .as_ref()
+            .map(|account| account.to_account_info()),
+        user: ctx.accounts.user.to_account_info(),
+        token_x_program: ctx.accounts.token_x_program.to_account_info(),
+        token_y_program: ctx.accounts.token_y_program.to_account_info(),
+        event_authority: ctx.accounts.dlmm_event_authority.to_account_info(),
+        program: ctx.accounts.dlmm_program.to_account_info(),
+    };
+
+    let cpi_context = CpiContext::new(ctx.accounts.dlmm_program.to_account_info(), accounts)
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    dlmm::cpi::swap_exact_out(cpi_context, max_in_amount, out_amount)?;
+
+    // Slippage is enforced by DLMM program
+    let swap_result = balances_before.swap_result(
+        &ctx.accounts.user_token_in,
+        &ctx.accounts.user_token_out,
+        0,
+    )?;
+
+    emit_cpi!(EvtSwap {
+        pool: ctx.accounts.lb_pair.key(),
+        user: ctx.accounts.user.key(),
+        input_mint: balances_before.input_mint,
+        output_mint: balances_before.output_mint,
+        amount_in: swap_result.amount_in,
+        amount_out: swap_result.amount_out,
+    });
+
+    Ok(())
+}
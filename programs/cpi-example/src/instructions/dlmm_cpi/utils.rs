@@ -0,0 +1,107 @@
+use crate::dlmm;
+use crate::dlmm::accounts::{BinArray, LbPair};
+use crate::dlmm::constants::{
+    BIN_ARRAY, MAX_BIN_ID, MAX_BIN_PER_ARRAY, MAX_BIN_PER_POSITION, MIN_BIN_ID,
+};
+use crate::errors::CpiExampleError;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// Get bin array index from bin id
+pub fn bin_id_to_bin_array_index(bin_id: i32) -> i64 {
+    bin_id.div_euclid(MAX_BIN_PER_ARRAY as i32).into()
+}
+
+/// Get the upper bin id of a position from its lower bin id and width
+pub fn position_upper_bin_id(lower_bin_id: i32, width: i32) -> i32 {
+    lower_bin_id.saturating_add(width).saturating_sub(1)
+}
+
+/// Check that the position range is within the pool bin range and does not exceed the maximum position width
+pub fn is_valid_position_range(lower_bin_id: i32, width: i32) -> bool {
+    let upper_bin_id = position_upper_bin_id(lower_bin_id, width);
+
+    width > 0
+        && width <= MAX_BIN_PER_POSITION as i32
+        && lower_bin_id >= MIN_BIN_ID
+        && upper_bin_id <= MAX_BIN_ID
+}
+
+/// Check that the bin range [min_bin_id, max_bin_id] is within the position range
+pub fn is_within_position_range(
+    lower_bin_id: i32,
+    width: i32,
+    min_bin_id: i32,
+    max_bin_id: i32,
+) -> bool {
+    min_bin_id <= max_bin_id
+        && min_bin_id >= lower_bin_id
+        && max_bin_id <= position_upper_bin_id(lower_bin_id, width)
+}
+
+/// Get the active bin id of the pool.
+/// Only read the active id, as the pool account data is not guaranteed to be aligned for a zero copy load.
+pub fn get_active_id(lb_pair: &AccountLoader<LbPair>) -> Result<i32> {
+    let offset = 8 + std::mem::offset_of!(LbPair, active_id);
+    let data = lb_pair.as_ref().try_borrow_data()?;
+    let active_id = data
+        .get(offset..offset + 4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(i32::from_le_bytes)
+        .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+
+    Ok(active_id)
+}
+
+/// Check that the bin arrays are bin array PDAs of the pool, start from the bin array of the active bin, and are ordered by the swap direction.
+/// Bin id decreases when swapping token X to token Y, and increases when swapping token Y to token X.
+pub fn validate_swap_bin_arrays(
+    lb_pair: Pubkey,
+    active_id: i32,
+    swap_for_y: bool,
+    bin_arrays: &[AccountInfo],
+) -> Result<()> {
+    require!(!bin_arrays.is_empty(), CpiExampleError::MissingBinArray);
+
+    let mut previous_index: Option<i64> = None;
+
+    for bin_array in bin_arrays {
+        require_keys_eq!(*bin_array.owner, dlmm::ID, CpiExampleError::InvalidBinArray);
+
+        let data = bin_array.try_borrow_data()?;
+        require!(
+            data.len() >= 8 + std::mem::size_of::<BinArray>()
+                && data[..8] == *BinArray::DISCRIMINATOR,
+            CpiExampleError::InvalidBinArray
+        );
+
+        // Index is the first field of the bin array. Only read the index to avoid copying the whole bin array to the stack.
+        let index = i64::from_le_bytes(
+            data[8..16]
+                .try_into()
+                .map_err(|_| CpiExampleError::InvalidBinArray)?,
+        );
+
+        let (bin_array_key, _bump) = Pubkey::find_program_address(
+            &[BIN_ARRAY, lb_pair.as_ref(), index.to_le_bytes().as_ref()],
+            &dlmm::ID,
+        );
+        require_keys_eq!(
+            bin_array_key,
+            bin_array.key(),
+            CpiExampleError::InvalidBinArray
+        );
+
+        let is_ordered = match previous_index {
+            Some(previous_index) if swap_for_y => index < previous_index,
+            Some(previous_index) => index > previous_index,
+            // The swap starts from the active bin
+            None => index == bin_id_to_bin_array_index(active_id),
+        };
+        require!(is_ordered, CpiExampleError::InvalidBinArrayOrder);
+
+        previous_index = Some(index);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,501 @@
+use crate::dynamic_amm;
+use crate::dynamic_amm::accounts::Pool;
+use crate::errors::CpiExampleError;
+use crate::events::{EvtAddLiquidity, EvtRemoveLiquidity};
+use crate::state::ProgramConfig;
+use crate::token_utils::LiquidityBalances;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use dynamic_amm::cpi::accounts::{AddBalanceLiquidity, RemoveBalanceLiquidity};
+
+/// Add and remove balance liquidity of Dynamic AMM share the same accounts
+fn remove_balance_liquidity_accounts(
+    accounts: AddBalanceLiquidity<'_>,
+) -> RemoveBalanceLiquidity<'_> {
+    let AddBalanceLiquidity {
+        pool,
+        lp_mint,
+        user_pool_lp,
+        a_vault_lp,
+        b_vault_lp,
+        a_vault,
+        b_vault,
+        a_vault_lp_mint,
+        b_vault_lp_mint,
+        a_token_vault,
+        b_token_vault,
+        user_a_token,
+        user_b_token,
+        user,
+        vault_program,
+        token_program,
+    } = accounts;
+
+    RemoveBalanceLiquidity {
+        pool,
+        lp_mint,
+        user_pool_lp,
+        a_vault_lp,
+        b_vault_lp,
+        a_vault,
+        b_vault,
+        a_vault_lp_mint,
+        b_vault_lp_mint,
+        a_token_vault,
+        b_token_vault,
+        user_a_token,
+        user_b_token,
+        user,
+        vault_program,
+        token_program,
+    }
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DynamicAmmBalanceLiquidity<'info> {
+    #[account(mut)]
+    /// CHECK: Pool account (PDA)
+    pub pool: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: LP mint of the pool
+    pub lp_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: User LP token account. Receives the minted LP upon deposit, and the LP is burned from it upon withdraw.
+    pub user_pool_lp: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: LP token account of vault A. Used to receive/burn the vault LP upon deposit/withdraw from the vault.
+    pub a_vault_lp: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: LP token account of vault B. Used to receive/burn the vault LP upon deposit/withdraw from the vault.
+    pub b_vault_lp: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Vault account for token a. token a of the pool will be deposit / withdraw from this vault account.
+    pub a_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Vault account for token b. token b of the pool will be deposit / withdraw from this vault account.
+    pub b_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Lp token mint of vault a
+    pub a_vault_lp_mint: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Lp token mint of vault b
+    pub b_vault_lp_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Token vault account of vault A
+    pub a_token_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Token vault account of vault B
+    pub b_token_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: User token A account. Token A is deposited from / withdrawn to this account.
+    pub user_a_token: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: User token B account. Token B is deposited from / withdrawn to this account.
+    pub user_b_token: UncheckedAccount<'info>,
+
+    /// CHECK: User account. Must be owner of the user token accounts.
+    pub user: Signer<'info>,
+
+    /// CHECK: Vault program. the pool will deposit/withdraw liquidity from the vault.
+    pub vault_program: UncheckedAccount<'info>,
+    /// CHECK: Token program.
+    pub token_program: UncheckedAccount<'info>,
+
+    #[account(address = dynamic_amm::ID)]
+    /// CHECK: Dynamic AMM program account
+    pub dynamic_amm_program: UncheckedAccount<'info>,
+}
+
+impl<'info> DynamicAmmBalanceLiquidity<'info> {
+    fn load_balances(&self) -> Result<LiquidityBalances> {
+        LiquidityBalances::load(&self.user_pool_lp, &self.user_a_token, &self.user_b_token)
+    }
+
+    fn balance_changes(&self, balances_before: &LiquidityBalances) -> Result<LiquidityBalances> {
+        balances_before.changes(&self.user_pool_lp, &self.user_a_token, &self.user_b_token)
+    }
+
+    fn balance_liquidity_accounts(&self) -> AddBalanceLiquidity<'info> {
+        AddBalanceLiquidity {
+            pool: self.pool.to_account_info(),
+            lp_mint: self.lp_mint.to_account_info(),
+            user_pool_lp: self.user_pool_lp.to_account_info(),
+            a_vault_lp: self.a_vault_lp.to_account_info(),
+            b_vault_lp: self.b_vault_lp.to_account_info(),
+            a_vault: self.a_vault.to_account_info(),
+            b_vault: self.b_vault.to_account_info(),
+            a_vault_lp_mint: self.a_vault_lp_mint.to_account_info(),
+            b_vault_lp_mint: self.b_vault_lp_mint.to_account_info(),
+            a_token_vault: self.a_token_vault.to_account_info(),
+            b_token_vault: self.b_token_vault.to_account_info(),
+            user_a_token: self.user_a_token.to_account_info(),
+            user_b_token: self.user_b_token.to_account_info(),
+            user: self.user.to_account_info(),
+            vault_program: self.vault_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        }
+    }
+}
+
+/// Deposits tokens to a Dynamic AMM pool in a balanced ratio.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `pool_token_amount` - The amount of LP to be minted.
+/// * `maximum_token_a_amount` - The maximum amount of token A to be deposited a.k.a slippage
+/// * `maximum_token_b_amount` - The maximum amount of token B to be deposited a.k.a slippage
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_add_balance_liquidity(
+    ctx: Context<DynamicAmmBalanceLiquidity>,
+    pool_token_amount: u64,
+    maximum_token_a_amount: u64,
+    maximum_token_b_amount: u64,
+) -> Result<()> {
+    let balances_before = ctx.accounts.load_balances()?;
+
+    let cpi_context = CpiContext::new(
+        ctx.accounts.dynamic_amm_program.to_account_info(),
+        ctx.accounts.balance_liquidity_accounts(),
+    );
+
+    dynamic_amm::cpi::add_balance_liquidity(
+        cpi_context,
+        pool_token_amount,
+        maximum_token_a_amount,
+        maximum_token_b_amount,
+    )?;
+
+    let changes = ctx.accounts.balance_changes(&balances_before)?;
+
+    emit_cpi!(EvtAddLiquidity {
+        pool: ctx.accounts.pool.key(),
+        owner: ctx.accounts.user.key(),
+        lp_amount: changes.pool_lp_amount,
+        token_a_amount: changes.token_a_amount,
+        token_b_amount: changes.token_b_amount,
+    });
+
+    Ok(())
+}
+
+/// Withdraws tokens from a Dynamic AMM pool in a balanced ratio.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `pool_token_amount` - The amount of LP to be burned.
+/// * `minimum_a_token_out` - The minimum amount of token A to be withdrawn a.k.a slippage
+/// * `minimum_b_token_out` - The minimum amount of token B to be withdrawn a.k.a slippage
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_remove_balance_liquidity(
+    ctx: Context<DynamicAmmBalanceLiquidity>,
+    pool_token_amount: u64,
+    minimum_a_token_out: u64,
+    minimum_b_token_out: u64,
+) -> Result<()> {
+    let balances_before = ctx.accounts.load_balances()?;
+
+    let cpi_context = CpiContext::new(
+        ctx.accounts.dynamic_amm_program.to_account_info(),
+        remove_balance_liquidity_accounts(ctx.accounts.balance_liquidity_accounts()),
+    );
+
+    dynamic_amm::cpi::remove_balance_liquidity(
+        cpi_context,
+        pool_token_amount,
+        minimum_a_token_out,
+        minimum_b_token_out,
+    )?;
+
+    let changes = ctx.accounts.balance_changes(&balances_before)?;
+
+    emit_cpi!(EvtRemoveLiquidity {
+        pool: ctx.accounts.pool.key(),
+        owner: ctx.accounts.user.key(),
+        lp_amount: changes.pool_lp_amount,
+        token_a_amount: changes.token_a_amount,
+        token_b_amount: changes.token_b_amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DynamicAmmBalanceLiquidityPdaCreator<'info> {
+    #[account(mut, has_one = lp_mint)]
+    /// CHECK: Pool account (PDA)
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(mut)]
+    /// CHECK: LP mint of the pool
+    pub lp_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Pool creator authority. PDA. Owner of the deposited tokens and the LP.
+    #[account(
+        seeds = [b"creator", owner.key().as_ref(), pool.key().as_ref()],
+        bump
+    )]
+    pub creator_authority: UncheckedAccount<'info>,
+
+    /// Owner of the creator authority PDA. Creator authorities are isolated per owner and pool.
+    pub owner: Signer<'info>,
+
+    /// Program config. Stores the admin of the creator authority PDA instructions.
+    #[account(seeds = [b"config"], bump = program_config.bump)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+
+    /// CHECK: Only admin can manage the liquidity of creator PDA.
+    #[account(
+        constraint = program_config.admin == cpi_example_admin.key() @ CpiExampleError::InvalidAdmin
+    )]
+    pub cpi_example_admin: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Creator LP token account. Receives the minted LP upon deposit, and the LP is burned from it upon withdraw.
+    pub creator_pool_lp: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: LP token account of vault A. Used to receive/burn the vault LP upon deposit/withdraw from the vault.
+    pub a_vault_lp: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: LP token account of vault B. Used to receive/burn the vault LP upon deposit/withdraw from the vault.
+    pub b_vault_lp: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Vault account for token a. token a of the pool will be deposit / withdraw from this vault account.
+    pub a_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Vault account for token b. token b of the pool will be deposit / withdraw from this vault account.
+    pub b_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Lp token mint of vault a
+    pub a_vault_lp_mint: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Lp token mint of vault b
+    pub b_vault_lp_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Token vault account of vault A
+    pub a_token_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Token vault account of vault B
+    pub b_token_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Creator token A account. Token A is deposited from / withdrawn to this account.
+    pub creator_a_token: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Creator token B account. Token B is deposited from / withdrawn to this account.
+    pub creator_b_token: UncheckedAccount<'info>,
+
+    /// CHECK: Vault program. the pool will deposit/withdraw liquidity from the vault.
+    pub vault_program: UncheckedAccount<'info>,
+    /// CHECK: Token program.
+    pub token_program: UncheckedAccount<'info>,
+
+    #[account(address = dynamic_amm::ID)]
+    /// CHECK: Dynamic AMM program account
+    pub dynamic_amm_program: UncheckedAccount<'info>,
+}
+
+impl<'info> DynamicAmmBalanceLiquidityPdaCreator<'info> {
+    /// The LP and tokens must be held by the associated token accounts of the creator authority PDA
+    fn validate_creator_token_accounts(&self) -> Result<()> {
+        let creator_authority_key = self.creator_authority.key();
+        let token_program_key = self.token_program.key();
+
+        for (mint, token_account) in [
+            (self.pool.lp_mint, &self.creator_pool_lp),
+            (self.pool.token_a_mint, &self.creator_a_token),
+            (self.pool.token_b_mint, &self.creator_b_token),
+        ] {
+            require_keys_eq!(
+                get_associated_token_address_with_program_id(
+                    &creator_authority_key,
+                    &mint,
+                    &token_program_key,
+                ),
+                token_account.key(),
+                CpiExampleError::InvalidCreatorTokenAccount
+            );
+        }
+
+        Ok(())
+    }
+
+    fn load_balances(&self) -> Result<LiquidityBalances> {
+        LiquidityBalances::load(
+            &self.creator_pool_lp,
+            &self.creator_a_token,
+            &self.creator_b_token,
+        )
+    }
+
+    fn balance_changes(&self, balances_before: &LiquidityBalances) -> Result<LiquidityBalances> {
+        balances_before.changes(
+            &self.creator_pool_lp,
+            &self.creator_a_token,
+            &self.creator_b_token,
+        )
+    }
+
+    fn balance_liquidity_accounts(&self) -> AddBalanceLiquidity<'info> {
+        AddBalanceLiquidity {
+            pool: self.pool.to_account_info(),
+            lp_mint: self.lp_mint.to_account_info(),
+            user_pool_lp: self.creator_pool_lp.to_account_info(),
+            a_vault_lp: self.a_vault_lp.to_account_info(),
+            b_vault_lp: self.b_vault_lp.to_account_info(),
+            a_vault: self.a_vault.to_account_info(),
+            b_vault: self.b_vault.to_account_info(),
+            a_vault_lp_mint: self.a_vault_lp_mint.to_account_info(),
+            b_vault_lp_mint: self.b_vault_lp_mint.to_account_info(),
+            a_token_vault: self.a_token_vault.to_account_info(),
+            b_token_vault: self.b_token_vault.to_account_info(),
+            user_a_token: self.creator_a_token.to_account_info(),
+            user_b_token: self.creator_b_token.to_account_info(),
+            user: self.creator_authority.to_account_info(),
+            vault_program: self.vault_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        }
+    }
+}
+
+/// Deposits tokens held by creator PDA to a Dynamic AMM pool in a balanced ratio.
+///
+/// The minted LP will be hold by creator PDA.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `pool_token_amount` - The amount of LP to be minted.
+/// * `maximum_token_a_amount` - The maximum amount of token A to be deposited a.k.a slippage
+/// * `maximum_token_b_amount` - The maximum amount of token B to be deposited a.k.a slippage
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_add_balance_liquidity_pda_creator(
+    ctx: Context<DynamicAmmBalanceLiquidityPdaCreator>,
+    pool_token_amount: u64,
+    maximum_token_a_amount: u64,
+    maximum_token_b_amount: u64,
+) -> Result<()> {
+    ctx.accounts.validate_creator_token_accounts()?;
+
+    let balances_before = ctx.accounts.load_balances()?;
+
+    let owner_key = ctx.accounts.owner.key();
+    let pool_key = ctx.accounts.pool.key();
+    let seeds = [
+        b"creator".as_ref(),
+        owner_key.as_ref(),
+        pool_key.as_ref(),
+        &[ctx.bumps.creator_authority],
+    ];
+
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.dynamic_amm_program.to_account_info(),
+        ctx.accounts.balance_liquidity_accounts(),
+        signer_seeds,
+    );
+
+    dynamic_amm::cpi::add_balance_liquidity(
+        cpi_context,
+        pool_token_amount,
+        maximum_token_a_amount,
+        maximum_token_b_amount,
+    )?;
+
+    let changes = ctx.accounts.balance_changes(&balances_before)?;
+
+    emit_cpi!(EvtAddLiquidity {
+        pool: pool_key,
+        owner: ctx.accounts.creator_authority.key(),
+        lp_amount: changes.pool_lp_amount,
+        token_a_amount: changes.token_a_amount,
+        token_b_amount: changes.token_b_amount,
+    });
+
+    Ok(())
+}
+
+/// Withdraws the liquidity of creator PDA from a Dynamic AMM pool in a balanced ratio.
+///
+/// The withdrawn tokens will be hold by creator PDA.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `pool_token_amount` - The amount of LP to be burned.
+/// * `minimum_a_token_out` - The minimum amount of token A to be withdrawn a.k.a slippage
+/// * `minimum_b_token_out` - The minimum amount of token B to be withdrawn a.k.a slippage
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_remove_balance_liquidity_pda_creator(
+    ctx: Context<DynamicAmmBalanceLiquidityPdaCreator>,
+    pool_token_amount: u64,
+    minimum_a_token_out: u64,
+    minimum_b_token_out: u64,
+) -> Result<()> {
+    ctx.accounts.validate_creator_token_accounts()?;
+
+    let balances_before = ctx.accounts.load_balances()?;
+
+    let owner_key = ctx.accounts.owner.key();
+    let pool_key = ctx.accounts.pool.key();
+    let seeds = [
+        b"creator".as_ref(),
+        owner_key.as_ref(),
+        pool_key.as_ref(),
+        &[ctx.bumps.creator_authority],
+    ];
+
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.dynamic_amm_program.to_account_info(),
+        remove_balance_liquidity_accounts(ctx.accounts.balance_liquidity_accounts()),
+        signer_seeds,
+    );
+
+    dynamic_amm::cpi::remove_balance_liquidity(
+        cpi_context,
+        pool_token_amount,
+        minimum_a_token_out,
+        minimum_b_token_out,
+    )?;
+
+    let changes = ctx.accounts.balance_changes(&balances_before)?;
+
+    emit_cpi!(EvtRemoveLiquidity {
+        pool: pool_key,
+        owner: ctx.accounts.creator_authority.key(),
+        lp_amount: changes.pool_lp_amount,
+        token_a_amount: changes.token_a_amount,
+        token_b_amount: changes.token_b_amount,
+    });
+
+    Ok(())
+}
@@ -1,8 +1,13 @@
 use crate::dynamic_amm;
 use crate::dynamic_amm::accounts::Pool;
+use crate::errors::CpiExampleError;
+use crate::events::EvtClaimFee;
+use crate::state::ProgramConfig;
+use crate::token_utils::load_token_account;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::get_associated_token_address_with_program_id;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DynamicAmmClaimFee<'info> {
     /// CHECK: Pool account (PDA)
@@ -86,8 +91,10 @@ pub struct DynamicAmmClaimFee<'info> {
 /// # Returns
 ///
 /// Returns a `Result` indicating success or failure.
-
 pub fn handle_claim_fee(ctx: Context<DynamicAmmClaimFee>) -> Result<()> {
+    let token_a_amount_before = load_token_account(&ctx.accounts.user_a_token)?.amount;
+    let token_b_amount_before = load_token_account(&ctx.accounts.user_b_token)?.amount;
+
     let accounts = dynamic_amm::cpi::accounts::ClaimFee {
         pool: ctx.accounts.pool.to_account_info(),
         lp_mint: ctx.accounts.lp_mint.to_account_info(),
@@ -113,9 +120,28 @@ pub fn handle_claim_fee(ctx: Context<DynamicAmmClaimFee>) -> Result<()> {
     let cpi_context = CpiContext::new(ctx.accounts.dynamic_amm.to_account_info(), accounts);
 
     // Claim max fee
-    dynamic_amm::cpi::claim_fee(cpi_context, u64::MAX)
+    dynamic_amm::cpi::claim_fee(cpi_context, u64::MAX)?;
+
+    let token_a_amount = load_token_account(&ctx.accounts.user_a_token)?
+        .amount
+        .checked_sub(token_a_amount_before)
+        .ok_or(CpiExampleError::MathOverflow)?;
+    let token_b_amount = load_token_account(&ctx.accounts.user_b_token)?
+        .amount
+        .checked_sub(token_b_amount_before)
+        .ok_or(CpiExampleError::MathOverflow)?;
+
+    emit_cpi!(EvtClaimFee {
+        pool: ctx.accounts.pool.key(),
+        owner: ctx.accounts.owner.key(),
+        token_a_amount,
+        token_b_amount,
+    });
+
+    Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DynamicAmmClaimFeePdaCreator<'info> {
     /// CHECK: Pool account (PDA)
@@ -129,18 +155,25 @@ pub struct DynamicAmmClaimFeePdaCreator<'info> {
     /// CHECK: Pool creator authority. PDA.
     #[account(
         mut,
-        seeds = [b"creator"],
+        seeds = [b"creator", owner.key().as_ref(), pool.key().as_ref()],
         bump
     )]
     pub creator_authority: UncheckedAccount<'info>,
 
+    /// Owner of the creator authority PDA. Creator authorities are isolated per owner and pool.
+    pub owner: Signer<'info>,
+
     /// CHECK: Lock escrow of creator PDA
     #[account(mut)]
     pub lock_escrow: UncheckedAccount<'info>,
 
+    /// Program config. Stores the admin of the creator authority PDA instructions.
+    #[account(seeds = [b"config"], bump = program_config.bump)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+
     /// CHECK: Only admin can claim fee for creator PDA.
     #[account(
-        constraint = crate::assert_eq_admin(cpi_example_admin.key())
+        constraint = program_config.admin == cpi_example_admin.key() @ CpiExampleError::InvalidAdmin
     )]
     pub cpi_example_admin: Signer<'info>,
 
@@ -224,17 +257,20 @@ pub fn handle_claim_fee_pda_creator(ctx: Context<DynamicAmmClaimFeePdaCreator>)
         &ctx.accounts.token_program.key(),
     );
 
-    assert_eq!(
+    require_keys_eq!(
         creator_a_token_key,
         ctx.accounts.creator_a_token.key(),
-        "Invalid creator_a_token"
+        CpiExampleError::InvalidCreatorTokenAccount
     );
-    assert_eq!(
+    require_keys_eq!(
         creator_b_token_key,
         ctx.accounts.creator_b_token.key(),
-        "Invalid creator_b_token"
+        CpiExampleError::InvalidCreatorTokenAccount
     );
 
+    let token_a_amount_before = load_token_account(&ctx.accounts.creator_a_token)?.amount;
+    let token_b_amount_before = load_token_account(&ctx.accounts.creator_b_token)?.amount;
+
     let accounts = dynamic_amm::cpi::accounts::ClaimFee {
         pool: ctx.accounts.pool.to_account_info(),
         lp_mint: ctx.accounts.lp_mint.to_account_info(),
@@ -257,7 +293,14 @@ pub fn handle_claim_fee_pda_creator(ctx: Context<DynamicAmmClaimFeePdaCreator>)
         b_token_vault: ctx.accounts.b_token_vault.to_account_info(),
     };
 
-    let seeds = [b"creator".as_ref(), &[ctx.bumps.creator_authority]];
+    let owner_key = ctx.accounts.owner.key();
+    let pool_key = ctx.accounts.pool.key();
+    let seeds = [
+        b"creator".as_ref(),
+        owner_key.as_ref(),
+        pool_key.as_ref(),
+        &[ctx.bumps.creator_authority],
+    ];
 
     let signer_seeds = &[&seeds[..]];
 
@@ -268,5 +311,23 @@ pub fn handle_claim_fee_pda_creator(ctx: Context<DynamicAmmClaimFeePdaCreator>)
     );
 
     // Claim max fee
-    dynamic_amm::cpi::claim_fee(cpi_context, u64::MAX)
+    dynamic_amm::cpi::claim_fee(cpi_context, u64::MAX)?;
+
+    let token_a_amount = load_token_account(&ctx.accounts.creator_a_token)?
+        .amount
+        .checked_sub(token_a_amount_before)
+        .ok_or(CpiExampleError::MathOverflow)?;
+    let token_b_amount = load_token_account(&ctx.accounts.creator_b_token)?
+        .amount
+        .checked_sub(token_b_amount_before)
+        .ok_or(CpiExampleError::MathOverflow)?;
+
+    emit_cpi!(EvtClaimFee {
+        pool: ctx.accounts.pool.key(),
+        owner: ctx.accounts.creator_authority.key(),
+        token_a_amount,
+        token_b_amount,
+    });
+
+    Ok(())
 }
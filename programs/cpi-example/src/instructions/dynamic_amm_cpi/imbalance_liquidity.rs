@@ -0,0 +1,269 @@
+use crate::dynamic_amm;
+use crate::events::{EvtAddLiquidity, EvtRemoveLiquidity};
+use crate::token_utils::{load_token_account, LiquidityBalances};
+use anchor_lang::prelude::*;
+use dynamic_amm::cpi::accounts::{AddImbalanceLiquidity, RemoveLiquiditySingleSide};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DynamicAmmAddImbalanceLiquidity<'info> {
+    #[account(mut)]
+    /// CHECK: Pool account (PDA)
+    pub pool: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: LP mint of the pool
+    pub lp_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: User LP token account. Receives the minted LP.
+    pub user_pool_lp: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: User token A account. Token A is deposited from this account.
+    pub user_a_token: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: User token B account. Token B is deposited from this account.
+    pub user_b_token: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Vault account for token a. token a of the pool will be deposit / withdraw from this vault account.
+    pub a_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Vault account for token b. token b of the pool will be deposit / withdraw from this vault account.
+    pub b_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Token vault account of vault A
+    pub a_token_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Token vault account of vault B
+    pub b_token_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Lp token mint of vault a
+    pub a_vault_lp_mint: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Lp token mint of vault b
+    pub b_vault_lp_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: LP token account of vault A. Used to receive/burn the vault LP upon deposit/withdraw from the vault.
+    pub a_vault_lp: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: LP token account of vault B. Used to receive/burn the vault LP upon deposit/withdraw from the vault.
+    pub b_vault_lp: UncheckedAccount<'info>,
+
+    /// CHECK: User account. Must be owner of the user token accounts.
+    pub user: Signer<'info>,
+
+    /// CHECK: Vault program. the pool will deposit/withdraw liquidity from the vault.
+    pub vault_program: UncheckedAccount<'info>,
+    /// CHECK: Token program.
+    pub token_program: UncheckedAccount<'info>,
+
+    #[account(address = dynamic_amm::ID)]
+    /// CHECK: Dynamic AMM program account
+    pub dynamic_amm_program: UncheckedAccount<'info>,
+}
+
+/// Deposits tokens to a Dynamic AMM pool in any ratio. A single token can be deposited by setting the amount of the other token to 0.
+/// Only supported by pool with stable swap curve.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `minimum_pool_token_amount` - The minimum amount of LP to be minted a.k.a slippage
+/// * `token_a_amount` - The amount of token A to be deposited.
+/// * `token_b_amount` - The amount of token B to be deposited.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_add_imbalance_liquidity(
+    ctx: Context<DynamicAmmAddImbalanceLiquidity>,
+    minimum_pool_token_amount: u64,
+    token_a_amount: u64,
+    token_b_amount: u64,
+) -> Result<()> {
+    let balances_before = LiquidityBalances::load(
+        &ctx.accounts.user_pool_lp,
+        &ctx.accounts.user_a_token,
+        &ctx.accounts.user_b_token,
+    )?;
+
+    let accounts = AddImbalanceLiquidity {
+        pool: ctx.accounts.pool.to_account_info(),
+        lp_mint: ctx.accounts.lp_mint.to_account_info(),
+        user_pool_lp: ctx.accounts.user_pool_lp.to_account_info(),
+        a_vault_lp: ctx.accounts.a_vault_lp.to_account_info(),
+        b_vault_lp: ctx.accounts.b_vault_lp.to_account_info(),
+        a_vault: ctx.accounts.a_vault.to_account_info(),
+        b_vault: ctx.accounts.b_vault.to_account_info(),
+        a_vault_lp_mint: ctx.accounts.a_vault_lp_mint.to_account_info(),
+        b_vault_lp_mint: ctx.accounts.b_vault_lp_mint.to_account_info(),
+        a_token_vault: ctx.accounts.a_token_vault.to_account_info(),
+        b_token_vault: ctx.accounts.b_token_vault.to_account_info(),
+        user_a_token: ctx.accounts.user_a_token.to_account_info(),
+        user_b_token: ctx.accounts.user_b_token.to_account_info(),
+        user: ctx.accounts.user.to_account_info(),
+        vault_program: ctx.accounts.vault_program.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    };
+
+    let cpi_context = CpiContext::new(ctx.accounts.dynamic_amm_program.to_account_info(), accounts);
+
+    dynamic_amm::cpi::add_imbalance_liquidity(
+        cpi_context,
+        minimum_pool_token_amount,
+        token_a_amount,
+        token_b_amount,
+    )?;
+
+    let changes = balances_before.changes(
+        &ctx.accounts.user_pool_lp,
+        &ctx.accounts.user_a_token,
+        &ctx.accounts.user_b_token,
+    )?;
+
+    emit_cpi!(EvtAddLiquidity {
+        pool: ctx.accounts.pool.key(),
+        owner: ctx.accounts.user.key(),
+        lp_amount: changes.pool_lp_amount,
+        token_a_amount: changes.token_a_amount,
+        token_b_amount: changes.token_b_amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DynamicAmmRemoveLiquiditySingleSide<'info> {
+    #[account(mut)]
+    /// CHECK: Pool account (PDA)
+    pub pool: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: LP mint of the pool
+    pub lp_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: User LP token account. The LP is burned from this account.
+    pub user_pool_lp: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: User token account. Receives the withdrawn token. It's mint field decides the token to be withdrawn.
+    pub user_destination_token: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Vault account for token a. token a of the pool will be deposit / withdraw from this vault account.
+    pub a_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Vault account for token b. token b of the pool will be deposit / withdraw from this vault account.
+    pub b_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Token vault account of vault A
+    pub a_token_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Token vault account of vault B
+    pub b_token_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Lp token mint of vault a
+    pub a_vault_lp_mint: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Lp token mint of vault b
+    pub b_vault_lp_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: LP token account of vault A. Used to receive/burn the vault LP upon deposit/withdraw from the vault.
+    pub a_vault_lp: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: LP token account of vault B. Used to receive/burn the vault LP upon deposit/withdraw from the vault.
+    pub b_vault_lp: UncheckedAccount<'info>,
+
+    /// CHECK: User account. Must be owner of user_pool_lp.
+    pub user: Signer<'info>,
+
+    /// CHECK: Vault program. the pool will deposit/withdraw liquidity from the vault.
+    pub vault_program: UncheckedAccount<'info>,
+    /// CHECK: Token program.
+    pub token_program: UncheckedAccount<'info>,
+
+    #[account(address = dynamic_amm::ID)]
+    /// CHECK: Dynamic AMM program account
+    pub dynamic_amm_program: UncheckedAccount<'info>,
+}
+
+/// Withdraws the liquidity from a Dynamic AMM pool as a single token. Only supported by pool with stable swap curve.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `pool_token_amount` - The amount of LP to be burned.
+/// * `minimum_out_amount` - The minimum amount of token to be withdrawn a.k.a slippage
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_remove_liquidity_single_side(
+    ctx: Context<DynamicAmmRemoveLiquiditySingleSide>,
+    pool_token_amount: u64,
+    minimum_out_amount: u64,
+) -> Result<()> {
+    let user_pool_lp_before = load_token_account(&ctx.accounts.user_pool_lp)?.amount;
+    let user_destination_token_before = load_token_account(&ctx.accounts.user_destination_token)?;
+
+    let accounts = RemoveLiquiditySingleSide {
+        pool: ctx.accounts.pool.to_account_info(),
+        lp_mint: ctx.accounts.lp_mint.to_account_info(),
+        user_pool_lp: ctx.accounts.user_pool_lp.to_account_info(),
+        a_vault_lp: ctx.accounts.a_vault_lp.to_account_info(),
+        b_vault_lp: ctx.accounts.b_vault_lp.to_account_info(),
+        a_vault: ctx.accounts.a_vault.to_account_info(),
+        b_vault: ctx.accounts.b_vault.to_account_info(),
+        a_vault_lp_mint: ctx.accounts.a_vault_lp_mint.to_account_info(),
+        b_vault_lp_mint: ctx.accounts.b_vault_lp_mint.to_account_info(),
+        a_token_vault: ctx.accounts.a_token_vault.to_account_info(),
+        b_token_vault: ctx.accounts.b_token_vault.to_account_info(),
+        user_destination_token: ctx.accounts.user_destination_token.to_account_info(),
+        user: ctx.accounts.user.to_account_info(),
+        vault_program: ctx.accounts.vault_program.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    };
+
+    let cpi_context = CpiContext::new(ctx.accounts.dynamic_amm_program.to_account_info(), accounts);
+
+    dynamic_amm::cpi::remove_liquidity_single_side(
+        cpi_context,
+        pool_token_amount,
+        minimum_out_amount,
+    )?;
+
+    let lp_amount =
+        user_pool_lp_before.abs_diff(load_token_account(&ctx.accounts.user_pool_lp)?.amount);
+    let out_amount = user_destination_token_before
+        .amount
+        .abs_diff(load_token_account(&ctx.accounts.user_destination_token)?.amount);
+
+    // The pool withdraws the token of the destination token account
+    let is_token_a =
+        user_destination_token_before.mint == load_token_account(&ctx.accounts.a_token_vault)?.mint;
+    let (token_a_amount, token_b_amount) = if is_token_a {
+        (out_amount, 0)
+    } else {
+        (0, out_amount)
+    };
+
+    emit_cpi!(EvtRemoveLiquidity {
+        pool: ctx.accounts.pool.key(),
+        owner: ctx.accounts.user.key(),
+        lp_amount,
+        token_a_amount,
+        token_b_amount,
+    });
+
+    Ok(())
+}
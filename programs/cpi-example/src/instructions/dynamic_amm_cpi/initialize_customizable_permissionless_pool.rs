@@ -1,7 +1,12 @@
+use crate::creator_authority::{
+    fund_creator_authority_lamports, refund_creator_authority, CreatorAuthorityLamportsAccounts,
+};
 use crate::dynamic_amm;
 use crate::dynamic_amm::types::CustomizableParams;
+use crate::errors::CpiExampleError;
+use crate::events::EvtInitializeDynamicAmmPool;
+use crate::state::ProgramConfig;
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::Transfer as NativeSolTransfer;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{Mint, Token, TokenAccount, Transfer as TokenTransfer},
@@ -9,6 +14,13 @@ use anchor_spl::{
 
 pub const POOL_SIZE: usize = 8 + 944;
 
+/// Size of the LP mint metadata account. `MAX_METADATA_LEN` of the token metadata program.
+pub const METADATA_SIZE: usize = 679;
+
+/// Lamports charged by the token metadata program for creating a metadata account. `CREATE_FEE` of the token metadata program.
+pub const METADATA_CREATE_FEE: u64 = 10_000_000;
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DynamicAmmInitializeCustomizablePermissionlessPool<'info> {
     /// CHECK: Pool account (PDA)
@@ -159,19 +171,34 @@ pub fn handle_initialize_customizable_permissionless_pool(
         token_a_amount,
         token_b_amount,
         params,
-    )
+    )?;
+
+    emit_cpi!(EvtInitializeDynamicAmmPool {
+        pool: ctx.accounts.pool.key(),
+        creator: ctx.accounts.payer.key(),
+        token_a_mint: ctx.accounts.token_a_mint.key(),
+        token_b_mint: ctx.accounts.token_b_mint.key(),
+        token_a_amount,
+        token_b_amount,
+    });
+
+    Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DynamicAmmInitializeCustomizablePermissionlessPoolPdaCreator<'info> {
     /// CHECK: Creator authority
     #[account(
         mut,
-        seeds = [b"creator"],
+        seeds = [b"creator", owner.key().as_ref(), pool.key().as_ref()],
         bump
     )]
     pub creator_authority: UncheckedAccount<'info>,
 
+    /// Owner of the creator authority PDA. Creator authorities are isolated per owner and pool.
+    pub owner: Signer<'info>,
+
     #[account(
         init_if_needed,
         associated_token::mint = token_a_mint,
@@ -254,8 +281,15 @@ pub struct DynamicAmmInitializeCustomizablePermissionlessPoolPdaCreator<'info> {
     #[account(mut)]
     pub protocol_token_b_fee: UncheckedAccount<'info>,
 
-    /// CHECK: Payer account. This account will be the creator of the pool, and the payer for PDA during initialize pool.
-    #[account(mut)]
+    /// Program config. Stores the admin of the creator authority PDA instructions.
+    #[account(seeds = [b"config"], bump = program_config.bump)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+
+    /// CHECK: CPI example program admin. Only admin can call this instruction. Fund the creator authority with the initial liquidity and account rental.
+    #[account(
+        mut,
+        constraint = program_config.admin == payer.key() @ CpiExampleError::InvalidAdmin
+    )]
     pub payer: Signer<'info>,
 
     /// Rent account.
@@ -300,6 +334,8 @@ pub fn handle_initialize_customizable_permissionless_pool_with_pda_creator(
     token_b_amount: u64,
     params: CustomizableParams,
 ) -> Result<()> {
+    let creator_authority_lamports = ctx.accounts.creator_authority.lamports();
+
     fund_creator_authority(
         token_a_amount,
         token_b_amount,
@@ -344,7 +380,14 @@ pub fn handle_initialize_customizable_permissionless_pool_with_pda_creator(
             system_program: ctx.accounts.system_program.to_account_info(),
         };
 
-    let seeds = [b"creator".as_ref(), &[ctx.bumps.creator_authority]];
+    let owner_key = ctx.accounts.owner.key();
+    let pool_key = ctx.accounts.pool.key();
+    let seeds = [
+        b"creator".as_ref(),
+        owner_key.as_ref(),
+        pool_key.as_ref(),
+        &[ctx.bumps.creator_authority],
+    ];
 
     let signer_seeds = &[&seeds[..]];
 
@@ -359,7 +402,28 @@ pub fn handle_initialize_customizable_permissionless_pool_with_pda_creator(
         token_a_amount,
         token_b_amount,
         params,
-    )
+    )?;
+
+    refund_creator_authority(
+        creator_authority_lamports,
+        &CreatorAuthorityLamportsAccounts {
+            creator_authority: &ctx.accounts.creator_authority,
+            payer: &ctx.accounts.payer,
+            system_program: &ctx.accounts.system_program,
+        },
+        signer_seeds,
+    )?;
+
+    emit_cpi!(EvtInitializeDynamicAmmPool {
+        pool: ctx.accounts.pool.key(),
+        creator: ctx.accounts.creator_authority.key(),
+        token_a_mint: ctx.accounts.token_a_mint.key(),
+        token_b_mint: ctx.accounts.token_b_mint.key(),
+        token_a_amount,
+        token_b_amount,
+    });
+
+    Ok(())
 }
 
 pub struct FundCreatorAuthorityAccounts<'b, 'info> {
@@ -421,34 +485,23 @@ pub fn fund_creator_authority<'b, 'info>(
     }
 
     // Fund creator PDA with SOL to pay for account rental
-    let mut lamports: u64 = 0;
+    let rent = Rent::get()?;
 
     // Pool
-    lamports += Rent::get()?.minimum_balance(POOL_SIZE);
+    let mut lamports = rent.minimum_balance(POOL_SIZE);
     // LP mint
-    lamports += Rent::get()?.minimum_balance(Mint::LEN);
-    //  a_vault_lp + b_vault_lp + creator LP ATA + protocol fee A + protocol fee B
-    let token_account_lamports = Rent::get()?.minimum_balance(TokenAccount::LEN);
-    lamports += token_account_lamports * 5;
-    // LP mint Metadata
-    lamports += Rent::get()?.minimum_balance(679);
-    // Metaplex fee ...
-    lamports += 10_000_000;
-
-    msg!("Required lamports: {}", lamports);
-
-    anchor_lang::system_program::transfer(
-        CpiContext::new(
-            system_program.to_account_info(),
-            NativeSolTransfer {
-                from: payer.to_account_info(),
-                to: creator_authority.to_account_info(),
-            },
-        ),
-        // Weird bug in bpf, more lamport causes failure. The calculated lamports above should be the correct one
-        // lamports,
-        34290400,
-    )?;
-
-    Ok(())
+    lamports += rent.minimum_balance(Mint::LEN);
+    // a_vault_lp + b_vault_lp + creator LP ATA + protocol fee A + protocol fee B
+    lamports += rent.minimum_balance(TokenAccount::LEN) * 5;
+    // LP mint metadata, and the fee charged by the token metadata program for creating it
+    lamports += rent.minimum_balance(METADATA_SIZE) + METADATA_CREATE_FEE;
+
+    fund_creator_authority_lamports(
+        lamports,
+        &CreatorAuthorityLamportsAccounts {
+            creator_authority,
+            payer,
+            system_program,
+        },
+    )
 }
@@ -1,4 +1,8 @@
+use crate::creator_authority::{refund_creator_authority, CreatorAuthorityLamportsAccounts};
 use crate::dynamic_amm;
+use crate::errors::CpiExampleError;
+use crate::events::EvtInitializeDynamicAmmPool;
+use crate::state::ProgramConfig;
 use crate::{fund_creator_authority, FundCreatorAuthorityAccounts};
 use anchor_lang::prelude::*;
 use anchor_spl::{
@@ -6,6 +10,7 @@ use anchor_spl::{
     token::{Token, TokenAccount},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DynamicAmmInitializePermissionlessPoolWithConfig<'info> {
     /// CHECK: Pool account (PDA)
@@ -160,19 +165,34 @@ pub fn handle_initialize_permissionless_pool_with_config(
         token_a_amount,
         token_b_amount,
         activation_point,
-    )
+    )?;
+
+    emit_cpi!(EvtInitializeDynamicAmmPool {
+        pool: ctx.accounts.pool.key(),
+        creator: ctx.accounts.payer.key(),
+        token_a_mint: ctx.accounts.token_a_mint.key(),
+        token_b_mint: ctx.accounts.token_b_mint.key(),
+        token_a_amount,
+        token_b_amount,
+    });
+
+    Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DynamicAmmInitializePermissionlessPoolWithConfigPdaCreator<'info> {
     /// CHECK: Creator authority
     #[account(
         mut,
-        seeds = [b"creator"],
+        seeds = [b"creator", owner.key().as_ref(), pool.key().as_ref()],
         bump
     )]
     pub creator_authority: UncheckedAccount<'info>,
 
+    /// Owner of the creator authority PDA. Creator authorities are isolated per owner and pool.
+    pub owner: Signer<'info>,
+
     #[account(
         init_if_needed,
         associated_token::mint = token_a_mint,
@@ -258,8 +278,15 @@ pub struct DynamicAmmInitializePermissionlessPoolWithConfigPdaCreator<'info> {
     #[account(mut)]
     pub protocol_token_b_fee: UncheckedAccount<'info>,
 
-    /// CHECK: Payer account. This account will be the creator of the pool, and the payer for PDA during initialize pool.
-    #[account(mut)]
+    /// Program config. Stores the admin of the creator authority PDA instructions.
+    #[account(seeds = [b"config"], bump = program_config.bump)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+
+    /// CHECK: CPI example program admin. Only admin can call this instruction. Fund the creator authority with the initial liquidity and account rental.
+    #[account(
+        mut,
+        constraint = program_config.admin == payer.key() @ CpiExampleError::InvalidAdmin
+    )]
     pub payer: Signer<'info>,
 
     /// Rent account.
@@ -305,6 +332,8 @@ pub fn handle_initialize_permissionless_pool_with_pda_creator(
     token_b_amount: u64,
     activation_point: Option<u64>,
 ) -> Result<()> {
+    let creator_authority_lamports = ctx.accounts.creator_authority.lamports();
+
     fund_creator_authority(
         token_a_amount,
         token_b_amount,
@@ -350,7 +379,14 @@ pub fn handle_initialize_permissionless_pool_with_pda_creator(
             config: ctx.accounts.config.to_account_info(),
         };
 
-    let seeds = [b"creator".as_ref(), &[ctx.bumps.creator_authority]];
+    let owner_key = ctx.accounts.owner.key();
+    let pool_key = ctx.accounts.pool.key();
+    let seeds = [
+        b"creator".as_ref(),
+        owner_key.as_ref(),
+        pool_key.as_ref(),
+        &[ctx.bumps.creator_authority],
+    ];
 
     let signer_seeds = &[&seeds[..]];
 
@@ -365,5 +401,26 @@ pub fn handle_initialize_permissionless_pool_with_pda_creator(
         token_a_amount,
         token_b_amount,
         activation_point,
-    )
+    )?;
+
+    refund_creator_authority(
+        creator_authority_lamports,
+        &CreatorAuthorityLamportsAccounts {
+            creator_authority: &ctx.accounts.creator_authority,
+            payer: &ctx.accounts.payer,
+            system_program: &ctx.accounts.system_program,
+        },
+        signer_seeds,
+    )?;
+
+    emit_cpi!(EvtInitializeDynamicAmmPool {
+        pool: ctx.accounts.pool.key(),
+        creator: ctx.accounts.creator_authority.key(),
+        token_a_mint: ctx.accounts.token_a_mint.key(),
+        token_b_mint: ctx.accounts.token_b_mint.key(),
+        token_a_amount,
+        token_b_amount,
+    });
+
+    Ok(())
 }
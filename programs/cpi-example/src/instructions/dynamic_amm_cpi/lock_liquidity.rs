@@ -1,8 +1,17 @@
-use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, TokenAccount};
-use crate::dynamic_amm::accounts::Pool;
 use crate::dynamic_amm;
+use crate::dynamic_amm::accounts::Pool;
+use crate::errors::CpiExampleError;
+use crate::events::EvtLockLiquidity;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{self, AssociatedToken};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+/// Maximum number of recipients of a lock. Each recipient takes 3 remaining accounts, so locking to many recipients
+/// requires an address lookup table to fit in a transaction.
+pub const MAX_LOCK_RECIPIENTS: usize = 20;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DynamicAmmLockLiquidity<'info> {
     /// CHECK: Pool account (PDA)
@@ -15,14 +24,6 @@ pub struct DynamicAmmLockLiquidity<'info> {
     /// CHECK: Pool LP mint
     pub lp_mint: Box<Account<'info, Mint>>,
 
-    /// CHECK: Lock escrow for user 0
-    #[account(mut)]
-    pub lock_escrow_0: UncheckedAccount<'info>,
-
-    /// CHECK: Lock escrow for user 1
-    #[account(mut)]
-    pub lock_escrow_1: UncheckedAccount<'info>,
-
     /// CHECK: Payer lp token account
     #[account(
         mut,
@@ -30,34 +31,10 @@ pub struct DynamicAmmLockLiquidity<'info> {
     )]
     pub source_lp_tokens: Box<Account<'info, TokenAccount>>,
 
-    /// CHECK: Escrow vault 0
-    #[account(
-        init_if_needed,
-        associated_token::mint = lp_mint,
-        associated_token::authority = lock_escrow_0,
-        payer = payer
-    )]
-    pub escrow_vault_0: Box<Account<'info, TokenAccount>>,
-
-    /// CHECK: Escrow vault 1
-    #[account(
-        init_if_needed,
-        associated_token::mint = lp_mint,
-        associated_token::authority = lock_escrow_1,
-        payer = payer
-    )]
-    pub escrow_vault_1: Box<Account<'info, TokenAccount>>,
-
-    /// Wallet that hold LP tokens and wish to lock user 0 and 1
+    /// Wallet that hold LP tokens and wish to lock to the recipients. Also funder for account rental.
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    /// CHECK: User 0 account
-    pub user_0: UncheckedAccount<'info>,
-
-    /// CHECK: User 1 account
-    pub user_1: UncheckedAccount<'info>,
-
     /// CHECK: Vault account for token a. token a of the pool will be deposit / withdraw from this vault account.
     pub a_vault: UncheckedAccount<'info>,
 
@@ -80,117 +57,223 @@ pub struct DynamicAmmLockLiquidity<'info> {
     #[account(address = dynamic_amm::ID)]
     pub dynamic_amm_program: UncheckedAccount<'info>,
 
-    /// CHECK: System program
-    pub system_program: UncheckedAccount<'info>,
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> DynamicAmmLockLiquidity<'info> {
+    fn lock_accounts(&self) -> LockAccounts<'info> {
+        LockAccounts {
+            pool: self.pool.to_account_info(),
+            lp_mint: self.lp_mint.to_account_info(),
+            source_lp_tokens: self.source_lp_tokens.to_account_info(),
+            source_owner: self.payer.to_account_info(),
+            payer: self.payer.to_account_info(),
+            a_vault: self.a_vault.to_account_info(),
+            b_vault: self.b_vault.to_account_info(),
+            a_vault_lp: self.a_vault_lp.to_account_info(),
+            b_vault_lp: self.b_vault_lp.to_account_info(),
+            a_vault_lp_mint: self.a_vault_lp_mint.to_account_info(),
+            b_vault_lp_mint: self.b_vault_lp_mint.to_account_info(),
+            dynamic_amm_program: self.dynamic_amm_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+        }
+    }
+}
+
+/// Accounts shared by the locks of all recipients
+pub(crate) struct LockAccounts<'info> {
+    pub pool: AccountInfo<'info>,
+    pub lp_mint: AccountInfo<'info>,
+    pub source_lp_tokens: AccountInfo<'info>,
+    /// Owner of the source LP token account
+    pub source_owner: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub a_vault: AccountInfo<'info>,
+    pub b_vault: AccountInfo<'info>,
+    pub a_vault_lp: AccountInfo<'info>,
+    pub b_vault_lp: AccountInfo<'info>,
+    pub a_vault_lp_mint: AccountInfo<'info>,
+    pub b_vault_lp_mint: AccountInfo<'info>,
+    pub dynamic_amm_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+}
 
-    /// CHECK: Token program.
-    pub token_program: UncheckedAccount<'info>,
+impl<'info> LockAccounts<'info> {
+    /// Lock the LP of the recipient to its lock escrow. The lock escrow and the escrow vault are created when they don't exist.
+    pub(crate) fn lock(
+        &self,
+        recipient: &LockRecipient<'_, 'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        // 1. Initialize lock escrow of the recipient
+        if recipient.lock_escrow.data_is_empty() {
+            let accounts = dynamic_amm::cpi::accounts::CreateLockEscrow {
+                pool: self.pool.clone(),
+                lock_escrow: recipient.lock_escrow.clone(),
+                owner: recipient.owner.clone(),
+                lp_mint: self.lp_mint.clone(),
+                payer: self.payer.clone(),
+                system_program: self.system_program.clone(),
+            };
+
+            let cpi_context = CpiContext::new(self.dynamic_amm_program.clone(), accounts);
+            dynamic_amm::cpi::create_lock_escrow(cpi_context)?;
+        }
+
+        // 2. Initialize escrow vault of the lock escrow. The associated token program validates the address.
+        associated_token::create_idempotent(CpiContext::new(
+            self.associated_token_program.clone(),
+            associated_token::Create {
+                payer: self.payer.clone(),
+                associated_token: recipient.escrow_vault.clone(),
+                authority: recipient.lock_escrow.clone(),
+                mint: self.lp_mint.clone(),
+                system_program: self.system_program.clone(),
+                token_program: self.token_program.clone(),
+            },
+        ))?;
+
+        // 3. Lock LP to the recipient
+        let accounts = dynamic_amm::cpi::accounts::Lock {
+            pool: self.pool.clone(),
+            lock_escrow: recipient.lock_escrow.clone(),
+            lp_mint: self.lp_mint.clone(),
+            owner: self.source_owner.clone(),
+            source_tokens: self.source_lp_tokens.clone(),
+            escrow_vault: recipient.escrow_vault.clone(),
+            token_program: self.token_program.clone(),
+            a_vault: self.a_vault.clone(),
+            b_vault: self.b_vault.clone(),
+            a_vault_lp_mint: self.a_vault_lp_mint.clone(),
+            b_vault_lp_mint: self.b_vault_lp_mint.clone(),
+            a_vault_lp: self.a_vault_lp.clone(),
+            b_vault_lp: self.b_vault_lp.clone(),
+        };
 
-    /// CHECK: Associated token program
-    pub associated_token_program: UncheckedAccount<'info>,
+        let cpi_context =
+            CpiContext::new_with_signer(self.dynamic_amm_program.clone(), accounts, signer_seeds);
+        dynamic_amm::cpi::lock(cpi_context, recipient.lp_amount)
+    }
 }
 
-struct LockUserAccountsAndInfo<'b, 'info> {
+/// Lock escrow of a recipient, and the amount of LP locked to it
+pub(crate) struct LockRecipient<'b, 'info> {
     pub lp_amount: u64,
-    pub lock_escrow: &'b UncheckedAccount<'info>,
-    pub escrow_vault: &'b Account<'info, TokenAccount>,
-    pub owner: &'b UncheckedAccount<'info>,
+    pub owner: &'b AccountInfo<'info>,
+    pub lock_escrow: &'b AccountInfo<'info>,
+    pub escrow_vault: &'b AccountInfo<'info>,
 }
 
-/// Lock liquidity of a user to multiple users. Each user can claim fee on locked liquidity based on their allocation.
-///
-/// # Arguments
-///
-/// * `ctx` - The context containing accounts and programs.
-/// * `allocations` - The percentage of liquidity to be locked for each user. The values must add up to 10_000.
+/// Split the LP to the recipients by the allocations. The last recipient gets the remainder of the rounding.
 ///
-/// # Returns
-///
-/// Returns a `Result` indicating success or failure.
-pub fn handle_lock_liquidity(ctx: Context<DynamicAmmLockLiquidity>, allocations: [u16; 2]) -> Result<()> {
+/// Each recipient is passed as the owner, lock escrow and escrow vault triple in the remaining accounts, in the order of the allocations.
+fn get_lock_recipients<'b, 'info>(
+    lp_amount: u64,
+    allocations: &[u16],
+    remaining_accounts: &'b [AccountInfo<'info>],
+) -> Result<Vec<LockRecipient<'b, 'info>>> {
+    require!(
+        !allocations.is_empty() && allocations.len() <= MAX_LOCK_RECIPIENTS,
+        CpiExampleError::InvalidLockRecipientCount
+    );
+
+    require!(
+        remaining_accounts.len() == allocations.len() * 3,
+        CpiExampleError::InvalidLockRecipientAccounts
+    );
+
     let total_bps: u32 = allocations
         .iter()
         .map(|alloc| Into::<u32>::into(*alloc))
         .sum();
 
-    assert!(total_bps == 10_000, "Invalid total bps");
-
-    let user_0_lp_amount: u64 = u128::from(ctx.accounts.source_lp_tokens.amount)
-        .checked_mul(allocations[0].into())
-        .unwrap()
-        .checked_div(10_000)
-        .unwrap()
-        .try_into()
-        .unwrap();
-
-    let user_1_lp_amount = ctx
-        .accounts
-        .source_lp_tokens
-        .amount
-        .checked_sub(user_0_lp_amount)
-        .unwrap();
-
-    let user_accounts_and_info = [
-        LockUserAccountsAndInfo {
-            lp_amount: user_0_lp_amount,
-            lock_escrow: &ctx.accounts.lock_escrow_0,
-            escrow_vault: ctx.accounts.escrow_vault_0.as_ref(),
-            owner: &ctx.accounts.user_0,
-        },
-        LockUserAccountsAndInfo {
-            lp_amount: user_1_lp_amount,
-            lock_escrow: &ctx.accounts.lock_escrow_1,
-            escrow_vault: ctx.accounts.escrow_vault_1.as_ref(),
-            owner: &ctx.accounts.user_1,
-        },
-    ];
+    require!(total_bps == 10_000, CpiExampleError::InvalidAllocation);
+
+    let mut remaining_lp_amount = lp_amount;
+    let mut recipients = Vec::with_capacity(allocations.len());
 
-    for LockUserAccountsAndInfo {
-        lp_amount,
-        lock_escrow,
-        escrow_vault,
-        owner,
-    } in user_accounts_and_info
+    for (i, (allocation, accounts)) in allocations
+        .iter()
+        .zip(remaining_accounts.chunks_exact(3))
+        .enumerate()
     {
-        // 1. Initialize stake escrow
-        let accounts = dynamic_amm::cpi::accounts::CreateLockEscrow {
-            pool: ctx.accounts.pool.to_account_info(),
-            lock_escrow: lock_escrow.to_account_info(),
-            owner: owner.to_account_info(),
-            lp_mint: ctx.accounts.lp_mint.to_account_info(),
-            payer: ctx.accounts.payer.to_account_info(),
-            system_program: ctx.accounts.system_program.to_account_info(),
+        let recipient_lp_amount = if i == allocations.len() - 1 {
+            remaining_lp_amount
+        } else {
+            u128::from(lp_amount)
+                .checked_mul((*allocation).into())
+                .ok_or(CpiExampleError::MathOverflow)?
+                .checked_div(10_000)
+                .ok_or(CpiExampleError::MathOverflow)?
+                .try_into()
+                .map_err(|_| CpiExampleError::MathOverflow)?
         };
 
-        let cpi_context =
-            CpiContext::new(ctx.accounts.dynamic_amm_program.to_account_info(), accounts);
-        dynamic_amm::cpi::create_lock_escrow(cpi_context)?;
+        remaining_lp_amount = remaining_lp_amount
+            .checked_sub(recipient_lp_amount)
+            .ok_or(CpiExampleError::MathOverflow)?;
 
-        // 2. Lock user LP to user
-        let accounts = dynamic_amm::cpi::accounts::Lock {
-            pool: ctx.accounts.pool.to_account_info(),
-            lock_escrow: lock_escrow.to_account_info(),
-            lp_mint: ctx.accounts.lp_mint.to_account_info(),
-            owner: ctx.accounts.payer.to_account_info(),
-            source_tokens: ctx.accounts.source_lp_tokens.to_account_info(),
-            escrow_vault: escrow_vault.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-            a_vault: ctx.accounts.a_vault.to_account_info(),
-            b_vault: ctx.accounts.b_vault.to_account_info(),
-            a_vault_lp_mint: ctx.accounts.a_vault_lp_mint.to_account_info(),
-            b_vault_lp_mint: ctx.accounts.b_vault_lp_mint.to_account_info(),
-            a_vault_lp: ctx.accounts.a_vault_lp.to_account_info(),
-            b_vault_lp: ctx.accounts.b_vault_lp.to_account_info(),
-        };
+        recipients.push(LockRecipient {
+            lp_amount: recipient_lp_amount,
+            owner: &accounts[0],
+            lock_escrow: &accounts[1],
+            escrow_vault: &accounts[2],
+        });
+    }
 
-        let cpi_context =
-            CpiContext::new(ctx.accounts.dynamic_amm_program.to_account_info(), accounts);
-        dynamic_amm::cpi::lock(cpi_context, lp_amount)?;
+    Ok(recipients)
+}
+
+/// Lock liquidity of a user to multiple users. Each user can claim fee on locked liquidity based on their allocation.
+///
+/// The owner, lock escrow and escrow vault of each user are passed as a triple in the remaining accounts, in the order of the allocations.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `allocations` - The percentage of liquidity to be locked for each user. The values must add up to 10_000.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_lock_liquidity<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, DynamicAmmLockLiquidity<'info>>,
+    allocations: Vec<u16>,
+) -> Result<()> {
+    let recipients = get_lock_recipients(
+        ctx.accounts.source_lp_tokens.amount,
+        &allocations,
+        ctx.remaining_accounts,
+    )?;
+
+    let lock_accounts = ctx.accounts.lock_accounts();
+
+    for recipient in recipients {
+        lock_accounts.lock(&recipient, &[])?;
+
+        emit_cpi!(EvtLockLiquidity {
+            pool: ctx.accounts.pool.key(),
+            owner: recipient.owner.key(),
+            lock_escrow: recipient.lock_escrow.key(),
+            lp_amount: recipient.lp_amount,
+        });
     }
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DynamicAmmLockLiquidityPdaCreator<'info> {
     /// CHECK: Pool account (PDA)
@@ -203,22 +286,17 @@ pub struct DynamicAmmLockLiquidityPdaCreator<'info> {
     /// CHECK: Pool LP mint
     pub lp_mint: Box<Account<'info, Mint>>,
 
-    /// CHECK: Lock escrow for pool creator. PDA.
-    #[account(mut)]
-    pub lock_escrow_creator: UncheckedAccount<'info>,
-
-    /// CHECK: Lock escrow for user 0
-    #[account(mut)]
-    pub lock_escrow_0: UncheckedAccount<'info>,
-
     /// CHECK: Pool creator authority. PDA.
     #[account(
         mut,
-        seeds = [b"creator"],
+        seeds = [b"creator", owner.key().as_ref(), pool.key().as_ref()],
         bump
     )]
     pub creator_authority: UncheckedAccount<'info>,
 
+    /// Owner of the creator authority PDA. Creator authorities are isolated per owner and pool.
+    pub owner: Signer<'info>,
+
     /// CHECK: Creator lp token account
     #[account(
         mut,
@@ -227,34 +305,17 @@ pub struct DynamicAmmLockLiquidityPdaCreator<'info> {
     )]
     pub source_lp_tokens: Box<Account<'info, TokenAccount>>,
 
-    /// CHECK: Escrow vault pool creator
-    #[account(
-        init_if_needed,
-        associated_token::mint = lp_mint,
-        associated_token::authority = lock_escrow_creator,
-        payer = payer
-    )]
-    pub escrow_vault_creator: Box<Account<'info, TokenAccount>>,
-
-    /// CHECK: Escrow vault 0
-    #[account(
-        init_if_needed,
-        associated_token::mint = lp_mint,
-        associated_token::authority = lock_escrow_0,
-        payer = payer
-    )]
-    pub escrow_vault_0: Box<Account<'info, TokenAccount>>,
+    /// Program config. Stores the admin of the creator authority PDA instructions.
+    #[account(seeds = [b"config"], bump = program_config.bump)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
 
     /// CHECK: CPI example program admin. Only admin can call this instruction. Also funder for account rental.
     #[account(
-        mut, 
-        constraint = crate::assert_eq_admin(payer.key()
-    ))]
+        mut,
+        constraint = program_config.admin == payer.key() @ CpiExampleError::InvalidAdmin
+    )]
     pub payer: Signer<'info>,
 
-    /// CHECK: User 0 account
-    pub user_0: UncheckedAccount<'info>,
-
     /// CHECK: Vault account for token a. token a of the pool will be deposit / withdraw from this vault account.
     pub a_vault: UncheckedAccount<'info>,
 
@@ -277,17 +338,42 @@ pub struct DynamicAmmLockLiquidityPdaCreator<'info> {
     #[account(address = dynamic_amm::ID)]
     pub dynamic_amm_program: UncheckedAccount<'info>,
 
-    /// CHECK: System program
-    pub system_program: UncheckedAccount<'info>,
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
 
-    /// CHECK: Token program.
-    pub token_program: UncheckedAccount<'info>,
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
 
-    /// CHECK: Associated token program
-    pub associated_token_program: UncheckedAccount<'info>,
+impl<'info> DynamicAmmLockLiquidityPdaCreator<'info> {
+    fn lock_accounts(&self) -> LockAccounts<'info> {
+        LockAccounts {
+            pool: self.pool.to_account_info(),
+            lp_mint: self.lp_mint.to_account_info(),
+            source_lp_tokens: self.source_lp_tokens.to_account_info(),
+            source_owner: self.creator_authority.to_account_info(),
+            payer: self.payer.to_account_info(),
+            a_vault: self.a_vault.to_account_info(),
+            b_vault: self.b_vault.to_account_info(),
+            a_vault_lp: self.a_vault_lp.to_account_info(),
+            b_vault_lp: self.b_vault_lp.to_account_info(),
+            a_vault_lp_mint: self.a_vault_lp_mint.to_account_info(),
+            b_vault_lp_mint: self.b_vault_lp_mint.to_account_info(),
+            dynamic_amm_program: self.dynamic_amm_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+        }
+    }
 }
 
-/// Lock liquidity of pool creator PDA to self and an user. Each can claim fee on locked liquidity based on their allocation.
+/// Lock liquidity of pool creator PDA to multiple users. Each can claim fee on locked liquidity based on their allocation.
+///
+/// The owner, lock escrow and escrow vault of each user are passed as a triple in the remaining accounts, in the order of the allocations.
+/// The pool creator PDA locks to self by being one of the users.
 ///
 /// # Arguments
 ///
@@ -297,114 +383,39 @@ pub struct DynamicAmmLockLiquidityPdaCreator<'info> {
 /// # Returns
 ///
 /// Returns a `Result` indicating success or failure.
-pub fn handle_lock_liquidity_pda_creator(
-    ctx: Context<DynamicAmmLockLiquidityPdaCreator>,
-    allocations: [u16; 2],
+pub fn handle_lock_liquidity_pda_creator<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, DynamicAmmLockLiquidityPdaCreator<'info>>,
+    allocations: Vec<u16>,
 ) -> Result<()> {
-    let total_bps: u32 = allocations
-        .iter()
-        .map(|alloc| Into::<u32>::into(*alloc))
-        .sum();
-
-    assert!(total_bps == 10_000, "Invalid total bps");
-
-    let pda_creator_lp_amount: u64 = u128::from(ctx.accounts.source_lp_tokens.amount)
-        .checked_mul(allocations[0].into())
-        .unwrap()
-        .checked_div(10_000)
-        .unwrap()
-        .try_into()
-        .unwrap();
-
-    let user_1_lp_amount = ctx
-        .accounts
-        .source_lp_tokens
-        .amount
-        .checked_sub(pda_creator_lp_amount)
-        .unwrap();
-
-    // 1. Initialize lock escrow for pool creator PDA
-    let accounts = dynamic_amm::cpi::accounts::CreateLockEscrow {
-        pool: ctx.accounts.pool.to_account_info(),
-        lock_escrow: ctx.accounts.lock_escrow_creator.to_account_info(),
-        owner: ctx.accounts.creator_authority.to_account_info(),
-        lp_mint: ctx.accounts.lp_mint.to_account_info(),
-        payer: ctx.accounts.payer.to_account_info(),
-        system_program: ctx.accounts.system_program.to_account_info(),
-    };
-
-    let cpi_context = CpiContext::new(
-        ctx.accounts.dynamic_amm_program.to_account_info(),
-        accounts,
-    );
-
-    dynamic_amm::cpi::create_lock_escrow(cpi_context)?;
-
-    // 2. Lock pool creator PDA LP to pool creator PDA lock escrow
-    let accounts = dynamic_amm::cpi::accounts::Lock {
-        pool: ctx.accounts.pool.to_account_info(),
-        lock_escrow: ctx.accounts.lock_escrow_creator.to_account_info(),
-        lp_mint: ctx.accounts.lp_mint.to_account_info(),
-        owner: ctx.accounts.creator_authority.to_account_info(),
-        source_tokens: ctx.accounts.source_lp_tokens.to_account_info(),
-        escrow_vault: ctx.accounts.escrow_vault_creator.to_account_info(),
-        token_program: ctx.accounts.token_program.to_account_info(),
-        a_vault: ctx.accounts.a_vault.to_account_info(),
-        b_vault: ctx.accounts.b_vault.to_account_info(),
-        a_vault_lp_mint: ctx.accounts.a_vault_lp_mint.to_account_info(),
-        b_vault_lp_mint: ctx.accounts.b_vault_lp_mint.to_account_info(),
-        a_vault_lp: ctx.accounts.a_vault_lp.to_account_info(),
-        b_vault_lp: ctx.accounts.b_vault_lp.to_account_info(),
-    };
-
+    let recipients = get_lock_recipients(
+        ctx.accounts.source_lp_tokens.amount,
+        &allocations,
+        ctx.remaining_accounts,
+    )?;
+
+    let owner_key = ctx.accounts.owner.key();
+    let pool_key = ctx.accounts.pool.key();
     let seeds = [
         b"creator".as_ref(),
-        &[ctx.bumps.creator_authority]
+        owner_key.as_ref(),
+        pool_key.as_ref(),
+        &[ctx.bumps.creator_authority],
     ];
 
     let signer_seeds = &[&seeds[..]];
 
-    let cpi_context =
-        CpiContext::new_with_signer(ctx.accounts.dynamic_amm_program.to_account_info(), accounts, signer_seeds);
-    dynamic_amm::cpi::lock(cpi_context, pda_creator_lp_amount)?;
-
-    // 3. Initialize lock escrow for user 1
-    let accounts = dynamic_amm::cpi::accounts::CreateLockEscrow {
-        pool: ctx.accounts.pool.to_account_info(),
-        lock_escrow: ctx.accounts.lock_escrow_0.to_account_info(),
-        owner: ctx.accounts.user_0.to_account_info(),
-        lp_mint: ctx.accounts.lp_mint.to_account_info(),
-        payer: ctx.accounts.payer.to_account_info(),
-        system_program: ctx.accounts.system_program.to_account_info(),
-    };
-
-    let cpi_context = CpiContext::new(
-        ctx.accounts.dynamic_amm_program.to_account_info(),
-        accounts,
-    );
+    let lock_accounts = ctx.accounts.lock_accounts();
 
-    dynamic_amm::cpi::create_lock_escrow(cpi_context)?;
-
-    // 4. Lock pool creator PDA LP to user 1 lock escrow
-    let accounts = dynamic_amm::cpi::accounts::Lock {
-        pool: ctx.accounts.pool.to_account_info(),
-        lock_escrow: ctx.accounts.lock_escrow_0.to_account_info(),
-        lp_mint: ctx.accounts.lp_mint.to_account_info(),
-        owner: ctx.accounts.creator_authority.to_account_info(),
-        source_tokens: ctx.accounts.source_lp_tokens.to_account_info(),
-        escrow_vault: ctx.accounts.escrow_vault_0.to_account_info(),
-        token_program: ctx.accounts.token_program.to_account_info(),
-        a_vault: ctx.accounts.a_vault.to_account_info(),
-        b_vault: ctx.accounts.b_vault.to_account_info(),
-        a_vault_lp_mint: ctx.accounts.a_vault_lp_mint.to_account_info(),
-        b_vault_lp_mint: ctx.accounts.b_vault_lp_mint.to_account_info(),
-        a_vault_lp: ctx.accounts.a_vault_lp.to_account_info(),
-        b_vault_lp: ctx.accounts.b_vault_lp.to_account_info(),
-    };
-
-    let cpi_context =
-        CpiContext::new_with_signer(ctx.accounts.dynamic_amm_program.to_account_info(), accounts, signer_seeds);
-    dynamic_amm::cpi::lock(cpi_context, user_1_lp_amount)?; 
+    for recipient in recipients {
+        lock_accounts.lock(&recipient, signer_seeds)?;
+
+        emit_cpi!(EvtLockLiquidity {
+            pool: ctx.accounts.pool.key(),
+            owner: recipient.owner.key(),
+            lock_escrow: recipient.lock_escrow.key(),
+            lp_amount: recipient.lp_amount,
+        });
+    }
 
     Ok(())
 }
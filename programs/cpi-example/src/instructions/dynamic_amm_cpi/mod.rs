@@ -15,3 +15,17 @@ pub use lock_liquidity::*;
 
 pub mod claim_fee;
 pub use claim_fee::*;
+
+pub mod balance_liquidity;
+pub use balance_liquidity::*;
+
+pub mod imbalance_liquidity;
+pub use imbalance_liquidity::*;
+
+pub mod pool_utils;
+
+pub mod zap_in;
+pub use zap_in::*;
+
+pub mod zap_out;
+pub use zap_out::*;
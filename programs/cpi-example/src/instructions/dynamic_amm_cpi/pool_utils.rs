@@ -0,0 +1,157 @@
+use crate::dynamic_amm::accounts::Pool;
+use crate::dynamic_amm::types::CurveType;
+use crate::dynamic_vault::accounts::Vault;
+use crate::errors::CpiExampleError;
+use anchor_lang::prelude::*;
+
+/// Denominator of the locked profit degradation of the vault
+const LOCKED_PROFIT_DEGRADATION_DENOMINATOR: u128 = 1_000_000_000_000;
+
+fn mul_div(x: u64, y: u64, denominator: u64) -> Result<u64> {
+    u128::from(x)
+        .checked_mul(y.into())
+        .ok_or(CpiExampleError::MathOverflow)?
+        .checked_div(denominator.into())
+        .ok_or(CpiExampleError::MathOverflow)?
+        .try_into()
+        .map_err(|_| CpiExampleError::MathOverflow.into())
+}
+
+/// Amount of the vault excluding the profit which is still locked at the current time
+pub fn get_unlocked_amount(vault: &Vault, current_time: u64) -> Result<u64> {
+    let tracker = &vault.locked_profit_tracker;
+
+    let duration = u128::from(current_time.saturating_sub(tracker.last_report));
+    let locked_profit_ratio = duration
+        .checked_mul(tracker.locked_profit_degradation.into())
+        .ok_or(CpiExampleError::MathOverflow)?;
+
+    let locked_profit = if locked_profit_ratio > LOCKED_PROFIT_DEGRADATION_DENOMINATOR {
+        0
+    } else {
+        u128::from(tracker.last_updated_locked_profit)
+            .checked_mul(LOCKED_PROFIT_DEGRADATION_DENOMINATOR - locked_profit_ratio)
+            .ok_or(CpiExampleError::MathOverflow)?
+            / LOCKED_PROFIT_DEGRADATION_DENOMINATOR
+    };
+
+    vault
+        .total_amount
+        .checked_sub(
+            locked_profit
+                .try_into()
+                .map_err(|_| CpiExampleError::MathOverflow)?,
+        )
+        .ok_or(CpiExampleError::MathOverflow.into())
+}
+
+/// Token reserve of one side of the pool. The pool holds the token as the LP of the vault.
+pub struct VaultReserve {
+    /// Vault LP held by the pool
+    pub pool_vault_lp_amount: u64,
+    /// Total supply of the vault LP
+    pub vault_lp_supply: u64,
+    /// Unlocked amount of the vault
+    pub vault_unlocked_amount: u64,
+}
+
+impl VaultReserve {
+    /// Token amount of the pool backed by the vault LP held by the pool
+    pub fn token_amount(&self) -> Result<u64> {
+        mul_div(
+            self.pool_vault_lp_amount,
+            self.vault_unlocked_amount,
+            self.vault_lp_supply,
+        )
+    }
+
+    /// Pool LP which can be minted by depositing at most `token_amount` to this side of the pool.
+    ///
+    /// Both conversions are rounded down, because the pool rounds up the vault LP and the token amount
+    /// required by the minted LP.
+    pub fn pool_lp_amount(&self, token_amount: u64, pool_lp_supply: u64) -> Result<u64> {
+        let vault_lp_amount = mul_div(
+            token_amount,
+            self.vault_lp_supply,
+            self.vault_unlocked_amount,
+        )?;
+        mul_div(vault_lp_amount, pool_lp_supply, self.pool_vault_lp_amount)
+    }
+}
+
+/// Amount of the input token to be swapped, so the rest of the input token and the swap output
+/// are deposited in the ratio of the pool reserves after the swap.
+///
+/// The output of a constant product pool is exact. The output of a stable pool is estimated at
+/// the peg, adjusted by the token multipliers of the pool.
+pub fn get_zap_in_swap_amount(
+    pool: &Pool,
+    reserve_in: u64,
+    reserve_out: u64,
+    amount: u64,
+    a_to_b: bool,
+) -> Result<u64> {
+    let fees = &pool.fees;
+
+    let swap_out = |in_amount: u64| -> Result<(u64, u64)> {
+        let trade_fee = mul_div(
+            in_amount,
+            fees.trade_fee_numerator,
+            fees.trade_fee_denominator,
+        )?;
+        let protocol_fee = mul_div(
+            trade_fee,
+            fees.protocol_trade_fee_numerator,
+            fees.protocol_trade_fee_denominator,
+        )?;
+        let in_amount_after_fee = in_amount - trade_fee;
+
+        let out_amount = match &pool.curve_type {
+            CurveType::ConstantProduct => mul_div(
+                reserve_out,
+                in_amount_after_fee,
+                reserve_in
+                    .checked_add(in_amount_after_fee)
+                    .ok_or(CpiExampleError::MathOverflow)?,
+            )?,
+            CurveType::Stable {
+                token_multiplier, ..
+            } => {
+                let (multiplier_in, multiplier_out) = if a_to_b {
+                    (
+                        token_multiplier.token_a_multiplier,
+                        token_multiplier.token_b_multiplier,
+                    )
+                } else {
+                    (
+                        token_multiplier.token_b_multiplier,
+                        token_multiplier.token_a_multiplier,
+                    )
+                };
+                mul_div(in_amount_after_fee, multiplier_in, multiplier_out)?.min(reserve_out)
+            }
+        };
+
+        Ok((out_amount, protocol_fee))
+    };
+
+    // Remaining input token over swap output shrinks against the reserve ratio as the swap amount
+    // grows. Search the largest swap amount which still leaves enough input token for the output.
+    let (mut low, mut high) = (0u64, amount);
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        let (out_amount, protocol_fee) = swap_out(mid)?;
+
+        let reserve_in_after = u128::from(reserve_in) + u128::from(mid - protocol_fee);
+        let reserve_out_after = u128::from(reserve_out - out_amount);
+
+        if u128::from(amount - mid) * reserve_out_after >= u128::from(out_amount) * reserve_in_after
+        {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    Ok(low)
+}
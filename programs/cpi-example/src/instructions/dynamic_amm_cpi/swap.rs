@@ -1,6 +1,13 @@
-use crate::dynamic_amm;
+use crate::dynamic_amm::accounts::Pool;
+use crate::errors::CpiExampleError;
+use crate::events::EvtSwap;
+use crate::state::{IntegratorFee, SwapResult};
+use crate::token_utils::{load_token_account, IntegratorFeeCharge, SwapBalances};
+use crate::{dynamic_amm, dynamic_vault};
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, Transfer};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DynamicAmmSwap<'info> {
     #[account(mut)]
@@ -46,35 +53,248 @@ pub struct DynamicAmmSwap<'info> {
     /// CHECK: Protocol fee token account. Used to receive trading fee. It's mint field must matched with user_source_token mint field.
     pub protocol_token_fee: UncheckedAccount<'info>,
 
+    #[account(mut)]
+    /// CHECK: Associated token account of the integrator receiving the integrator fee. Validated by the handler.
+    pub integrator_fee_token: Option<UncheckedAccount<'info>>,
+
     /// CHECK: User account. Must be owner of user_source_token.
     pub user: Signer<'info>,
 
     /// CHECK: Vault program. the pool will deposit/withdraw liquidity from the vault.
     pub vault_program: UncheckedAccount<'info>,
-    /// CHECK: Token program.
-    pub token_program: UncheckedAccount<'info>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
 
     #[account(address = dynamic_amm::ID)]
     /// CHECK: Dynamic AMM program account
     pub dynamic_amm_program: UncheckedAccount<'info>,
 }
 
-/// Executes a Dynamic AMM swap
+/// Executes a Dynamic AMM swap, and returns the actual amounts of the swap measured from the user token balances.
+///
+/// When the integrator fee is charged, the fee is transferred to the integrator fee token account from the input token
+/// before the swap, or from the output token after the swap. The returned amounts and the slippage check include the fee.
 ///
 /// # Arguments
 ///
 /// * `ctx` - The context containing accounts and programs.
-/// * `amount_in` - The amount of input tokens to be swapped.
-/// * `min_amount_out` - The minimum amount of output tokens expected a.k.a slippage
+/// * `in_amount` - The amount of input tokens to be swapped, including the integrator fee.
+/// * `minimum_out_amount` - The minimum amount of output tokens expected a.k.a slippage
+/// * `integrator_fee` - The optional integrator fee charged on the swap.
 ///
 /// # Returns
 ///
-/// Returns a `Result` indicating success or failure.
+/// Returns the [`SwapResult`], which is also set as the return data of the instruction.
 pub fn handle_dynamic_amm_swap(
     ctx: Context<DynamicAmmSwap>,
     in_amount: u64,
     minimum_out_amount: u64,
+    integrator_fee: Option<IntegratorFee>,
+) -> Result<SwapResult> {
+    let balances_before = SwapBalances::load(
+        &ctx.accounts.user_source_token,
+        &ctx.accounts.user_destination_token,
+    )?;
+
+    let integrator_fee_charge = IntegratorFeeCharge::new(
+        integrator_fee,
+        ctx.accounts
+            .integrator_fee_token
+            .as_ref()
+            .map(|account| account.as_ref()),
+        &balances_before,
+    )?;
+
+    let mut swap_in_amount = in_amount;
+    if let Some(charge) = integrator_fee_charge
+        .as_ref()
+        .filter(|charge| charge.is_input_fee())
+    {
+        let fee_amount = charge.fee_amount(in_amount)?;
+        transfer_integrator_fee(
+            ctx.accounts,
+            &ctx.accounts.user_source_token,
+            charge,
+            fee_amount,
+        )?;
+        swap_in_amount = in_amount
+            .checked_sub(fee_amount)
+            .ok_or(CpiExampleError::MathOverflow)?;
+    }
+
+    let accounts = dynamic_amm::cpi::accounts::Swap {
+        pool: ctx.accounts.pool.to_account_info(),
+        user_source_token: ctx.accounts.user_source_token.to_account_info(),
+        user_destination_token: ctx.accounts.user_destination_token.to_account_info(),
+        a_vault: ctx.accounts.a_vault.to_account_info(),
+        b_vault: ctx.accounts.b_vault.to_account_info(),
+        a_token_vault: ctx.accounts.a_token_vault.to_account_info(),
+        b_token_vault: ctx.accounts.b_token_vault.to_account_info(),
+        a_vault_lp_mint: ctx.accounts.a_vault_lp_mint.to_account_info(),
+        b_vault_lp_mint: ctx.accounts.b_vault_lp_mint.to_account_info(),
+        a_vault_lp: ctx.accounts.a_vault_lp.to_account_info(),
+        b_vault_lp: ctx.accounts.b_vault_lp.to_account_info(),
+        protocol_token_fee: ctx.accounts.protocol_token_fee.to_account_info(),
+        user: ctx.accounts.user.to_account_info(),
+        vault_program: ctx.accounts.vault_program.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    };
+
+    let cpi_context = CpiContext::new(ctx.accounts.dynamic_amm_program.to_account_info(), accounts);
+
+    dynamic_amm::cpi::swap(cpi_context, swap_in_amount, minimum_out_amount)?;
+
+    if let Some(charge) = integrator_fee_charge
+        .as_ref()
+        .filter(|charge| !charge.is_input_fee())
+    {
+        let user_destination_token_amount =
+            load_token_account(&ctx.accounts.user_destination_token)?.amount;
+        let swap_out_amount = user_destination_token_amount
+            .checked_sub(balances_before.user_token_out_amount)
+            .ok_or(CpiExampleError::MathOverflow)?;
+        transfer_integrator_fee(
+            ctx.accounts,
+            &ctx.accounts.user_destination_token,
+            charge,
+            charge.fee_amount(swap_out_amount)?,
+        )?;
+    }
+
+    let swap_result = balances_before.swap_result(
+        &ctx.accounts.user_source_token,
+        &ctx.accounts.user_destination_token,
+        minimum_out_amount,
+    )?;
+
+    emit_cpi!(EvtSwap {
+        pool: ctx.accounts.pool.key(),
+        user: ctx.accounts.user.key(),
+        input_mint: balances_before.input_mint,
+        output_mint: balances_before.output_mint,
+        amount_in: swap_result.amount_in,
+        amount_out: swap_result.amount_out,
+    });
+
+    Ok(swap_result)
+}
+
+/// Transfers the integrator fee from the user token account of the charged mint
+fn transfer_integrator_fee<'info>(
+    accounts: &DynamicAmmSwap<'info>,
+    from: &UncheckedAccount<'info>,
+    charge: &IntegratorFeeCharge<'info>,
+    fee_amount: u64,
 ) -> Result<()> {
+    if fee_amount == 0 {
+        return Ok(());
+    }
+
+    anchor_spl::token::transfer(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: from.to_account_info(),
+                to: charge.fee_token.clone(),
+                authority: accounts.user.to_account_info(),
+            },
+        ),
+        fee_amount,
+    )
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DynamicAmmSwapStrict<'info> {
+    #[account(
+        mut,
+        has_one = a_vault,
+        has_one = b_vault,
+        has_one = a_vault_lp,
+        has_one = b_vault_lp,
+    )]
+    /// CHECK: Pool account (PDA)
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(mut)]
+    /// CHECK: User token account. Token from this account will be transfer into the vault by the pool in exchange for another token of the pool.
+    pub user_source_token: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: User token account. The exchanged token will be transfer into this account from the pool.
+    pub user_destination_token: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Vault account for token a. token a of the pool will be deposit / withdraw from this vault account.
+    pub a_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Vault account for token b. token b of the pool will be deposit / withdraw from this vault account.
+    pub b_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Token vault account of vault A. Validated against the vault by the vault program.
+    pub a_token_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Token vault account of vault B. Validated against the vault by the vault program.
+    pub b_token_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Lp token mint of vault a. Validated against the vault by the vault program.
+    pub a_vault_lp_mint: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Lp token mint of vault b. Validated against the vault by the vault program.
+    pub b_vault_lp_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: LP token account of vault A. Used to receive/burn the vault LP upon deposit/withdraw from the vault.
+    pub a_vault_lp: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: LP token account of vault B. Used to receive/burn the vault LP upon deposit/withdraw from the vault.
+    pub b_vault_lp: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = protocol_token_fee.key() == pool.protocol_token_a_fee
+            || protocol_token_fee.key() == pool.protocol_token_b_fee
+            @ CpiExampleError::InvalidProtocolTokenFee
+    )]
+    /// CHECK: Protocol fee token account of the pool. Used to receive trading fee. It's mint field must matched with user_source_token mint field.
+    pub protocol_token_fee: UncheckedAccount<'info>,
+
+    /// CHECK: User account. Must be owner of user_source_token.
+    pub user: Signer<'info>,
+
+    #[account(address = dynamic_vault::ID)]
+    /// CHECK: Vault program. the pool will deposit/withdraw liquidity from the vault.
+    pub vault_program: UncheckedAccount<'info>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = dynamic_amm::ID)]
+    /// CHECK: Dynamic AMM program account
+    pub dynamic_amm_program: UncheckedAccount<'info>,
+}
+
+/// Executes a Dynamic AMM swap after validating the pool accounts against the pool state, and returns the actual amounts of the swap.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `in_amount` - The amount of input tokens to be swapped.
+/// * `minimum_out_amount` - The minimum amount of output tokens expected a.k.a slippage
+///
+/// # Returns
+///
+/// Returns the [`SwapResult`], which is also set as the return data of the instruction.
+pub fn handle_dynamic_amm_swap_strict(
+    ctx: Context<DynamicAmmSwapStrict>,
+    in_amount: u64,
+    minimum_out_amount: u64,
+) -> Result<SwapResult> {
+    let balances_before = SwapBalances::load(
+        &ctx.accounts.user_source_token,
+        &ctx.accounts.user_destination_token,
+    )?;
+
     let accounts = dynamic_amm::cpi::accounts::Swap {
         pool: ctx.accounts.pool.to_account_info(),
         user_source_token: ctx.accounts.user_source_token.to_account_info(),
@@ -95,5 +315,22 @@ pub fn handle_dynamic_amm_swap(
 
     let cpi_context = CpiContext::new(ctx.accounts.dynamic_amm_program.to_account_info(), accounts);
 
-    dynamic_amm::cpi::swap(cpi_context, in_amount, minimum_out_amount)
+    dynamic_amm::cpi::swap(cpi_context, in_amount, minimum_out_amount)?;
+
+    let swap_result = balances_before.swap_result(
+        &ctx.accounts.user_source_token,
+        &ctx.accounts.user_destination_token,
+        minimum_out_amount,
+    )?;
+
+    emit_cpi!(EvtSwap {
+        pool: ctx.accounts.pool.key(),
+        user: ctx.accounts.user.key(),
+        input_mint: balances_before.input_mint,
+        output_mint: balances_before.output_mint,
+        amount_in: swap_result.amount_in,
+        amount_out: swap_result.amount_out,
+    });
+
+    Ok(swap_result)
 }
@@ -0,0 +1,365 @@
+use crate::dynamic_amm::accounts::Pool;
+use crate::dynamic_amm_cpi::lock_liquidity::{LockAccounts, LockRecipient};
+use crate::dynamic_amm_cpi::pool_utils::{
+    get_unlocked_amount, get_zap_in_swap_amount, VaultReserve,
+};
+use crate::dynamic_vault::accounts::Vault;
+use crate::errors::CpiExampleError;
+use crate::events::{EvtAddLiquidity, EvtLockLiquidity, EvtSwap};
+use crate::token_utils::{LiquidityBalances, SwapBalances};
+use crate::{dynamic_amm, dynamic_vault};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DynamicAmmZapIn<'info> {
+    #[account(
+        mut,
+        has_one = lp_mint,
+        has_one = a_vault,
+        has_one = b_vault,
+        has_one = a_vault_lp,
+        has_one = b_vault_lp,
+    )]
+    /// CHECK: Pool account (PDA)
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(mut)]
+    /// LP mint of the pool
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    /// CHECK: User LP token account. Receives the minted LP, and the LP is locked from it.
+    pub user_pool_lp: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: User token A account. Token A is zapped from / deposited from this account.
+    pub user_a_token: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: User token B account. Token B is zapped from / deposited from this account.
+    pub user_b_token: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = a_vault.lp_mint == a_vault_lp_mint.key())]
+    /// Vault account for token a. token a of the pool will be deposit / withdraw from this vault account.
+    pub a_vault: Box<Account<'info, Vault>>,
+    #[account(mut, constraint = b_vault.lp_mint == b_vault_lp_mint.key())]
+    /// Vault account for token b. token b of the pool will be deposit / withdraw from this vault account.
+    pub b_vault: Box<Account<'info, Vault>>,
+
+    #[account(mut)]
+    /// CHECK: Token vault account of vault A. Validated against the vault by the vault program.
+    pub a_token_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Token vault account of vault B. Validated against the vault by the vault program.
+    pub b_token_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// Lp token mint of vault a
+    pub a_vault_lp_mint: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    /// Lp token mint of vault b
+    pub b_vault_lp_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    /// LP token account of vault A. Used to receive/burn the vault LP upon deposit/withdraw from the vault.
+    pub a_vault_lp: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    /// LP token account of vault B. Used to receive/burn the vault LP upon deposit/withdraw from the vault.
+    pub b_vault_lp: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = protocol_token_fee.key() == pool.protocol_token_a_fee
+            || protocol_token_fee.key() == pool.protocol_token_b_fee
+            @ CpiExampleError::InvalidProtocolTokenFee
+    )]
+    /// CHECK: Protocol fee token account of the pool. Used to receive trading fee. It's mint field must matched with the zapped token.
+    pub protocol_token_fee: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Lock escrow of the user. Created when it doesn't exist. The minted LP is locked when provided.
+    pub lock_escrow: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Escrow vault. Associated token account of the lock escrow. Created when it doesn't exist.
+    pub escrow_vault: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// User account. Must be owner of the user token accounts. Also funder for account rental of the lock escrow.
+    pub user: Signer<'info>,
+
+    #[account(address = dynamic_vault::ID)]
+    /// CHECK: Vault program. the pool will deposit/withdraw liquidity from the vault.
+    pub vault_program: UncheckedAccount<'info>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+    /// Associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    #[account(address = dynamic_amm::ID)]
+    /// CHECK: Dynamic AMM program account
+    pub dynamic_amm_program: UncheckedAccount<'info>,
+}
+
+impl<'info> DynamicAmmZapIn<'info> {
+    /// Token A and token B reserves of the pool
+    fn vault_reserves(&self, current_time: u64) -> Result<(VaultReserve, VaultReserve)> {
+        Ok((
+            VaultReserve {
+                pool_vault_lp_amount: self.a_vault_lp.amount,
+                vault_lp_supply: self.a_vault_lp_mint.supply,
+                vault_unlocked_amount: get_unlocked_amount(&self.a_vault, current_time)?,
+            },
+            VaultReserve {
+                pool_vault_lp_amount: self.b_vault_lp.amount,
+                vault_lp_supply: self.b_vault_lp_mint.supply,
+                vault_unlocked_amount: get_unlocked_amount(&self.b_vault, current_time)?,
+            },
+        ))
+    }
+
+    /// Reload the pool and vault states changed by the swap
+    fn reload_reserves(&mut self) -> Result<()> {
+        self.a_vault.reload()?;
+        self.b_vault.reload()?;
+        self.a_vault_lp_mint.reload()?;
+        self.b_vault_lp_mint.reload()?;
+        self.a_vault_lp.reload()?;
+        self.b_vault_lp.reload()?;
+        self.lp_mint.reload()
+    }
+
+    fn swap(&self, a_to_b: bool, in_amount: u64) -> Result<()> {
+        let (user_source_token, user_destination_token) = if a_to_b {
+            (&self.user_a_token, &self.user_b_token)
+        } else {
+            (&self.user_b_token, &self.user_a_token)
+        };
+
+        let accounts = dynamic_amm::cpi::accounts::Swap {
+            pool: self.pool.to_account_info(),
+            user_source_token: user_source_token.to_account_info(),
+            user_destination_token: user_destination_token.to_account_info(),
+            a_vault: self.a_vault.to_account_info(),
+            b_vault: self.b_vault.to_account_info(),
+            a_token_vault: self.a_token_vault.to_account_info(),
+            b_token_vault: self.b_token_vault.to_account_info(),
+            a_vault_lp_mint: self.a_vault_lp_mint.to_account_info(),
+            b_vault_lp_mint: self.b_vault_lp_mint.to_account_info(),
+            a_vault_lp: self.a_vault_lp.to_account_info(),
+            b_vault_lp: self.b_vault_lp.to_account_info(),
+            protocol_token_fee: self.protocol_token_fee.to_account_info(),
+            user: self.user.to_account_info(),
+            vault_program: self.vault_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        };
+
+        let cpi_context = CpiContext::new(self.dynamic_amm_program.to_account_info(), accounts);
+
+        // The overall slippage is checked against the minted LP
+        dynamic_amm::cpi::swap(cpi_context, in_amount, 0)
+    }
+
+    fn add_balance_liquidity(
+        &self,
+        pool_token_amount: u64,
+        maximum_token_a_amount: u64,
+        maximum_token_b_amount: u64,
+    ) -> Result<()> {
+        let accounts = dynamic_amm::cpi::accounts::AddBalanceLiquidity {
+            pool: self.pool.to_account_info(),
+            lp_mint: self.lp_mint.to_account_info(),
+            user_pool_lp: self.user_pool_lp.to_account_info(),
+            a_vault_lp: self.a_vault_lp.to_account_info(),
+            b_vault_lp: self.b_vault_lp.to_account_info(),
+            a_vault: self.a_vault.to_account_info(),
+            b_vault: self.b_vault.to_account_info(),
+            a_vault_lp_mint: self.a_vault_lp_mint.to_account_info(),
+            b_vault_lp_mint: self.b_vault_lp_mint.to_account_info(),
+            a_token_vault: self.a_token_vault.to_account_info(),
+            b_token_vault: self.b_token_vault.to_account_info(),
+            user_a_token: self.user_a_token.to_account_info(),
+            user_b_token: self.user_b_token.to_account_info(),
+            user: self.user.to_account_info(),
+            vault_program: self.vault_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        };
+
+        let cpi_context = CpiContext::new(self.dynamic_amm_program.to_account_info(), accounts);
+
+        dynamic_amm::cpi::add_balance_liquidity(
+            cpi_context,
+            pool_token_amount,
+            maximum_token_a_amount,
+            maximum_token_b_amount,
+        )
+    }
+
+    fn lock_accounts(&self) -> LockAccounts<'info> {
+        LockAccounts {
+            pool: self.pool.to_account_info(),
+            lp_mint: self.lp_mint.to_account_info(),
+            source_lp_tokens: self.user_pool_lp.to_account_info(),
+            source_owner: self.user.to_account_info(),
+            payer: self.user.to_account_info(),
+            a_vault: self.a_vault.to_account_info(),
+            b_vault: self.b_vault.to_account_info(),
+            a_vault_lp: self.a_vault_lp.to_account_info(),
+            b_vault_lp: self.b_vault_lp.to_account_info(),
+            a_vault_lp_mint: self.a_vault_lp_mint.to_account_info(),
+            b_vault_lp_mint: self.b_vault_lp_mint.to_account_info(),
+            dynamic_amm_program: self.dynamic_amm_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+        }
+    }
+}
+
+/// Zaps a single token into a Dynamic AMM pool. Part of the input token is swapped to the other token of the pool,
+/// then both tokens are deposited in a balanced ratio. The minted LP is locked to the lock escrow of the user when
+/// the lock escrow and the escrow vault are provided.
+///
+/// The swap amount is computed from the pool and vault reserves, so the tokens left in the user token accounts are minimal.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `in_amount` - The amount of input token to be zapped.
+/// * `a_to_b` - Whether the input token is token A of the pool.
+/// * `minimum_pool_token_amount` - The minimum amount of LP to be minted a.k.a slippage
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_zap_in(
+    ctx: Context<DynamicAmmZapIn>,
+    in_amount: u64,
+    a_to_b: bool,
+    minimum_pool_token_amount: u64,
+) -> Result<()> {
+    let lock_accounts = match (&ctx.accounts.lock_escrow, &ctx.accounts.escrow_vault) {
+        (Some(lock_escrow), Some(escrow_vault)) => {
+            Some((lock_escrow.clone(), escrow_vault.clone()))
+        }
+        (None, None) => None,
+        _ => return Err(CpiExampleError::MissingLockEscrow.into()),
+    };
+
+    let current_time: u64 = Clock::get()?
+        .unix_timestamp
+        .try_into()
+        .map_err(|_| CpiExampleError::MathOverflow)?;
+
+    let (user_token_in, user_token_out) = if a_to_b {
+        (&ctx.accounts.user_a_token, &ctx.accounts.user_b_token)
+    } else {
+        (&ctx.accounts.user_b_token, &ctx.accounts.user_a_token)
+    };
+
+    // 1. Swap part of the input token to the other token
+    let swap_balances_before = SwapBalances::load(user_token_in, user_token_out)?;
+
+    let (reserve_a, reserve_b) = ctx.accounts.vault_reserves(current_time)?;
+    let (reserve_in, reserve_out) = if a_to_b {
+        (reserve_a.token_amount()?, reserve_b.token_amount()?)
+    } else {
+        (reserve_b.token_amount()?, reserve_a.token_amount()?)
+    };
+
+    let swap_amount = get_zap_in_swap_amount(
+        &ctx.accounts.pool,
+        reserve_in,
+        reserve_out,
+        in_amount,
+        a_to_b,
+    )?;
+
+    if swap_amount > 0 {
+        ctx.accounts.swap(a_to_b, swap_amount)?;
+    }
+
+    let swap_result = swap_balances_before.swap_result(user_token_in, user_token_out, 0)?;
+
+    emit_cpi!(EvtSwap {
+        pool: ctx.accounts.pool.key(),
+        user: ctx.accounts.user.key(),
+        input_mint: swap_balances_before.input_mint,
+        output_mint: swap_balances_before.output_mint,
+        amount_in: swap_result.amount_in,
+        amount_out: swap_result.amount_out,
+    });
+
+    // 2. Deposit the rest of the input token and the swap output
+    ctx.accounts.reload_reserves()?;
+
+    let remaining_in_amount = in_amount
+        .checked_sub(swap_result.amount_in)
+        .ok_or(CpiExampleError::MathOverflow)?;
+    let (deposit_a_amount, deposit_b_amount) = if a_to_b {
+        (remaining_in_amount, swap_result.amount_out)
+    } else {
+        (swap_result.amount_out, remaining_in_amount)
+    };
+
+    let (reserve_a, reserve_b) = ctx.accounts.vault_reserves(current_time)?;
+    let lp_supply = ctx.accounts.lp_mint.supply;
+    let pool_token_amount = reserve_a
+        .pool_lp_amount(deposit_a_amount, lp_supply)?
+        .min(reserve_b.pool_lp_amount(deposit_b_amount, lp_supply)?);
+
+    // The zap must mint some LP even when the caller accepts any amount
+    require!(
+        pool_token_amount >= minimum_pool_token_amount.max(1),
+        CpiExampleError::ExceededLpSlippage
+    );
+
+    let liquidity_balances_before = LiquidityBalances::load(
+        &ctx.accounts.user_pool_lp,
+        &ctx.accounts.user_a_token,
+        &ctx.accounts.user_b_token,
+    )?;
+
+    ctx.accounts
+        .add_balance_liquidity(pool_token_amount, deposit_a_amount, deposit_b_amount)?;
+
+    let changes = liquidity_balances_before.changes(
+        &ctx.accounts.user_pool_lp,
+        &ctx.accounts.user_a_token,
+        &ctx.accounts.user_b_token,
+    )?;
+
+    emit_cpi!(EvtAddLiquidity {
+        pool: ctx.accounts.pool.key(),
+        owner: ctx.accounts.user.key(),
+        lp_amount: changes.pool_lp_amount,
+        token_a_amount: changes.token_a_amount,
+        token_b_amount: changes.token_b_amount,
+    });
+
+    // 3. Lock the minted LP
+    if let Some((lock_escrow, escrow_vault)) = lock_accounts {
+        ctx.accounts.lock_accounts().lock(
+            &LockRecipient {
+                lp_amount: changes.pool_lp_amount,
+                owner: ctx.accounts.user.as_ref(),
+                lock_escrow: lock_escrow.as_ref(),
+                escrow_vault: escrow_vault.as_ref(),
+            },
+            &[],
+        )?;
+
+        emit_cpi!(EvtLockLiquidity {
+            pool: ctx.accounts.pool.key(),
+            owner: ctx.accounts.user.key(),
+            lock_escrow: lock_escrow.key(),
+            lp_amount: changes.pool_lp_amount,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,236 @@
+use crate::dynamic_amm::accounts::Pool;
+use crate::errors::CpiExampleError;
+use crate::events::{EvtRemoveLiquidity, EvtSwap};
+use crate::token_utils::{load_token_account, LiquidityBalances, SwapBalances};
+use crate::{dynamic_amm, dynamic_vault};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DynamicAmmZapOut<'info> {
+    #[account(
+        mut,
+        has_one = lp_mint,
+        has_one = a_vault,
+        has_one = b_vault,
+        has_one = a_vault_lp,
+        has_one = b_vault_lp,
+    )]
+    /// CHECK: Pool account (PDA)
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(mut)]
+    /// CHECK: LP mint of the pool
+    pub lp_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: User LP token account. The LP is burned from this account.
+    pub user_pool_lp: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: User token A account. Receives the withdrawn token A, which is swapped when token A isn't the output token.
+    pub user_a_token: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: User token B account. Receives the withdrawn token B, which is swapped when token B isn't the output token.
+    pub user_b_token: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Vault account for token a. token a of the pool will be deposit / withdraw from this vault account.
+    pub a_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Vault account for token b. token b of the pool will be deposit / withdraw from this vault account.
+    pub b_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Token vault account of vault A
+    pub a_token_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Token vault account of vault B
+    pub b_token_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Lp token mint of vault a
+    pub a_vault_lp_mint: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Lp token mint of vault b
+    pub b_vault_lp_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: LP token account of vault A. Used to receive/burn the vault LP upon deposit/withdraw from the vault.
+    pub a_vault_lp: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: LP token account of vault B. Used to receive/burn the vault LP upon deposit/withdraw from the vault.
+    pub b_vault_lp: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = protocol_token_fee.key() == pool.protocol_token_a_fee
+            || protocol_token_fee.key() == pool.protocol_token_b_fee
+            @ CpiExampleError::InvalidProtocolTokenFee
+    )]
+    /// CHECK: Protocol fee token account of the pool. Used to receive trading fee. It's mint field must matched with the swapped token.
+    pub protocol_token_fee: UncheckedAccount<'info>,
+
+    /// User account. Must be owner of the user token accounts.
+    pub user: Signer<'info>,
+
+    #[account(address = dynamic_vault::ID)]
+    /// CHECK: Vault program. the pool will deposit/withdraw liquidity from the vault.
+    pub vault_program: UncheckedAccount<'info>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = dynamic_amm::ID)]
+    /// CHECK: Dynamic AMM program account
+    pub dynamic_amm_program: UncheckedAccount<'info>,
+}
+
+impl<'info> DynamicAmmZapOut<'info> {
+    fn remove_balance_liquidity(&self, pool_token_amount: u64) -> Result<()> {
+        let accounts = dynamic_amm::cpi::accounts::RemoveBalanceLiquidity {
+            pool: self.pool.to_account_info(),
+            lp_mint: self.lp_mint.to_account_info(),
+            user_pool_lp: self.user_pool_lp.to_account_info(),
+            a_vault_lp: self.a_vault_lp.to_account_info(),
+            b_vault_lp: self.b_vault_lp.to_account_info(),
+            a_vault: self.a_vault.to_account_info(),
+            b_vault: self.b_vault.to_account_info(),
+            a_vault_lp_mint: self.a_vault_lp_mint.to_account_info(),
+            b_vault_lp_mint: self.b_vault_lp_mint.to_account_info(),
+            a_token_vault: self.a_token_vault.to_account_info(),
+            b_token_vault: self.b_token_vault.to_account_info(),
+            user_a_token: self.user_a_token.to_account_info(),
+            user_b_token: self.user_b_token.to_account_info(),
+            user: self.user.to_account_info(),
+            vault_program: self.vault_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        };
+
+        let cpi_context = CpiContext::new(self.dynamic_amm_program.to_account_info(), accounts);
+
+        // The overall slippage is checked against the output token received
+        dynamic_amm::cpi::remove_balance_liquidity(cpi_context, pool_token_amount, 0, 0)
+    }
+
+    fn swap(&self, a_to_b: bool, in_amount: u64) -> Result<()> {
+        let (user_source_token, user_destination_token) = if a_to_b {
+            (&self.user_a_token, &self.user_b_token)
+        } else {
+            (&self.user_b_token, &self.user_a_token)
+        };
+
+        let accounts = dynamic_amm::cpi::accounts::Swap {
+            pool: self.pool.to_account_info(),
+            user_source_token: user_source_token.to_account_info(),
+            user_destination_token: user_destination_token.to_account_info(),
+            a_vault: self.a_vault.to_account_info(),
+            b_vault: self.b_vault.to_account_info(),
+            a_token_vault: self.a_token_vault.to_account_info(),
+            b_token_vault: self.b_token_vault.to_account_info(),
+            a_vault_lp_mint: self.a_vault_lp_mint.to_account_info(),
+            b_vault_lp_mint: self.b_vault_lp_mint.to_account_info(),
+            a_vault_lp: self.a_vault_lp.to_account_info(),
+            b_vault_lp: self.b_vault_lp.to_account_info(),
+            protocol_token_fee: self.protocol_token_fee.to_account_info(),
+            user: self.user.to_account_info(),
+            vault_program: self.vault_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        };
+
+        let cpi_context = CpiContext::new(self.dynamic_amm_program.to_account_info(), accounts);
+
+        // The overall slippage is checked against the output token received
+        dynamic_amm::cpi::swap(cpi_context, in_amount, 0)
+    }
+}
+
+/// Zaps the liquidity of a Dynamic AMM pool out to a single token. The LP is burned for both tokens of the pool,
+/// then the withdrawn token of the other side is swapped to the output token.
+///
+/// Unlike `remove_liquidity_single_side`, it works with any curve type of the pool.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `pool_token_amount` - The amount of LP to be burned.
+/// * `a_to_b` - Whether the withdrawn token A is swapped to token B. Token B is the output token when true, otherwise token A.
+/// * `min_amount_out` - The minimum amount of output token to be received in total a.k.a slippage
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_zap_out(
+    ctx: Context<DynamicAmmZapOut>,
+    pool_token_amount: u64,
+    a_to_b: bool,
+    min_amount_out: u64,
+) -> Result<()> {
+    let (user_token_in, user_token_out) = if a_to_b {
+        (&ctx.accounts.user_a_token, &ctx.accounts.user_b_token)
+    } else {
+        (&ctx.accounts.user_b_token, &ctx.accounts.user_a_token)
+    };
+
+    let user_token_out_before = load_token_account(user_token_out)?.amount;
+
+    // 1. Withdraw both tokens of the pool
+    let liquidity_balances_before = LiquidityBalances::load(
+        &ctx.accounts.user_pool_lp,
+        &ctx.accounts.user_a_token,
+        &ctx.accounts.user_b_token,
+    )?;
+
+    ctx.accounts.remove_balance_liquidity(pool_token_amount)?;
+
+    let changes = liquidity_balances_before.changes(
+        &ctx.accounts.user_pool_lp,
+        &ctx.accounts.user_a_token,
+        &ctx.accounts.user_b_token,
+    )?;
+
+    emit_cpi!(EvtRemoveLiquidity {
+        pool: ctx.accounts.pool.key(),
+        owner: ctx.accounts.user.key(),
+        lp_amount: changes.pool_lp_amount,
+        token_a_amount: changes.token_a_amount,
+        token_b_amount: changes.token_b_amount,
+    });
+
+    // 2. Swap the withdrawn token of the other side to the output token
+    let swap_amount = if a_to_b {
+        changes.token_a_amount
+    } else {
+        changes.token_b_amount
+    };
+
+    if swap_amount > 0 {
+        let swap_balances_before = SwapBalances::load(user_token_in, user_token_out)?;
+
+        ctx.accounts.swap(a_to_b, swap_amount)?;
+
+        let swap_result = swap_balances_before.swap_result(user_token_in, user_token_out, 0)?;
+
+        emit_cpi!(EvtSwap {
+            pool: ctx.accounts.pool.key(),
+            user: ctx.accounts.user.key(),
+            input_mint: swap_balances_before.input_mint,
+            output_mint: swap_balances_before.output_mint,
+            amount_in: swap_result.amount_in,
+            amount_out: swap_result.amount_out,
+        });
+    }
+
+    // 3. Check the withdrawn and the swapped output token against the slippage
+    let amount_out = load_token_account(user_token_out)?
+        .amount
+        .checked_sub(user_token_out_before)
+        .ok_or(CpiExampleError::MathOverflow)?;
+
+    require!(
+        amount_out >= min_amount_out,
+        CpiExampleError::ExceededSlippage
+    );
+
+    Ok(())
+}
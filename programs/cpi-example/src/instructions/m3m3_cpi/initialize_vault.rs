@@ -1,9 +1,11 @@
 use crate::dynamic_amm;
+use crate::events::EvtInitializeM3m3Vault;
 use crate::m3m3;
 use crate::m3m3::types::InitializeVaultParams;
 use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitializeM3m3Vault<'info> {
     /// CHECK: Pool account (PDA)
@@ -168,5 +170,14 @@ pub fn handle_initialize_m3m3_vault(
     };
 
     let cpi_context = CpiContext::new(ctx.accounts.m3m3_program.to_account_info(), accounts);
-    m3m3::cpi::initialize_vault(cpi_context, vault_params)
+    m3m3::cpi::initialize_vault(cpi_context, vault_params)?;
+
+    emit_cpi!(EvtInitializeM3m3Vault {
+        m3m3_vault: ctx.accounts.m3m3_vault.key(),
+        pool: ctx.accounts.pool.key(),
+        lock_escrow: ctx.accounts.lock_escrow.key(),
+        lp_amount: max_amount,
+    });
+
+    Ok(())
 }
@@ -1,3 +1,6 @@
+pub mod admin;
+pub use admin::*;
+
 pub mod dlmm_cpi;
 pub use dlmm_cpi::*;
 
@@ -6,3 +9,7 @@ pub use dynamic_amm_cpi::*;
 
 pub mod m3m3_cpi;
 pub use m3m3_cpi::*;
+
+pub mod creator_authority;
+
+pub mod token_utils;
@@ -0,0 +1,171 @@
+use crate::errors::CpiExampleError;
+use crate::state::{IntegratorFee, IntegratorFeeMode, SwapResult};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+/// Maximum integrator fee of a swap. 10%.
+pub const MAX_INTEGRATOR_FEE_BPS: u16 = 1_000;
+
+const BASIS_POINT_MAX: u64 = 10_000;
+
+/// Load the token account of the token program or the token 2022 program
+pub fn load_token_account(account: &AccountInfo) -> Result<TokenAccount> {
+    let data = account.try_borrow_data()?;
+    TokenAccount::try_deserialize(&mut data.as_ref())
+}
+
+/// Load the mint of the token program or the token 2022 program
+pub fn load_mint(account: &AccountInfo) -> Result<Mint> {
+    let data = account.try_borrow_data()?;
+    Mint::try_deserialize(&mut data.as_ref())
+}
+
+/// Balances of the user token accounts of a swap, recorded before the CPI
+pub struct SwapBalances {
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub user_token_in_amount: u64,
+    pub user_token_out_amount: u64,
+}
+
+impl SwapBalances {
+    pub fn load(user_token_in: &AccountInfo, user_token_out: &AccountInfo) -> Result<Self> {
+        let user_token_in = load_token_account(user_token_in)?;
+        let user_token_out = load_token_account(user_token_out)?;
+
+        Ok(Self {
+            input_mint: user_token_in.mint,
+            output_mint: user_token_out.mint,
+            user_token_in_amount: user_token_in.amount,
+            user_token_out_amount: user_token_out.amount,
+        })
+    }
+
+    /// Actual amounts of the swap, from the balance changes of the user token accounts since the balances were recorded.
+    /// Fails when the user received less than `min_amount_out`.
+    pub fn swap_result(
+        &self,
+        user_token_in: &AccountInfo,
+        user_token_out: &AccountInfo,
+        min_amount_out: u64,
+    ) -> Result<SwapResult> {
+        let user_token_in_amount = load_token_account(user_token_in)?.amount;
+        let user_token_out_amount = load_token_account(user_token_out)?.amount;
+
+        let amount_in = self
+            .user_token_in_amount
+            .checked_sub(user_token_in_amount)
+            .ok_or(CpiExampleError::MathOverflow)?;
+        let amount_out = user_token_out_amount
+            .checked_sub(self.user_token_out_amount)
+            .ok_or(CpiExampleError::MathOverflow)?;
+
+        require!(
+            amount_out >= min_amount_out,
+            CpiExampleError::ExceededSlippage
+        );
+
+        Ok(SwapResult {
+            amount_in,
+            amount_out,
+        })
+    }
+}
+
+/// Balances of the LP and token accounts of a liquidity owner, recorded before the CPI
+pub struct LiquidityBalances {
+    pub pool_lp_amount: u64,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+}
+
+impl LiquidityBalances {
+    pub fn load(
+        pool_lp: &AccountInfo,
+        token_a: &AccountInfo,
+        token_b: &AccountInfo,
+    ) -> Result<Self> {
+        Ok(Self {
+            pool_lp_amount: load_token_account(pool_lp)?.amount,
+            token_a_amount: load_token_account(token_a)?.amount,
+            token_b_amount: load_token_account(token_b)?.amount,
+        })
+    }
+
+    /// Amounts of LP and tokens moved since the balances were recorded
+    pub fn changes(
+        &self,
+        pool_lp: &AccountInfo,
+        token_a: &AccountInfo,
+        token_b: &AccountInfo,
+    ) -> Result<Self> {
+        let balances = Self::load(pool_lp, token_a, token_b)?;
+
+        Ok(Self {
+            pool_lp_amount: balances.pool_lp_amount.abs_diff(self.pool_lp_amount),
+            token_a_amount: balances.token_a_amount.abs_diff(self.token_a_amount),
+            token_b_amount: balances.token_b_amount.abs_diff(self.token_b_amount),
+        })
+    }
+}
+
+/// Integrator fee charged on a swap, validated against the token account receiving it
+pub struct IntegratorFeeCharge<'info> {
+    pub fee: IntegratorFee,
+    /// Associated token account of the fee recipient for the charged mint
+    pub fee_token: AccountInfo<'info>,
+}
+
+impl<'info> IntegratorFeeCharge<'info> {
+    /// Validate the integrator fee of a swap. Returns `None` when no fee is charged.
+    pub fn new(
+        integrator_fee: Option<IntegratorFee>,
+        fee_token: Option<&AccountInfo<'info>>,
+        balances: &SwapBalances,
+    ) -> Result<Option<Self>> {
+        let Some(fee) = integrator_fee else {
+            return Ok(None);
+        };
+
+        require!(
+            fee.fee_bps <= MAX_INTEGRATOR_FEE_BPS,
+            CpiExampleError::InvalidIntegratorFee
+        );
+
+        let fee_token = fee_token.ok_or(CpiExampleError::MissingIntegratorFeeToken)?;
+        let fee_token_state = load_token_account(fee_token)?;
+
+        let charged_mint = match fee.mode {
+            IntegratorFeeMode::Input => balances.input_mint,
+            IntegratorFeeMode::Output => balances.output_mint,
+        };
+        let fee_token_key = get_associated_token_address_with_program_id(
+            &fee_token_state.owner,
+            &charged_mint,
+            fee_token.owner,
+        );
+
+        require!(
+            fee_token_state.mint == charged_mint && fee_token.key() == fee_token_key,
+            CpiExampleError::InvalidIntegratorFeeToken
+        );
+
+        Ok(Some(Self {
+            fee,
+            fee_token: fee_token.clone(),
+        }))
+    }
+
+    /// Fee of `amount`, rounded down in favor of the user
+    pub fn fee_amount(&self, amount: u64) -> Result<u64> {
+        let fee_amount =
+            u128::from(amount) * u128::from(self.fee.fee_bps) / u128::from(BASIS_POINT_MAX);
+        u64::try_from(fee_amount).map_err(|_| CpiExampleError::MathOverflow.into())
+    }
+
+    /// Whether the fee is taken from the input token before the swap
+    pub fn is_input_fee(&self) -> bool {
+        self.fee.mode == IntegratorFeeMode::Input
+    }
+}
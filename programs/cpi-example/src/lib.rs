@@ -2,7 +2,10 @@
 #![allow(unexpected_cfgs)]
 use anchor_lang::prelude::*;
 
+pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod state;
 pub use instructions::*;
 
 declare_program!(dlmm);
@@ -10,12 +13,17 @@ declare_program!(dynamic_amm);
 declare_program!(dynamic_vault);
 declare_program!(m3m3);
 
+use crate::dlmm_add_liquidity::*;
+use crate::dlmm_add_liquidity_one_side::*;
+use crate::dlmm_claim_fee::*;
+use crate::dlmm_claim_reward::*;
+use crate::dlmm_close_position::*;
+use crate::dlmm_initialize_lb_pair::*;
+use crate::dlmm_initialize_position::*;
+use crate::dlmm_remove_liquidity::*;
 use crate::dlmm_swap::*;
 use crate::dynamic_amm_swap::*;
-
-fn assert_eq_admin(_key: Pubkey) -> bool {
-    true
-}
+use crate::state::{IntegratorFee, RemoveLiquidityResult, SwapResult};
 
 declare_id!("4JTNRRQpgLusbEhGnzTuE9kgPgMLXQX1wqBzU52GduqH");
 
@@ -23,12 +31,205 @@ declare_id!("4JTNRRQpgLusbEhGnzTuE9kgPgMLXQX1wqBzU52GduqH");
 pub mod cpi_example {
     use super::*;
 
+    // NOTE: Only the upgrade authority of the program can initialize the config
+    pub fn initialize_config(ctx: Context<InitializeConfig>, admin: Pubkey) -> Result<()> {
+        instructions::admin::initialize_config::handle_initialize_config(ctx, admin)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::admin::propose_admin::handle_propose_admin(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::admin::accept_admin::handle_accept_admin(ctx)
+    }
+
+    // NOTE: Only the admin can sweep, with the approval of the owner of the creator authority PDA
+    pub fn sweep_creator_authority(
+        ctx: Context<SweepCreatorAuthority>,
+        close_creator_token: bool,
+    ) -> Result<()> {
+        instructions::admin::sweep_creator_authority::handle_sweep_creator_authority(
+            ctx,
+            close_creator_token,
+        )
+    }
+
+    // NOTE: The optional integrator fee is transferred to the integrator fee token account
     pub fn dlmm_swap<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, DlmmSwap<'info>>,
         amount_in: u64,
         min_amount_out: u64,
+        integrator_fee: Option<IntegratorFee>,
+    ) -> Result<SwapResult> {
+        instructions::dlmm_cpi::dlmm_swap::handle_dlmm_swap(
+            ctx,
+            amount_in,
+            min_amount_out,
+            integrator_fee,
+        )
+    }
+
+    pub fn dlmm_swap_exact_out<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DlmmSwap<'info>>,
+        max_in_amount: u64,
+        out_amount: u64,
+    ) -> Result<()> {
+        instructions::dlmm_cpi::dlmm_swap_exact_out::handle_dlmm_swap_exact_out(
+            ctx,
+            max_in_amount,
+            out_amount,
+        )
+    }
+
+    pub fn dlmm_swap_with_price_impact<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DlmmSwap<'info>>,
+        amount_in: u64,
+        active_id: Option<i32>,
+        max_price_impact_bps: u16,
+    ) -> Result<()> {
+        instructions::dlmm_cpi::dlmm_swap_with_price_impact::handle_dlmm_swap_with_price_impact(
+            ctx,
+            amount_in,
+            active_id,
+            max_price_impact_bps,
+        )
+    }
+
+    // NOTE: Creator authority PDA will be the creator of the pool
+    pub fn dlmm_initialize_customizable_permissionless_lb_pair_pda_creator(
+        ctx: Context<DlmmInitializeCustomizablePermissionlessLbPairPdaCreator>,
+        params: dlmm::types::CustomizableParams,
+    ) -> Result<()> {
+        instructions::dlmm_cpi::dlmm_initialize_lb_pair::handle_dlmm_initialize_customizable_permissionless_lb_pair_pda_creator(
+            ctx,
+            params,
+        )
+    }
+
+    // NOTE: Creator authority PDA will be the owner of the position
+    pub fn dlmm_initialize_position_pda_creator(
+        ctx: Context<DlmmInitializePositionPdaCreator>,
+        lower_bin_id: i32,
+        width: i32,
+    ) -> Result<()> {
+        instructions::dlmm_cpi::dlmm_initialize_position::handle_dlmm_initialize_position_pda_creator(
+            ctx,
+            lower_bin_id,
+            width,
+        )
+    }
+
+    pub fn dlmm_add_liquidity_by_strategy_pda_creator(
+        ctx: Context<DlmmAddLiquidityByStrategyPdaCreator>,
+        lower_bin_id: i32,
+        width: i32,
+        liquidity_parameter: dlmm::types::LiquidityParameterByStrategy,
+    ) -> Result<()> {
+        instructions::dlmm_cpi::dlmm_add_liquidity::handle_dlmm_add_liquidity_by_strategy_pda_creator(
+            ctx,
+            lower_bin_id,
+            width,
+            liquidity_parameter,
+        )
+    }
+
+    pub fn dlmm_add_liquidity_by_strategy_one_side_pda_creator(
+        ctx: Context<DlmmAddLiquidityOneSidePdaCreator>,
+        lower_bin_id: i32,
+        width: i32,
+        liquidity_parameter: dlmm::types::LiquidityParameterByStrategyOneSide,
+    ) -> Result<()> {
+        instructions::dlmm_cpi::dlmm_add_liquidity_one_side::handle_dlmm_add_liquidity_by_strategy_one_side_pda_creator(
+            ctx,
+            lower_bin_id,
+            width,
+            liquidity_parameter,
+        )
+    }
+
+    pub fn dlmm_add_liquidity_one_side_precise_pda_creator(
+        ctx: Context<DlmmAddLiquidityOneSidePdaCreator>,
+        lower_bin_id: i32,
+        width: i32,
+        parameter: dlmm::types::AddLiquiditySingleSidePreciseParameter,
+    ) -> Result<()> {
+        instructions::dlmm_cpi::dlmm_add_liquidity_one_side::handle_dlmm_add_liquidity_one_side_precise_pda_creator(
+            ctx,
+            lower_bin_id,
+            width,
+            parameter,
+        )
+    }
+
+    pub fn dlmm_remove_liquidity_by_range_pda_creator(
+        ctx: Context<DlmmRemoveLiquidityPdaCreator>,
+        lower_bin_id: i32,
+        width: i32,
+        from_bin_id: i32,
+        to_bin_id: i32,
+        bps_to_remove: u16,
+    ) -> Result<RemoveLiquidityResult> {
+        instructions::dlmm_cpi::dlmm_remove_liquidity::handle_dlmm_remove_liquidity_by_range_pda_creator(
+            ctx,
+            lower_bin_id,
+            width,
+            from_bin_id,
+            to_bin_id,
+            bps_to_remove,
+        )
+    }
+
+    pub fn dlmm_remove_all_liquidity_pda_creator(
+        ctx: Context<DlmmRemoveLiquidityPdaCreator>,
+        lower_bin_id: i32,
+        width: i32,
+    ) -> Result<RemoveLiquidityResult> {
+        instructions::dlmm_cpi::dlmm_remove_liquidity::handle_dlmm_remove_all_liquidity_pda_creator(
+            ctx,
+            lower_bin_id,
+            width,
+        )
+    }
+
+    pub fn dlmm_close_position_pda_creator(
+        ctx: Context<DlmmClosePositionPdaCreator>,
+        lower_bin_id: i32,
+        width: i32,
     ) -> Result<()> {
-        instructions::dlmm_cpi::dlmm_swap::handle_dlmm_swap(ctx, amount_in, min_amount_out)
+        instructions::dlmm_cpi::dlmm_close_position::handle_dlmm_close_position_pda_creator(
+            ctx,
+            lower_bin_id,
+            width,
+        )
+    }
+
+    // NOTE: Claimed fee will be hold by creator authority PDA
+    pub fn dlmm_claim_fee(
+        ctx: Context<DlmmClaimFeePdaCreator>,
+        lower_bin_id: i32,
+        width: i32,
+    ) -> Result<()> {
+        instructions::dlmm_cpi::dlmm_claim_fee::handle_dlmm_claim_fee_pda_creator(
+            ctx,
+            lower_bin_id,
+            width,
+        )
+    }
+
+    // NOTE: Claimed reward will be hold by creator authority PDA
+    pub fn dlmm_claim_reward(
+        ctx: Context<DlmmClaimRewardPdaCreator>,
+        lower_bin_id: i32,
+        width: i32,
+        reward_index: u64,
+    ) -> Result<()> {
+        instructions::dlmm_cpi::dlmm_claim_reward::handle_dlmm_claim_reward_pda_creator(
+            ctx,
+            lower_bin_id,
+            width,
+            reward_index,
+        )
     }
 
     pub fn initialize_dynamic_amm_customizable_permissionless_pool(
@@ -98,29 +299,46 @@ pub mod cpi_example {
         )
     }
 
+    // NOTE: The optional integrator fee is transferred to the integrator fee token account
     pub fn dynamic_amm_swap(
         ctx: Context<DynamicAmmSwap>,
         amount_in: u64,
         min_amount_out: u64,
-    ) -> Result<()> {
+        integrator_fee: Option<IntegratorFee>,
+    ) -> Result<SwapResult> {
         instructions::dynamic_amm_cpi::dynamic_amm_swap::handle_dynamic_amm_swap(
             ctx,
             amount_in,
             min_amount_out,
+            integrator_fee,
+        )
+    }
+
+    // NOTE: Same as dynamic_amm_swap without the integrator fee, but the accounts are validated against the pool state before the CPI
+    pub fn dynamic_amm_swap_strict(
+        ctx: Context<DynamicAmmSwapStrict>,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<SwapResult> {
+        instructions::dynamic_amm_cpi::dynamic_amm_swap::handle_dynamic_amm_swap_strict(
+            ctx,
+            amount_in,
+            min_amount_out,
         )
     }
 
-    pub fn dynamic_amm_lock_liquidity(
-        ctx: Context<DynamicAmmLockLiquidity>,
-        allocations: [u16; 2],
+    // NOTE: Owner, lock escrow and escrow vault of each recipient are passed as remaining accounts
+    pub fn dynamic_amm_lock_liquidity<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DynamicAmmLockLiquidity<'info>>,
+        allocations: Vec<u16>,
     ) -> Result<()> {
         instructions::dynamic_amm_cpi::lock_liquidity::handle_lock_liquidity(ctx, allocations)
     }
 
-    // NOTE: Creator authority PDA lock LP token hold to self + other user
-    pub fn dynamic_amm_lock_liquidity_pda_creator(
-        ctx: Context<DynamicAmmLockLiquidityPdaCreator>,
-        allocations: [u16; 2],
+    // NOTE: Creator authority PDA lock LP token hold to the recipients, which can include self
+    pub fn dynamic_amm_lock_liquidity_pda_creator<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DynamicAmmLockLiquidityPdaCreator<'info>>,
+        allocations: Vec<u16>,
     ) -> Result<()> {
         instructions::dynamic_amm_cpi::lock_liquidity::handle_lock_liquidity_pda_creator(
             ctx,
@@ -138,4 +356,119 @@ pub mod cpi_example {
     ) -> Result<()> {
         instructions::dynamic_amm_cpi::claim_fee::handle_claim_fee_pda_creator(ctx)
     }
+
+    pub fn dynamic_amm_add_balance_liquidity(
+        ctx: Context<DynamicAmmBalanceLiquidity>,
+        pool_token_amount: u64,
+        maximum_token_a_amount: u64,
+        maximum_token_b_amount: u64,
+    ) -> Result<()> {
+        instructions::dynamic_amm_cpi::balance_liquidity::handle_add_balance_liquidity(
+            ctx,
+            pool_token_amount,
+            maximum_token_a_amount,
+            maximum_token_b_amount,
+        )
+    }
+
+    pub fn dynamic_amm_remove_balance_liquidity(
+        ctx: Context<DynamicAmmBalanceLiquidity>,
+        pool_token_amount: u64,
+        minimum_a_token_out: u64,
+        minimum_b_token_out: u64,
+    ) -> Result<()> {
+        instructions::dynamic_amm_cpi::balance_liquidity::handle_remove_balance_liquidity(
+            ctx,
+            pool_token_amount,
+            minimum_a_token_out,
+            minimum_b_token_out,
+        )
+    }
+
+    // NOTE: Tokens are deposited from the creator authority PDA, which holds the minted LP
+    pub fn dynamic_amm_add_balance_liquidity_pda_creator(
+        ctx: Context<DynamicAmmBalanceLiquidityPdaCreator>,
+        pool_token_amount: u64,
+        maximum_token_a_amount: u64,
+        maximum_token_b_amount: u64,
+    ) -> Result<()> {
+        instructions::dynamic_amm_cpi::balance_liquidity::handle_add_balance_liquidity_pda_creator(
+            ctx,
+            pool_token_amount,
+            maximum_token_a_amount,
+            maximum_token_b_amount,
+        )
+    }
+
+    // NOTE: LP of the creator authority PDA is burned, and the withdrawn tokens are held by the creator authority PDA
+    pub fn dynamic_amm_remove_balance_liquidity_pda_creator(
+        ctx: Context<DynamicAmmBalanceLiquidityPdaCreator>,
+        pool_token_amount: u64,
+        minimum_a_token_out: u64,
+        minimum_b_token_out: u64,
+    ) -> Result<()> {
+        instructions::dynamic_amm_cpi::balance_liquidity::handle_remove_balance_liquidity_pda_creator(
+            ctx,
+            pool_token_amount,
+            minimum_a_token_out,
+            minimum_b_token_out,
+        )
+    }
+
+    pub fn dynamic_amm_add_imbalance_liquidity(
+        ctx: Context<DynamicAmmAddImbalanceLiquidity>,
+        minimum_pool_token_amount: u64,
+        token_a_amount: u64,
+        token_b_amount: u64,
+    ) -> Result<()> {
+        instructions::dynamic_amm_cpi::imbalance_liquidity::handle_add_imbalance_liquidity(
+            ctx,
+            minimum_pool_token_amount,
+            token_a_amount,
+            token_b_amount,
+        )
+    }
+
+    // NOTE: The mint of user_destination_token decides the token to be withdrawn
+    pub fn dynamic_amm_remove_liquidity_single_side(
+        ctx: Context<DynamicAmmRemoveLiquiditySingleSide>,
+        pool_token_amount: u64,
+        minimum_out_amount: u64,
+    ) -> Result<()> {
+        instructions::dynamic_amm_cpi::imbalance_liquidity::handle_remove_liquidity_single_side(
+            ctx,
+            pool_token_amount,
+            minimum_out_amount,
+        )
+    }
+
+    // NOTE: The minted LP is locked to the lock escrow of the user when the lock escrow and the escrow vault are provided
+    pub fn dynamic_amm_zap_in(
+        ctx: Context<DynamicAmmZapIn>,
+        in_amount: u64,
+        a_to_b: bool,
+        minimum_pool_token_amount: u64,
+    ) -> Result<()> {
+        instructions::dynamic_amm_cpi::zap_in::handle_zap_in(
+            ctx,
+            in_amount,
+            a_to_b,
+            minimum_pool_token_amount,
+        )
+    }
+
+    // NOTE: The slippage is checked against the total output token received from the withdrawal and the swap
+    pub fn dynamic_amm_zap_out(
+        ctx: Context<DynamicAmmZapOut>,
+        pool_token_amount: u64,
+        a_to_b: bool,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        instructions::dynamic_amm_cpi::zap_out::handle_zap_out(
+            ctx,
+            pool_token_amount,
+            a_to_b,
+            min_amount_out,
+        )
+    }
 }
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+/// Global configuration of the CPI example program. PDA.
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramConfig {
+    /// Admin allowed to call the creator authority PDA instructions
+    pub admin: Pubkey,
+    /// Admin proposed by the current admin. Default pubkey when there is no pending transfer.
+    pub pending_admin: Pubkey,
+    /// Bump of the program config PDA
+    pub bump: u8,
+}
+
+/// Actual amounts of a swap, measured from the balance changes of the user token accounts.
+/// Returned to the caller through the return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapResult {
+    /// Amount debited from the user token in account
+    pub amount_in: u64,
+    /// Amount credited to the user token out account
+    pub amount_out: u64,
+}
+
+/// Amounts withdrawn from a DLMM position, measured from the balance changes of the receiver token accounts.
+/// Returned to the caller through the return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RemoveLiquidityResult {
+    /// Amount credited to the receiver token X account
+    pub amount_x: u64,
+    /// Amount credited to the receiver token Y account
+    pub amount_y: u64,
+}
+
+/// Side of the swap charged by the integrator fee
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntegratorFeeMode {
+    /// Fee is taken from the input token before the swap
+    Input,
+    /// Fee is taken from the output token after the swap.
+    /// On DLMM swaps, the referral share of the protocol fee is paid in the input token, so the integrator only earns it when `host_fee_in` is passed.
+    Output,
+}
+
+/// Fee charged by the integrator on a swap routed through the CPI example program
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IntegratorFee {
+    /// Fee rate in bps. Capped by `MAX_INTEGRATOR_FEE_BPS`.
+    pub fee_bps: u16,
+    /// Side of the swap charged by the fee
+    pub mode: IntegratorFeeMode,
+}
@@ -0,0 +1,264 @@
+use crate::helpers;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use cpi_example::dlmm::accounts::{BinArray, LbPair};
+use cpi_example::dlmm::types::{
+    AddLiquiditySingleSidePreciseParameter, CompressedBinDepositAmount,
+    LiquidityParameterByStrategyOneSide, StrategyParameters, StrategyType,
+};
+use cpi_example::errors::CpiExampleError;
+use cpi_example_client::dlmm_pda::*;
+use cpi_example_client::instructions::*;
+use helpers::dlmm_launch_pool_utils::*;
+use helpers::{process_and_assert_err, process_and_assert_ok};
+use solana_program_test::*;
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, signer::Signer};
+
+const ACTIVE_ID: i32 = 0;
+const POSITION_WIDTH: i32 = 70;
+
+/// Bin arrays covering the bid and ask positions
+const BIN_ARRAY_INDEXES: [i64; 4] = [-2, -1, 0, 1];
+
+/// Returns (bin id, amount x, amount y) of all bins with liquidity in the bin arrays
+async fn get_deposited_bins(
+    banks_client: &mut BanksClient,
+    lb_pair: Pubkey,
+    bin_array_indexes: &[i64],
+) -> Vec<(i32, u64, u64)> {
+    let mut deposited_bins = vec![];
+
+    for index in bin_array_indexes {
+        let (bin_array_key, _bump) = derive_bin_array_pda(lb_pair, *index);
+        let bin_array_account = banks_client
+            .get_account(bin_array_key)
+            .await
+            .unwrap()
+            .unwrap();
+        let bin_array: BinArray = bytemuck::pod_read_unaligned(
+            &bin_array_account.data[8..8 + std::mem::size_of::<BinArray>()],
+        );
+
+        let lower_bin_id = bin_array.index as i32 * POSITION_WIDTH;
+        for (i, bin) in bin_array.bins.iter().enumerate() {
+            if bin.amount_x > 0 || bin.amount_y > 0 {
+                deposited_bins.push((lower_bin_id + i as i32, bin.amount_x, bin.amount_y));
+            }
+        }
+    }
+
+    deposited_bins
+}
+
+#[tokio::test]
+async fn test_dlmm_add_liquidity_one_side_pda_creator() {
+    let (mut banks_client, mock_user, launch_pool_context) =
+        setup_launch_pool(ACTIVE_ID, &BIN_ARRAY_INDEXES).await;
+    let pool_keys = &launch_pool_context.pool_keys;
+
+    // Token X is deposited above the active bin by strategy
+    let ask_lower_bin_id = ACTIVE_ID + 1;
+    let ask_upper_bin_id = ask_lower_bin_id + POSITION_WIDTH - 1;
+    let ask_position_keys = DlmmPositionKeys::derive_pda_creator(
+        pool_keys.lb_pair,
+        mock_user.pubkey(),
+        ask_lower_bin_id,
+        POSITION_WIDTH,
+    );
+
+    let liquidity_parameter = LiquidityParameterByStrategyOneSide {
+        amount: 1_000_000_000,
+        active_id: ACTIVE_ID,
+        max_active_bin_slippage: 0,
+        strategy_parameters: StrategyParameters {
+            min_bin_id: ask_lower_bin_id,
+            max_bin_id: ask_upper_bin_id,
+            strategy_type: StrategyType::CurveOneSide,
+            parameteres: [0u8; 64],
+        },
+    };
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dlmm_initialize_position_pda_creator(&ask_position_keys, mock_user.pubkey()),
+            dlmm_add_liquidity_by_strategy_one_side_pda_creator(
+                pool_keys,
+                &ask_position_keys,
+                mock_user.pubkey(),
+                pool_keys.token_x_mint,
+                liquidity_parameter,
+            ),
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    // Token Y is deposited below the active bin with precise amount per bin
+    let bid_lower_bin_id = ACTIVE_ID - POSITION_WIDTH - 1;
+    let bid_upper_bin_id = bid_lower_bin_id + POSITION_WIDTH - 1;
+    let bid_position_keys = DlmmPositionKeys::derive_pda_creator(
+        pool_keys.lb_pair,
+        mock_user.pubkey(),
+        bid_lower_bin_id,
+        POSITION_WIDTH,
+    );
+
+    let parameter = AddLiquiditySingleSidePreciseParameter {
+        bins: (bid_lower_bin_id..=bid_upper_bin_id)
+            .map(|bin_id| CompressedBinDepositAmount {
+                bin_id,
+                amount: 1_000,
+            })
+            .collect(),
+        decompress_multiplier: 1_000,
+    };
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dlmm_initialize_position_pda_creator(&bid_position_keys, mock_user.pubkey()),
+            dlmm_add_liquidity_one_side_precise_pda_creator(
+                pool_keys,
+                &bid_position_keys,
+                mock_user.pubkey(),
+                pool_keys.token_y_mint,
+                parameter,
+            ),
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let lb_pair_account = banks_client
+        .get_account(pool_keys.lb_pair)
+        .await
+        .unwrap()
+        .unwrap();
+    let lb_pair_state: LbPair =
+        bytemuck::pod_read_unaligned(&lb_pair_account.data[8..8 + std::mem::size_of::<LbPair>()]);
+    assert_eq!(lb_pair_state.active_id, ACTIVE_ID);
+
+    let deposited_bins =
+        get_deposited_bins(&mut banks_client, pool_keys.lb_pair, &BIN_ARRAY_INDEXES).await;
+
+    let ask_bins = deposited_bins
+        .iter()
+        .filter(|(_, amount_x, _)| *amount_x > 0)
+        .count();
+    let bid_bins = deposited_bins
+        .iter()
+        .filter(|(_, _, amount_y)| *amount_y > 0)
+        .count();
+    assert!(ask_bins > 0);
+    assert_eq!(bid_bins, POSITION_WIDTH as usize);
+
+    for (bin_id, amount_x, amount_y) in deposited_bins {
+        if amount_x > 0 {
+            assert!(bin_id > ACTIVE_ID && bin_id <= ask_upper_bin_id);
+            assert_eq!(amount_y, 0);
+        }
+        if amount_y > 0 {
+            assert!(bin_id < ACTIVE_ID && bin_id >= bid_lower_bin_id);
+            assert_eq!(amount_x, 0);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_dlmm_add_liquidity_one_side_pda_creator_invalid_bin_range() {
+    let (mut banks_client, mock_user, launch_pool_context) =
+        setup_launch_pool(ACTIVE_ID, &BIN_ARRAY_INDEXES).await;
+
+    let pool_keys = &launch_pool_context.pool_keys;
+
+    let lower_bin_id = ACTIVE_ID + 1;
+    let position_keys = DlmmPositionKeys::derive_pda_creator(
+        pool_keys.lb_pair,
+        mock_user.pubkey(),
+        lower_bin_id,
+        POSITION_WIDTH,
+    );
+
+    process_and_assert_ok(
+        &[dlmm_initialize_position_pda_creator(
+            &position_keys,
+            mock_user.pubkey(),
+        )],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    // Strategy range exceeds the upper bin of the position
+    let liquidity_parameter = LiquidityParameterByStrategyOneSide {
+        amount: 1_000_000_000,
+        active_id: ACTIVE_ID,
+        max_active_bin_slippage: 0,
+        strategy_parameters: StrategyParameters {
+            min_bin_id: lower_bin_id,
+            max_bin_id: lower_bin_id + POSITION_WIDTH,
+            strategy_type: StrategyType::SpotOneSide,
+            parameteres: [0u8; 64],
+        },
+    };
+
+    process_and_assert_err(
+        &[dlmm_add_liquidity_by_strategy_one_side_pda_creator(
+            pool_keys,
+            &position_keys,
+            mock_user.pubkey(),
+            pool_keys.token_x_mint,
+            liquidity_parameter,
+        )],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        CpiExampleError::InvalidBinRange,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_dlmm_add_liquidity_one_side_precise_pda_creator_overflow() {
+    let (mut banks_client, mock_user, launch_pool_context) =
+        setup_launch_pool(ACTIVE_ID, &BIN_ARRAY_INDEXES).await;
+
+    let pool_keys = &launch_pool_context.pool_keys;
+
+    let lower_bin_id = ACTIVE_ID - POSITION_WIDTH - 1;
+    let position_keys = DlmmPositionKeys::derive_pda_creator(
+        pool_keys.lb_pair,
+        mock_user.pubkey(),
+        lower_bin_id,
+        POSITION_WIDTH,
+    );
+
+    // Total deposit amount of the bins exceeds u64
+    let parameter = AddLiquiditySingleSidePreciseParameter {
+        bins: vec![CompressedBinDepositAmount {
+            bin_id: lower_bin_id,
+            amount: 2,
+        }],
+        decompress_multiplier: u64::MAX,
+    };
+
+    process_and_assert_err(
+        &[dlmm_add_liquidity_one_side_precise_pda_creator(
+            pool_keys,
+            &position_keys,
+            mock_user.pubkey(),
+            pool_keys.token_y_mint,
+            parameter,
+        )],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        CpiExampleError::MathOverflow,
+    )
+    .await;
+}
@@ -0,0 +1,348 @@
+use crate::helpers;
+use anchor_lang::prelude::Clock;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::{solana_program::pubkey::Pubkey, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token::state::{Account as TokenAccountState, AccountState, Mint};
+use cpi_example::dlmm;
+use cpi_example::dlmm::accounts::LbPair;
+use cpi_example::errors::CpiExampleError;
+use cpi_example_client::dlmm_pda::*;
+use cpi_example_client::instructions::*;
+use helpers::dlmm_launch_pool_utils::setup_launch_pool;
+use helpers::dlmm_position_utils::*;
+use helpers::dlmm_utils::*;
+use helpers::{
+    add_packable_account, add_program_config, get_token_balance, process_and_assert_err,
+    process_and_assert_ok, setup_cpi_example_program,
+};
+use solana_program_test::*;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction,
+    instruction::{AccountMeta, Instruction},
+    signature::Keypair,
+    signer::Signer,
+};
+
+const USDC_USDT_POOL: Pubkey = solana_sdk::pubkey!("ARwi1S4DaiTG5DX7S4M4ZsrXqpMD1MrTmbu9ue2tpmEq");
+
+const DEPOSIT_AMOUNT: u64 = 100_000_000_000;
+
+async fn setup(
+    reward_mint: Pubkey,
+) -> (
+    ProgramTestContext,
+    Keypair,
+    PoolSetupContext,
+    DlmmPoolKeys,
+    DlmmPositionKeys,
+) {
+    let mock_user = Keypair::new();
+
+    let mut test = setup_cpi_example_program();
+    add_program_config(&mut test, mock_user.pubkey());
+
+    test.prefer_bpf(true);
+    test.add_program("dlmm", dlmm::ID, None);
+
+    let pool_setup_context =
+        setup_pool_from_cluster(&mut test, USDC_USDT_POOL, mock_user.pubkey()).await;
+
+    let pool_keys = DlmmPoolKeys::new(USDC_USDT_POOL, &pool_setup_context.pool_state);
+    let position_keys = derive_position_keys(
+        USDC_USDT_POOL,
+        mock_user.pubkey(),
+        pool_setup_context.pool_state.active_id,
+    );
+
+    setup_position_bin_arrays_from_cluster(
+        &mut test,
+        &position_keys,
+        pool_setup_context.pool_state.active_id,
+    )
+    .await;
+
+    let mint_state = Mint {
+        decimals: 6,
+        supply: u64::MAX,
+        is_initialized: true,
+        ..Default::default()
+    };
+    add_packable_account(&mut test, mint_state, anchor_spl::token::ID, reward_mint);
+
+    let mut context = test.start_with_context().await;
+
+    initialize_position_and_add_liquidity(
+        &mut context.banks_client,
+        &mock_user,
+        &pool_keys,
+        &position_keys,
+        pool_setup_context.pool_state.active_id,
+        DEPOSIT_AMOUNT,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    (
+        context,
+        mock_user,
+        pool_setup_context,
+        pool_keys,
+        position_keys,
+    )
+}
+
+async fn generate_swap_fees(
+    banks_client: &mut BanksClient,
+    pool_setup_context: &PoolSetupContext,
+    user: &Keypair,
+) {
+    let pool_state = &pool_setup_context.pool_state;
+
+    let (active_bin_array_key, _bump) = derive_bin_array_pda(
+        USDC_USDT_POOL,
+        bin_id_to_bin_array_index(pool_state.active_id)
+            .unwrap()
+            .into(),
+    );
+
+    for (user_token_in, user_token_out) in [
+        (
+            pool_setup_context.user_token_x,
+            pool_setup_context.user_token_y,
+        ),
+        (
+            pool_setup_context.user_token_y,
+            pool_setup_context.user_token_x,
+        ),
+    ] {
+        let mut accounts = dlmm::client::accounts::Swap {
+            lb_pair: USDC_USDT_POOL,
+            bin_array_bitmap_extension: None,
+            reserve_x: pool_state.reserve_x,
+            reserve_y: pool_state.reserve_y,
+            user_token_in,
+            user_token_out,
+            token_x_mint: pool_state.token_x_mint,
+            token_y_mint: pool_state.token_y_mint,
+            oracle: pool_state.oracle,
+            host_fee_in: None,
+            user: user.pubkey(),
+            token_x_program: anchor_spl::token::ID,
+            token_y_program: anchor_spl::token::ID,
+            event_authority: derive_event_authority_pda().0,
+            program: dlmm::ID,
+        }
+        .to_account_metas(None);
+
+        accounts.push(AccountMeta::new(active_bin_array_key, false));
+
+        let ix_data = dlmm::client::args::Swap {
+            amount_in: 100_000_000,
+            min_amount_out: 0,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: dlmm::ID,
+            accounts,
+            data: ix_data,
+        };
+
+        process_and_assert_ok(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                instruction,
+            ],
+            user,
+            &[user],
+            banks_client,
+        )
+        .await;
+    }
+}
+
+/// Start a farming reward of the pool. Only DLMM admin can initialize reward, therefore the pool state is overridden.
+async fn setup_farm_reward(
+    context: &mut ProgramTestContext,
+    reward_index: usize,
+    reward_mint: Pubkey,
+    reward_per_second: u64,
+) {
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+
+    let mut pool_account = context
+        .banks_client
+        .get_account(USDC_USDT_POOL)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let pool_state_bytes = &mut pool_account.data[8..8 + std::mem::size_of::<LbPair>()];
+    let mut pool_state: LbPair = bytemuck::pod_read_unaligned(pool_state_bytes);
+
+    let (reward_vault, _bump) = derive_reward_vault_pda(USDC_USDT_POOL, reward_index as u64);
+    let reward_duration = 86_400;
+    let current_timestamp = clock.unix_timestamp as u64;
+
+    let reward_info = &mut pool_state.reward_infos[reward_index];
+    reward_info.mint = reward_mint;
+    reward_info.vault = reward_vault;
+    reward_info.reward_duration = reward_duration;
+    reward_info.reward_duration_end = current_timestamp + reward_duration;
+    reward_info.reward_rate = u128::from(reward_per_second) << 64;
+    reward_info.last_update_time = current_timestamp;
+
+    pool_state_bytes.copy_from_slice(bytemuck::bytes_of(&pool_state));
+    context.set_account(&USDC_USDT_POOL, &pool_account.into());
+
+    let reward_vault_state = TokenAccountState {
+        mint: reward_mint,
+        owner: USDC_USDT_POOL,
+        amount: reward_per_second * reward_duration,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+    let mut data = vec![0u8; TokenAccountState::LEN];
+    TokenAccountState::pack(reward_vault_state, &mut data).unwrap();
+
+    context.set_account(
+        &reward_vault,
+        &solana_sdk::account::Account {
+            lamports: 10_000_000,
+            data,
+            owner: anchor_spl::token::ID,
+            ..Default::default()
+        }
+        .into(),
+    );
+}
+
+#[tokio::test]
+async fn test_dlmm_claim_fee() {
+    let (mut context, mock_user, pool_setup_context, pool_keys, position_keys) =
+        setup(Pubkey::new_unique()).await;
+    let banks_client = &mut context.banks_client;
+
+    generate_swap_fees(banks_client, &pool_setup_context, &mock_user).await;
+
+    let pool_state = &pool_setup_context.pool_state;
+
+    let creator_token_x =
+        get_associated_token_address(&position_keys.creator_authority, &pool_state.token_x_mint);
+    let creator_token_y =
+        get_associated_token_address(&position_keys.creator_authority, &pool_state.token_y_mint);
+
+    let instruction = dlmm_claim_fee(&pool_keys, &position_keys, mock_user.pubkey());
+
+    let creator_token_x_before = get_token_balance(banks_client, creator_token_x).await;
+    let creator_token_y_before = get_token_balance(banks_client, creator_token_y).await;
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            instruction,
+        ],
+        &mock_user,
+        &[&mock_user],
+        banks_client,
+    )
+    .await;
+
+    let creator_token_x_after = get_token_balance(banks_client, creator_token_x).await;
+    let creator_token_y_after = get_token_balance(banks_client, creator_token_y).await;
+
+    assert!(creator_token_x_after > creator_token_x_before);
+    assert!(creator_token_y_after > creator_token_y_before);
+}
+
+#[tokio::test]
+async fn test_dlmm_claim_reward() {
+    let reward_mint = Pubkey::new_unique();
+    let reward_index = 0;
+
+    let (mut context, mock_user, _pool_setup_context, _pool_keys, position_keys) =
+        setup(reward_mint).await;
+
+    setup_farm_reward(&mut context, reward_index, reward_mint, 1_000_000).await;
+
+    let creator_reward_token =
+        get_associated_token_address(&position_keys.creator_authority, &reward_mint);
+
+    let creator_reward_token_state = TokenAccountState {
+        mint: reward_mint,
+        owner: position_keys.creator_authority,
+        amount: 0,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+    let mut data = vec![0u8; TokenAccountState::LEN];
+    TokenAccountState::pack(creator_reward_token_state, &mut data).unwrap();
+
+    context.set_account(
+        &creator_reward_token,
+        &solana_sdk::account::Account {
+            lamports: 10_000_000,
+            data,
+            owner: anchor_spl::token::ID,
+            ..Default::default()
+        }
+        .into(),
+    );
+
+    // Let the reward accumulate
+    let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += 3_600;
+    context.set_sysvar(&clock);
+
+    let instruction = dlmm_claim_reward(
+        &position_keys,
+        mock_user.pubkey(),
+        reward_index as u64,
+        reward_mint,
+    );
+
+    let banks_client = &mut context.banks_client;
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            instruction,
+        ],
+        &mock_user,
+        &[&mock_user],
+        banks_client,
+    )
+    .await;
+
+    let creator_reward_amount = get_token_balance(banks_client, creator_reward_token).await;
+    assert!(creator_reward_amount > 0);
+}
+
+#[tokio::test]
+async fn test_dlmm_claim_fee_invalid_creator_token() {
+    let (mut banks_client, mock_user, launch_pool_context) = setup_launch_pool(0, &[]).await;
+    let pool_keys = &launch_pool_context.pool_keys;
+    let position_keys = derive_position_keys(pool_keys.lb_pair, mock_user.pubkey(), 0);
+
+    let mut instruction = dlmm_claim_fee(pool_keys, &position_keys, mock_user.pubkey());
+
+    // Fee of the creator authority can only be claimed to its own token accounts
+    let creator_token_x =
+        get_associated_token_address(&position_keys.creator_authority, &pool_keys.token_x_mint);
+    instruction
+        .accounts
+        .iter_mut()
+        .find(|account| account.pubkey == creator_token_x)
+        .unwrap()
+        .pubkey = launch_pool_context.user_token_x;
+
+    process_and_assert_err(
+        &[instruction],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        CpiExampleError::InvalidCreatorTokenAccount,
+    )
+    .await;
+}
@@ -0,0 +1,118 @@
+use crate::helpers;
+use cpi_example::dlmm::types::{
+    LiquidityParameterByStrategyOneSide, StrategyParameters, StrategyType,
+};
+use cpi_example_client::instructions::*;
+use helpers::dlmm_launch_pool_utils::*;
+use helpers::{process_and_assert_err, process_and_assert_ok};
+use solana_program_test::*;
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, signature::Keypair, signer::Signer};
+
+const ACTIVE_ID: i32 = 0;
+const POSITION_WIDTH: i32 = 70;
+
+const CONSTRAINT_SEEDS: u32 = 2006;
+
+#[tokio::test]
+async fn test_dlmm_creator_authority_isolated_per_owner() {
+    let (mut banks_client, mock_user, launch_pool_context) =
+        setup_launch_pool(ACTIVE_ID, &[1]).await;
+    let pool_keys = &launch_pool_context.pool_keys;
+
+    let owner_a = Keypair::new();
+    let owner_b = Keypair::new();
+
+    // Both owners open a position of the same bin range in the same pool
+    let lower_bin_id = ACTIVE_ID + 1;
+    let position_keys_a = DlmmPositionKeys::derive_pda_creator(
+        pool_keys.lb_pair,
+        owner_a.pubkey(),
+        lower_bin_id,
+        POSITION_WIDTH,
+    );
+    let position_keys_b = DlmmPositionKeys::derive_pda_creator(
+        pool_keys.lb_pair,
+        owner_b.pubkey(),
+        lower_bin_id,
+        POSITION_WIDTH,
+    );
+
+    assert_ne!(
+        position_keys_a.creator_authority,
+        position_keys_b.creator_authority
+    );
+    assert_ne!(position_keys_a.position, position_keys_b.position);
+
+    let liquidity_parameter = LiquidityParameterByStrategyOneSide {
+        amount: 1_000_000_000,
+        active_id: ACTIVE_ID,
+        max_active_bin_slippage: 0,
+        strategy_parameters: StrategyParameters {
+            min_bin_id: lower_bin_id,
+            max_bin_id: lower_bin_id + POSITION_WIDTH - 1,
+            strategy_type: StrategyType::SpotOneSide,
+            parameteres: [0u8; 64],
+        },
+    };
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dlmm_initialize_position_pda_creator(&position_keys_a, mock_user.pubkey()),
+            dlmm_initialize_position_pda_creator(&position_keys_b, mock_user.pubkey()),
+            dlmm_add_liquidity_by_strategy_one_side_pda_creator(
+                pool_keys,
+                &position_keys_a,
+                mock_user.pubkey(),
+                pool_keys.token_x_mint,
+                liquidity_parameter,
+            ),
+        ],
+        &mock_user,
+        &[&mock_user, &owner_a, &owner_b],
+        &mut banks_client,
+    )
+    .await;
+
+    // Owner B can't sign for the creator authority of owner A
+    let mut position_keys_a_signed_by_b = DlmmPositionKeys::derive_pda_creator(
+        pool_keys.lb_pair,
+        owner_a.pubkey(),
+        lower_bin_id,
+        POSITION_WIDTH,
+    );
+    position_keys_a_signed_by_b.owner = owner_b.pubkey();
+
+    process_and_assert_err(
+        &[dlmm_remove_all_liquidity_pda_creator(
+            pool_keys,
+            &position_keys_a_signed_by_b,
+            mock_user.pubkey(),
+            launch_pool_context.user_token_x,
+            launch_pool_context.user_token_y,
+        )],
+        &mock_user,
+        &[&mock_user, &owner_b],
+        &mut banks_client,
+        CONSTRAINT_SEEDS,
+    )
+    .await;
+
+    // Owner B still controls its own position
+    process_and_assert_ok(
+        &[dlmm_close_position_pda_creator(
+            &position_keys_b,
+            mock_user.pubkey(),
+        )],
+        &mock_user,
+        &[&mock_user, &owner_b],
+        &mut banks_client,
+    )
+    .await;
+
+    let position_b = banks_client
+        .get_account(position_keys_b.position)
+        .await
+        .unwrap();
+    assert!(position_b.is_none());
+}
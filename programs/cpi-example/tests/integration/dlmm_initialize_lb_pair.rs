@@ -0,0 +1,140 @@
+use crate::helpers;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token::state::{Account as TokenAccountState, AccountState, Mint};
+use cpi_example::dlmm;
+use cpi_example::dlmm::accounts::LbPair;
+use cpi_example::dlmm::types::CustomizableParams;
+use cpi_example_client::cpi_example_pda::derive_creator_authority;
+use cpi_example_client::dlmm_pda::*;
+use cpi_example_client::instructions::*;
+use helpers::dlmm_utils::*;
+use helpers::{
+    add_packable_account, add_program_config, process_and_assert_ok, setup_cpi_example_program,
+    USDC,
+};
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account, compute_budget::ComputeBudgetInstruction, signature::Keypair, signer::Signer,
+};
+
+async fn initialize_lb_pair_pda_creator(active_id: i32, with_bitmap_extension: bool) {
+    let mock_user = Keypair::new();
+
+    let mut test = setup_cpi_example_program();
+    add_program_config(&mut test, mock_user.pubkey());
+
+    test.prefer_bpf(true);
+    test.add_program("dlmm", dlmm::ID, None);
+
+    let token_mint_x = Pubkey::new_unique();
+    let token_mint_y = USDC;
+
+    test.add_account(
+        mock_user.pubkey(),
+        Account {
+            lamports: u32::MAX.into(),
+            data: vec![],
+            owner: solana_sdk::system_program::ID,
+            ..Default::default()
+        },
+    );
+
+    for mint in [token_mint_x, token_mint_y] {
+        let mint_state = Mint {
+            decimals: 6,
+            supply: u64::MAX,
+            is_initialized: true,
+            ..Default::default()
+        };
+        add_packable_account(&mut test, mint_state, anchor_spl::token::ID, mint);
+
+        let token_state = TokenAccountState {
+            mint,
+            owner: mock_user.pubkey(),
+            amount: u64::MAX / 2,
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+        add_packable_account(
+            &mut test,
+            token_state,
+            anchor_spl::token::ID,
+            get_associated_token_address(&mock_user.pubkey(), &mint),
+        );
+    }
+
+    let (mut banks_client, _, _) = test.start().await;
+
+    let mut pool_keys =
+        DlmmPoolKeys::derive_customizable_permissionless(token_mint_x, token_mint_y);
+    let lb_pair = pool_keys.lb_pair;
+    let (creator_authority, _bump) = derive_creator_authority(mock_user.pubkey(), lb_pair);
+    let (bin_array, _bump) = derive_bin_array_pda(
+        lb_pair,
+        bin_id_to_bin_array_index(active_id).unwrap().into(),
+    );
+    let (bin_array_bitmap_extension, _bump) = derive_bin_array_bitmap_extension(lb_pair);
+    pool_keys.bin_array_bitmap_extension =
+        with_bitmap_extension.then_some(bin_array_bitmap_extension);
+
+    let instruction = dlmm_initialize_customizable_permissionless_lb_pair_pda_creator(
+        &pool_keys,
+        mock_user.pubkey(),
+        mock_user.pubkey(),
+        CustomizableParams {
+            active_id,
+            bin_step: 10,
+            base_factor: 10_000,
+            activation_type: 0,
+            has_alpha_vault: false,
+            activation_point: None,
+            padding: [0u8; 64],
+        },
+    );
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            instruction,
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let lb_pair_account = banks_client.get_account(lb_pair).await.unwrap().unwrap();
+    let lb_pair_state: LbPair =
+        bytemuck::pod_read_unaligned(&lb_pair_account.data[8..8 + std::mem::size_of::<LbPair>()]);
+
+    assert_eq!(lb_pair_state.active_id, active_id);
+    assert_eq!(lb_pair_state.creator, creator_authority);
+
+    let bin_array_account = banks_client.get_account(bin_array).await.unwrap().unwrap();
+    assert_eq!(bin_array_account.owner, dlmm::ID);
+
+    let bin_array_bitmap_extension_account = banks_client
+        .get_account(bin_array_bitmap_extension)
+        .await
+        .unwrap();
+    assert_eq!(
+        bin_array_bitmap_extension_account.is_some(),
+        with_bitmap_extension
+    );
+
+    // The lamports funded to the creator authority but not spent by the pool creation are refunded to the payer
+    let creator_authority_lamports = banks_client.get_balance(creator_authority).await.unwrap();
+    assert_eq!(creator_authority_lamports, 0);
+}
+
+#[tokio::test]
+async fn test_dlmm_initialize_lb_pair_pda_creator() {
+    initialize_lb_pair_pda_creator(0, false).await;
+}
+
+#[tokio::test]
+async fn test_dlmm_initialize_lb_pair_pda_creator_with_bitmap_extension() {
+    // Active bin array is out of the pool bin array bitmap range
+    initialize_lb_pair_pda_creator(40_000, true).await;
+}
@@ -0,0 +1,314 @@
+use crate::helpers;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use cpi_example::dlmm;
+use cpi_example::dlmm::types::{LiquidityParameterByStrategy, StrategyParameters, StrategyType};
+use cpi_example::state::RemoveLiquidityResult;
+use cpi_example_client::instructions::*;
+use helpers::dlmm_position_utils::*;
+use helpers::dlmm_utils::*;
+use helpers::{
+    add_program_config, get_token_balance, process_and_assert_err, process_and_assert_ok,
+    process_and_get_return_data, setup_cpi_example_program,
+};
+use solana_program_test::*;
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, signature::Keypair, signer::Signer};
+
+const USDC_USDT_POOL: Pubkey = solana_sdk::pubkey!("ARwi1S4DaiTG5DX7S4M4ZsrXqpMD1MrTmbu9ue2tpmEq");
+
+const NON_EMPTY_POSITION: u32 = 6030;
+const CONSTRAINT_TOKEN_MINT: u32 = 2014;
+
+async fn setup() -> (
+    BanksClient,
+    Keypair,
+    PoolSetupContext,
+    DlmmPoolKeys,
+    DlmmPositionKeys,
+) {
+    let mock_user = Keypair::new();
+
+    let mut test = setup_cpi_example_program();
+    add_program_config(&mut test, mock_user.pubkey());
+
+    test.prefer_bpf(true);
+    test.add_program("dlmm", dlmm::ID, None);
+
+    let pool_setup_context =
+        setup_pool_from_cluster(&mut test, USDC_USDT_POOL, mock_user.pubkey()).await;
+
+    let pool_keys = DlmmPoolKeys::new(USDC_USDT_POOL, &pool_setup_context.pool_state);
+    let position_keys = derive_position_keys(
+        USDC_USDT_POOL,
+        mock_user.pubkey(),
+        pool_setup_context.pool_state.active_id,
+    );
+
+    setup_position_bin_arrays_from_cluster(
+        &mut test,
+        &position_keys,
+        pool_setup_context.pool_state.active_id,
+    )
+    .await;
+
+    let (banks_client, _, _) = test.start().await;
+
+    (
+        banks_client,
+        mock_user,
+        pool_setup_context,
+        pool_keys,
+        position_keys,
+    )
+}
+
+#[tokio::test]
+async fn test_dlmm_position_pda_creator_open_deposit_withdraw_close() {
+    let (mut banks_client, mock_user, pool_setup_context, pool_keys, position_keys) = setup().await;
+    let active_id = pool_setup_context.pool_state.active_id;
+
+    process_and_assert_ok(
+        &[dlmm_initialize_position_pda_creator(
+            &position_keys,
+            mock_user.pubkey(),
+        )],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let position_account = banks_client
+        .get_account(position_keys.position)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(position_account.owner, dlmm::ID);
+
+    let amount_x = 1_000_000;
+    let amount_y = 1_000_000;
+
+    let liquidity_parameter = LiquidityParameterByStrategy {
+        amount_x,
+        amount_y,
+        active_id,
+        max_active_bin_slippage: 0,
+        strategy_parameters: StrategyParameters {
+            min_bin_id: active_id - 5,
+            max_bin_id: active_id + 5,
+            strategy_type: StrategyType::SpotBalanced,
+            parameteres: [0u8; 64],
+        },
+    };
+
+    let pool_state = &pool_setup_context.pool_state;
+    let reserve_x_before = get_token_balance(&mut banks_client, pool_state.reserve_x).await;
+    let reserve_y_before = get_token_balance(&mut banks_client, pool_state.reserve_y).await;
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dlmm_add_liquidity_by_strategy_pda_creator(
+                &pool_keys,
+                &position_keys,
+                mock_user.pubkey(),
+                liquidity_parameter,
+            ),
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let reserve_x_after = get_token_balance(&mut banks_client, pool_state.reserve_x).await;
+    let reserve_y_after = get_token_balance(&mut banks_client, pool_state.reserve_y).await;
+
+    let deposited_x = reserve_x_after - reserve_x_before;
+    let deposited_y = reserve_y_after - reserve_y_before;
+    assert!(deposited_x > 0 && deposited_x <= amount_x);
+    assert!(deposited_y > 0 && deposited_y <= amount_y);
+
+    // Position with liquidity can't be closed
+    process_and_assert_err(
+        &[dlmm_close_position_pda_creator(
+            &position_keys,
+            mock_user.pubkey(),
+        )],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        NON_EMPTY_POSITION,
+    )
+    .await;
+
+    // Withdraw half of the liquidity of the bins at and above the active bin
+    let user_token_x_before =
+        get_token_balance(&mut banks_client, pool_setup_context.user_token_x).await;
+    let user_token_y_before =
+        get_token_balance(&mut banks_client, pool_setup_context.user_token_y).await;
+
+    let remove_result: RemoveLiquidityResult = process_and_get_return_data(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dlmm_remove_liquidity_by_range_pda_creator(
+                &pool_keys,
+                &position_keys,
+                mock_user.pubkey(),
+                pool_setup_context.user_token_x,
+                pool_setup_context.user_token_y,
+                active_id,
+                active_id + 5,
+                5_000,
+            ),
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let user_token_x_after =
+        get_token_balance(&mut banks_client, pool_setup_context.user_token_x).await;
+    let user_token_y_after =
+        get_token_balance(&mut banks_client, pool_setup_context.user_token_y).await;
+    let withdrawn_x = user_token_x_after - user_token_x_before;
+    let withdrawn_y = user_token_y_after - user_token_y_before;
+    assert!(withdrawn_x > 0 && withdrawn_x < deposited_x);
+
+    // The reported amounts are the balance changes of the receiver token accounts
+    assert_eq!(
+        remove_result,
+        RemoveLiquidityResult {
+            amount_x: withdrawn_x,
+            amount_y: withdrawn_y,
+        }
+    );
+
+    // Withdraw the rest
+    let user_token_x_before = user_token_x_after;
+    let user_token_y_before = user_token_y_after;
+
+    let remove_result: RemoveLiquidityResult = process_and_get_return_data(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dlmm_remove_all_liquidity_pda_creator(
+                &pool_keys,
+                &position_keys,
+                mock_user.pubkey(),
+                pool_setup_context.user_token_x,
+                pool_setup_context.user_token_y,
+            ),
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let user_token_x_after =
+        get_token_balance(&mut banks_client, pool_setup_context.user_token_x).await;
+    let user_token_y_after =
+        get_token_balance(&mut banks_client, pool_setup_context.user_token_y).await;
+
+    assert_eq!(
+        remove_result,
+        RemoveLiquidityResult {
+            amount_x: user_token_x_after - user_token_x_before,
+            amount_y: user_token_y_after - user_token_y_before,
+        }
+    );
+
+    // Rounding down in favor of the pool
+    let total_withdrawn_x = withdrawn_x + remove_result.amount_x;
+    let total_withdrawn_y = withdrawn_y + remove_result.amount_y;
+    assert!(total_withdrawn_x <= deposited_x && deposited_x - total_withdrawn_x <= 22);
+    assert!(total_withdrawn_y <= deposited_y && deposited_y - total_withdrawn_y <= 22);
+
+    process_and_assert_ok(
+        &[dlmm_close_position_pda_creator(
+            &position_keys,
+            mock_user.pubkey(),
+        )],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let position_account = banks_client
+        .get_account(position_keys.position)
+        .await
+        .unwrap();
+    assert!(position_account.is_none());
+}
+
+#[tokio::test]
+async fn test_dlmm_remove_liquidity_pda_creator_invalid_receiver() {
+    let (mut banks_client, mock_user, pool_setup_context, pool_keys, position_keys) = setup().await;
+
+    initialize_position_and_add_liquidity(
+        &mut banks_client,
+        &mock_user,
+        &pool_keys,
+        &position_keys,
+        pool_setup_context.pool_state.active_id,
+        1_000_000,
+        1_000_000,
+    )
+    .await;
+
+    // Token X withdrawn to the token Y account
+    let instruction = dlmm_remove_all_liquidity_pda_creator(
+        &pool_keys,
+        &position_keys,
+        mock_user.pubkey(),
+        pool_setup_context.user_token_y,
+        pool_setup_context.user_token_y,
+    );
+
+    process_and_assert_err(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            instruction,
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        CONSTRAINT_TOKEN_MINT,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_dlmm_position_pda_creator_close_empty() {
+    let (mut banks_client, mock_user, _pool_setup_context, _pool_keys, position_keys) =
+        setup().await;
+
+    process_and_assert_ok(
+        &[dlmm_initialize_position_pda_creator(
+            &position_keys,
+            mock_user.pubkey(),
+        )],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    process_and_assert_ok(
+        &[dlmm_close_position_pda_creator(
+            &position_keys,
+            mock_user.pubkey(),
+        )],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let position_account = banks_client
+        .get_account(position_keys.position)
+        .await
+        .unwrap();
+    assert!(position_account.is_none());
+}
@@ -1,18 +1,30 @@
 use crate::helpers;
-use anchor_lang::{solana_program::pubkey::Pubkey, InstructionData, ToAccountMetas};
+use anchor_lang::solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use anchor_spl::associated_token::get_associated_token_address;
 use cpi_example::dlmm;
-use helpers::dlmm_pda::*;
+use cpi_example::dlmm::types::{
+    LiquidityParameterByStrategyOneSide, StrategyParameters, StrategyType,
+};
+use cpi_example::errors::CpiExampleError;
+use cpi_example::state::{IntegratorFee, IntegratorFeeMode, SwapResult};
+use cpi_example::token_utils::MAX_INTEGRATOR_FEE_BPS;
+use cpi_example_client::dlmm_pda::*;
+use cpi_example_client::instructions::*;
+use helpers::dlmm_launch_pool_utils::*;
 use helpers::dlmm_utils::*;
-use helpers::{process_and_assert_ok, setup_cpi_example_program};
-use solana_program_test::*;
-use solana_sdk::instruction::AccountMeta;
-use solana_sdk::{
-    compute_budget::ComputeBudgetInstruction, instruction::Instruction, signature::Keypair,
-    signer::Signer,
+use helpers::{
+    get_token_balance, process_and_assert_err, process_and_assert_ok, process_and_get_return_data,
+    setup_cpi_example_program,
 };
+use solana_program_test::*;
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, signature::Keypair, signer::Signer};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
 
 const USDC_USDT_POOL: Pubkey = solana_sdk::pubkey!("ARwi1S4DaiTG5DX7S4M4ZsrXqpMD1MrTmbu9ue2tpmEq");
 
+const SWAP_AMOUNT_IN: u64 = 1_000_000;
+const INTEGRATOR_FEE_BPS: u16 = 50;
+
 #[tokio::test]
 async fn test_dlmm_swap() {
     let mock_user = Keypair::new();
@@ -28,49 +40,490 @@ async fn test_dlmm_swap() {
         user_token_y,
     } = setup_pool_from_cluster(&mut test, USDC_USDT_POOL, mock_user.pubkey()).await;
 
+    let active_bin_array_idx = bin_id_to_bin_array_index(pool_state.active_id).unwrap();
+    // Swap token X to token Y moves the active bin downward
+    setup_bin_array_from_cluster(&mut test, USDC_USDT_POOL, (active_bin_array_idx - 1).into())
+        .await;
+
     let (mut banks_client, _, _) = test.start().await;
 
-    let ix_data = cpi_example::instruction::DlmmSwap {
-        amount_in: 1_000_000,
-        min_amount_out: 0,
-    }
-    .data();
-
-    let mut accounts = cpi_example::accounts::DlmmSwap {
-        lb_pair: USDC_USDT_POOL,
-        bin_array_bitmap_extension: None,
-        reserve_x: pool_state.reserve_x,
-        reserve_y: pool_state.reserve_y,
-        user_token_in: user_token_x,
-        user_token_out: user_token_y,
-        token_x_mint: pool_state.token_x_mint,
-        token_y_mint: pool_state.token_y_mint,
-        oracle: pool_state.oracle,
-        host_fee_in: None,
-        user: mock_user.pubkey(),
-        dlmm_program: dlmm::ID,
-        event_authority: derive_event_authority_pda().0,
-        token_x_program: anchor_spl::token::ID,
-        token_y_program: anchor_spl::token::ID,
+    // Bin arrays are ordered by the swap direction, starting from the active bin array
+    let bin_arrays = bin_array_keys(
+        USDC_USDT_POOL,
+        &[
+            active_bin_array_idx.into(),
+            (active_bin_array_idx - 1).into(),
+        ],
+    );
+
+    let instruction = dlmm_swap(
+        &DlmmPoolKeys::new(USDC_USDT_POOL, &pool_state),
+        mock_user.pubkey(),
+        user_token_x,
+        user_token_y,
+        &bin_arrays,
+        1_000_000,
+        0,
+        None,
+    );
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            instruction,
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+}
+
+/// Launch pool with token X deposited above the active bin, to be bought with token Y
+async fn setup_launch_pool_with_ask_liquidity() -> (BanksClient, Keypair, LaunchPoolContext) {
+    let active_id = 0;
+    let position_width = 70;
+    let (mut banks_client, mock_user, launch_pool_context) =
+        setup_launch_pool(active_id, &[0, 1]).await;
+    let pool_keys = &launch_pool_context.pool_keys;
+
+    let lower_bin_id = active_id + 1;
+    let position_keys = DlmmPositionKeys::derive_pda_creator(
+        pool_keys.lb_pair,
+        mock_user.pubkey(),
+        lower_bin_id,
+        position_width,
+    );
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dlmm_initialize_position_pda_creator(&position_keys, mock_user.pubkey()),
+            dlmm_add_liquidity_by_strategy_one_side_pda_creator(
+                pool_keys,
+                &position_keys,
+                mock_user.pubkey(),
+                pool_keys.token_x_mint,
+                LiquidityParameterByStrategyOneSide {
+                    amount: 1_000_000_000,
+                    active_id,
+                    max_active_bin_slippage: 0,
+                    strategy_parameters: StrategyParameters {
+                        min_bin_id: lower_bin_id,
+                        max_bin_id: lower_bin_id + position_width - 1,
+                        strategy_type: StrategyType::SpotOneSide,
+                        parameteres: [0u8; 64],
+                    },
+                },
+            ),
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    (banks_client, mock_user, launch_pool_context)
+}
+
+#[tokio::test]
+async fn test_dlmm_swap_return_data() {
+    let (mut banks_client, mock_user, launch_pool_context) =
+        setup_launch_pool_with_ask_liquidity().await;
+    let pool_keys = &launch_pool_context.pool_keys;
+
+    let user_token_x_before =
+        get_token_balance(&mut banks_client, launch_pool_context.user_token_x).await;
+    let user_token_y_before =
+        get_token_balance(&mut banks_client, launch_pool_context.user_token_y).await;
+
+    // Swap token Y to token X moves the active bin upward
+    let instruction = dlmm_swap(
+        pool_keys,
+        mock_user.pubkey(),
+        launch_pool_context.user_token_y,
+        launch_pool_context.user_token_x,
+        &bin_array_keys(pool_keys.lb_pair, &[0, 1]),
+        1_000_000,
+        0,
+        None,
+    );
+
+    let swap_result: SwapResult = process_and_get_return_data(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            instruction,
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let user_token_x_after =
+        get_token_balance(&mut banks_client, launch_pool_context.user_token_x).await;
+    let user_token_y_after =
+        get_token_balance(&mut banks_client, launch_pool_context.user_token_y).await;
+
+    assert_eq!(swap_result.amount_in, 1_000_000);
+    assert_eq!(
+        user_token_y_before - user_token_y_after,
+        swap_result.amount_in
+    );
+    assert!(swap_result.amount_out > 0);
+    assert_eq!(
+        user_token_x_after - user_token_x_before,
+        swap_result.amount_out
+    );
+}
+
+/// Token balances changed by a swap token Y to token X in the launch pool
+struct LaunchPoolSwapBalances {
+    user_token_x: u64,
+    user_token_y: u64,
+    reserve_x: u64,
+    reserve_y: u64,
+    integrator_fee_token: u64,
+}
+
+impl LaunchPoolSwapBalances {
+    async fn load(
+        banks_client: &mut BanksClient,
+        launch_pool_context: &LaunchPoolContext,
+        integrator_fee_token: Pubkey,
+    ) -> Self {
+        let pool_keys = &launch_pool_context.pool_keys;
+
+        Self {
+            user_token_x: get_token_balance(banks_client, launch_pool_context.user_token_x).await,
+            user_token_y: get_token_balance(banks_client, launch_pool_context.user_token_y).await,
+            reserve_x: get_token_balance(banks_client, pool_keys.reserve_x).await,
+            reserve_y: get_token_balance(banks_client, pool_keys.reserve_y).await,
+            integrator_fee_token: get_token_balance(banks_client, integrator_fee_token).await,
+        }
     }
-    .to_account_metas(None);
+}
 
-    let (active_bin_array_key, _bump) = derive_bin_array_pda(
-        USDC_USDT_POOL,
-        bin_id_to_bin_array_index(pool_state.active_id)
-            .unwrap()
-            .into(),
+/// Swap token Y to token X with the integrator fee, and return the balances before and after the swap
+async fn swap_with_integrator_fee(
+    mode: IntegratorFeeMode,
+) -> (SwapResult, LaunchPoolSwapBalances, LaunchPoolSwapBalances) {
+    let (mut banks_client, mock_user, launch_pool_context) =
+        setup_launch_pool_with_ask_liquidity().await;
+    let pool_keys = &launch_pool_context.pool_keys;
+
+    let charged_mint = match mode {
+        IntegratorFeeMode::Input => pool_keys.token_y_mint,
+        IntegratorFeeMode::Output => pool_keys.token_x_mint,
+    };
+    let integrator = Pubkey::new_unique();
+    let integrator_fee_token = get_associated_token_address(&integrator, &charged_mint);
+
+    process_and_assert_ok(
+        &[create_associated_token_account_idempotent(
+            &mock_user.pubkey(),
+            &integrator,
+            &charged_mint,
+            &anchor_spl::token::ID,
+        )],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let balances_before = LaunchPoolSwapBalances::load(
+        &mut banks_client,
+        &launch_pool_context,
+        integrator_fee_token,
+    )
+    .await;
+
+    let instruction = dlmm_swap(
+        pool_keys,
+        mock_user.pubkey(),
+        launch_pool_context.user_token_y,
+        launch_pool_context.user_token_x,
+        &bin_array_keys(pool_keys.lb_pair, &[0, 1]),
+        SWAP_AMOUNT_IN,
+        0,
+        Some((
+            IntegratorFee {
+                fee_bps: INTEGRATOR_FEE_BPS,
+                mode,
+            },
+            integrator_fee_token,
+        )),
+    );
+
+    let swap_result: SwapResult = process_and_get_return_data(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            instruction,
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let balances_after = LaunchPoolSwapBalances::load(
+        &mut banks_client,
+        &launch_pool_context,
+        integrator_fee_token,
+    )
+    .await;
+
+    (swap_result, balances_before, balances_after)
+}
+
+#[tokio::test]
+async fn test_dlmm_swap_integrator_fee_input() {
+    let (swap_result, before, after) = swap_with_integrator_fee(IntegratorFeeMode::Input).await;
+
+    let integrator_fee = SWAP_AMOUNT_IN * u64::from(INTEGRATOR_FEE_BPS) / 10_000;
+    let integrator_fee_received = after.integrator_fee_token - before.integrator_fee_token;
+    let reserve_y_received = after.reserve_y - before.reserve_y;
+
+    // The integrator fee token account also receives the DLMM host fee of the swap
+    assert_eq!(swap_result.amount_in, SWAP_AMOUNT_IN);
+    assert_eq!(before.user_token_y - after.user_token_y, SWAP_AMOUNT_IN);
+    assert!(integrator_fee_received > integrator_fee);
+    assert_eq!(integrator_fee_received + reserve_y_received, SWAP_AMOUNT_IN);
+
+    assert!(swap_result.amount_out > 0);
+    assert_eq!(
+        after.user_token_x - before.user_token_x,
+        swap_result.amount_out
     );
+    assert_eq!(before.reserve_x - after.reserve_x, swap_result.amount_out);
+}
+
+#[tokio::test]
+async fn test_dlmm_swap_integrator_fee_output() {
+    let (swap_result, before, after) = swap_with_integrator_fee(IntegratorFeeMode::Output).await;
+
+    let swap_amount_out = before.reserve_x - after.reserve_x;
+    let integrator_fee = swap_amount_out * u64::from(INTEGRATOR_FEE_BPS) / 10_000;
 
-    accounts.push(AccountMeta::new(active_bin_array_key, false));
+    assert_eq!(swap_result.amount_in, SWAP_AMOUNT_IN);
+    assert_eq!(before.user_token_y - after.user_token_y, SWAP_AMOUNT_IN);
+    assert_eq!(after.reserve_y - before.reserve_y, SWAP_AMOUNT_IN);
 
-    let instruction = Instruction {
-        program_id: cpi_example::id(),
-        data: ix_data,
-        accounts,
+    assert!(integrator_fee > 0);
+    assert_eq!(
+        after.integrator_fee_token - before.integrator_fee_token,
+        integrator_fee
+    );
+    assert_eq!(swap_result.amount_out, swap_amount_out - integrator_fee);
+    assert_eq!(
+        after.user_token_x - before.user_token_x,
+        swap_result.amount_out
+    );
+}
+
+#[tokio::test]
+async fn test_dlmm_swap_invalid_integrator_fee() {
+    let (mut banks_client, mock_user, launch_pool_context) =
+        setup_launch_pool_with_ask_liquidity().await;
+    let pool_keys = &launch_pool_context.pool_keys;
+
+    let integrator = Pubkey::new_unique();
+    let integrator_token_x = get_associated_token_address(&integrator, &pool_keys.token_x_mint);
+
+    process_and_assert_ok(
+        &[create_associated_token_account_idempotent(
+            &mock_user.pubkey(),
+            &integrator,
+            &pool_keys.token_x_mint,
+            &anchor_spl::token::ID,
+        )],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let output_fee = IntegratorFee {
+        fee_bps: INTEGRATOR_FEE_BPS,
+        mode: IntegratorFeeMode::Output,
     };
+    let cases = [
+        (
+            Some((
+                IntegratorFee {
+                    fee_bps: MAX_INTEGRATOR_FEE_BPS + 1,
+                    ..output_fee
+                },
+                integrator_token_x,
+            )),
+            CpiExampleError::InvalidIntegratorFee,
+        ),
+        // Token X is not the input token of the swap token Y to token X
+        (
+            Some((
+                IntegratorFee {
+                    mode: IntegratorFeeMode::Input,
+                    ..output_fee
+                },
+                integrator_token_x,
+            )),
+            CpiExampleError::InvalidIntegratorFeeToken,
+        ),
+        // Reserve of the pool is not an associated token account
+        (
+            Some((output_fee, pool_keys.reserve_x)),
+            CpiExampleError::InvalidIntegratorFeeToken,
+        ),
+    ];
+
+    for (integrator_fee, error_code) in cases {
+        let instruction = dlmm_swap(
+            pool_keys,
+            mock_user.pubkey(),
+            launch_pool_context.user_token_y,
+            launch_pool_context.user_token_x,
+            &bin_array_keys(pool_keys.lb_pair, &[0, 1]),
+            SWAP_AMOUNT_IN,
+            0,
+            integrator_fee,
+        );
+
+        process_and_assert_err(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                instruction,
+            ],
+            &mock_user,
+            &[&mock_user],
+            &mut banks_client,
+            error_code,
+        )
+        .await;
+    }
+
+    // Omit the optional integrator fee token account
+    let mut instruction = dlmm_swap(
+        pool_keys,
+        mock_user.pubkey(),
+        launch_pool_context.user_token_y,
+        launch_pool_context.user_token_x,
+        &bin_array_keys(pool_keys.lb_pair, &[0, 1]),
+        SWAP_AMOUNT_IN,
+        0,
+        Some((output_fee, integrator_token_x)),
+    );
+    let integrator_fee_token = instruction
+        .accounts
+        .iter_mut()
+        .find(|account| account.pubkey == integrator_token_x)
+        .unwrap();
+    integrator_fee_token.pubkey = cpi_example::ID;
+    integrator_fee_token.is_writable = false;
+
+    process_and_assert_err(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            instruction,
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        CpiExampleError::MissingIntegratorFeeToken,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_dlmm_swap_exact_out_and_with_price_impact_reject_integrator_fee_token() {
+    let (mut banks_client, mock_user, launch_pool_context) =
+        setup_launch_pool_with_ask_liquidity().await;
+    let pool_keys = &launch_pool_context.pool_keys;
+
+    let integrator = Pubkey::new_unique();
+    let integrator_token_x = get_associated_token_address(&integrator, &pool_keys.token_x_mint);
 
     process_and_assert_ok(
+        &[create_associated_token_account_idempotent(
+            &mock_user.pubkey(),
+            &integrator,
+            &pool_keys.token_x_mint,
+            &anchor_spl::token::ID,
+        )],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let bin_arrays = bin_array_keys(pool_keys.lb_pair, &[0, 1]);
+    let instructions = [
+        dlmm_swap_exact_out(
+            pool_keys,
+            mock_user.pubkey(),
+            launch_pool_context.user_token_y,
+            launch_pool_context.user_token_x,
+            &bin_arrays,
+            SWAP_AMOUNT_IN,
+            1,
+        ),
+        dlmm_swap_with_price_impact(
+            pool_keys,
+            mock_user.pubkey(),
+            launch_pool_context.user_token_y,
+            launch_pool_context.user_token_x,
+            &bin_arrays,
+            SWAP_AMOUNT_IN,
+            None,
+            10_000,
+        ),
+    ];
+
+    for mut instruction in instructions {
+        // The integrator fee token account follows the oracle and host_fee_in accounts
+        let index = instruction
+            .accounts
+            .iter()
+            .position(|account| account.pubkey == pool_keys.oracle)
+            .unwrap()
+            + 2;
+        instruction.accounts[index] = AccountMeta::new(integrator_token_x, false);
+
+        process_and_assert_err(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                instruction,
+            ],
+            &mock_user,
+            &[&mock_user],
+            &mut banks_client,
+            CpiExampleError::InvalidIntegratorFee,
+        )
+        .await;
+    }
+}
+
+/// Swap token X to token Y in the launch pool with the given bin arrays
+async fn swap_with_remaining_accounts_and_assert_err(
+    bin_arrays: impl Fn(&LaunchPoolContext) -> Vec<Pubkey>,
+    error_code: CpiExampleError,
+) {
+    let active_id = 0;
+    let (mut banks_client, mock_user, launch_pool_context) =
+        setup_launch_pool(active_id, &[-1, 0, 1]).await;
+
+    let instruction = dlmm_swap(
+        &launch_pool_context.pool_keys,
+        mock_user.pubkey(),
+        launch_pool_context.user_token_x,
+        launch_pool_context.user_token_y,
+        &bin_arrays(&launch_pool_context),
+        1_000_000,
+        0,
+        None,
+    );
+
+    process_and_assert_err(
         &[
             ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
             instruction,
@@ -78,6 +531,59 @@ async fn test_dlmm_swap() {
         &mock_user,
         &[&mock_user],
         &mut banks_client,
+        error_code,
+    )
+    .await;
+}
+
+fn bin_array_keys(lb_pair: Pubkey, bin_array_indexes: &[i64]) -> Vec<Pubkey> {
+    bin_array_indexes
+        .iter()
+        .map(|index| derive_bin_array_pda(lb_pair, *index).0)
+        .collect()
+}
+
+#[tokio::test]
+async fn test_dlmm_swap_missing_bin_array() {
+    swap_with_remaining_accounts_and_assert_err(|_| vec![], CpiExampleError::MissingBinArray).await;
+}
+
+#[tokio::test]
+async fn test_dlmm_swap_invalid_bin_array() {
+    // Oracle is owned by DLMM program but is not a bin array
+    swap_with_remaining_accounts_and_assert_err(
+        |launch_pool_context| vec![launch_pool_context.pool_keys.oracle],
+        CpiExampleError::InvalidBinArray,
+    )
+    .await;
+
+    // Bin array of another pool
+    swap_with_remaining_accounts_and_assert_err(
+        |_| bin_array_keys(USDC_USDT_POOL, &[0]),
+        CpiExampleError::InvalidBinArray,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_dlmm_swap_invalid_bin_array_order() {
+    // Swap token X to token Y requires descending bin array index
+    swap_with_remaining_accounts_and_assert_err(
+        |launch_pool_context| bin_array_keys(launch_pool_context.pool_keys.lb_pair, &[0, 1]),
+        CpiExampleError::InvalidBinArrayOrder,
+    )
+    .await;
+
+    swap_with_remaining_accounts_and_assert_err(
+        |launch_pool_context| bin_array_keys(launch_pool_context.pool_keys.lb_pair, &[0, 0]),
+        CpiExampleError::InvalidBinArrayOrder,
+    )
+    .await;
+
+    // The first bin array must contain the active bin
+    swap_with_remaining_accounts_and_assert_err(
+        |launch_pool_context| bin_array_keys(launch_pool_context.pool_keys.lb_pair, &[-1]),
+        CpiExampleError::InvalidBinArrayOrder,
     )
     .await;
 }
@@ -0,0 +1,70 @@
+use crate::helpers;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use cpi_example::dlmm;
+use cpi_example_client::dlmm_pda::*;
+use cpi_example_client::instructions::*;
+use helpers::dlmm_utils::*;
+use helpers::{get_token_balance, process_and_assert_ok, setup_cpi_example_program};
+use solana_program_test::*;
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, signature::Keypair, signer::Signer};
+
+const USDC_USDT_POOL: Pubkey = solana_sdk::pubkey!("ARwi1S4DaiTG5DX7S4M4ZsrXqpMD1MrTmbu9ue2tpmEq");
+
+#[tokio::test]
+async fn test_dlmm_swap_exact_out() {
+    let mock_user = Keypair::new();
+
+    let mut test = setup_cpi_example_program();
+
+    test.prefer_bpf(true);
+    test.add_program("dlmm", dlmm::ID, None);
+
+    let PoolSetupContext {
+        pool_state,
+        user_token_x,
+        user_token_y,
+    } = setup_pool_from_cluster(&mut test, USDC_USDT_POOL, mock_user.pubkey()).await;
+
+    let (mut banks_client, _, _) = test.start().await;
+
+    let max_in_amount = 2_000_000;
+    let out_amount = 1_000_000;
+
+    let (active_bin_array_key, _bump) = derive_bin_array_pda(
+        USDC_USDT_POOL,
+        bin_id_to_bin_array_index(pool_state.active_id)
+            .unwrap()
+            .into(),
+    );
+
+    let instruction = dlmm_swap_exact_out(
+        &DlmmPoolKeys::new(USDC_USDT_POOL, &pool_state),
+        mock_user.pubkey(),
+        user_token_x,
+        user_token_y,
+        &[active_bin_array_key],
+        max_in_amount,
+        out_amount,
+    );
+
+    let user_token_x_before = get_token_balance(&mut banks_client, user_token_x).await;
+    let user_token_y_before = get_token_balance(&mut banks_client, user_token_y).await;
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            instruction,
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let user_token_x_after = get_token_balance(&mut banks_client, user_token_x).await;
+    let user_token_y_after = get_token_balance(&mut banks_client, user_token_y).await;
+
+    let in_amount = user_token_x_before - user_token_x_after;
+    assert!(in_amount > 0 && in_amount <= max_in_amount);
+    assert_eq!(user_token_y_after - user_token_y_before, out_amount);
+}
@@ -0,0 +1,130 @@
+use crate::helpers;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use cpi_example::dlmm;
+use cpi_example::dlmm::accounts::LbPair;
+use cpi_example_client::dlmm_pda::*;
+use cpi_example_client::instructions::*;
+use helpers::dlmm_utils::*;
+use helpers::{
+    get_token_balance, process_and_assert_err, process_and_assert_ok, setup_cpi_example_program,
+};
+use solana_program_test::*;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction, instruction::Instruction, signature::Keypair,
+    signer::Signer,
+};
+
+const USDC_USDT_POOL: Pubkey = solana_sdk::pubkey!("ARwi1S4DaiTG5DX7S4M4ZsrXqpMD1MrTmbu9ue2tpmEq");
+
+// DLMM ExceededAmountSlippageTolerance
+const EXCEEDED_AMOUNT_SLIPPAGE_TOLERANCE: u32 = 6003;
+
+fn build_swap_with_price_impact_ix(
+    pool_state: &LbPair,
+    user_token_x: Pubkey,
+    user_token_y: Pubkey,
+    user: Pubkey,
+    amount_in: u64,
+    max_price_impact_bps: u16,
+) -> Instruction {
+    let (active_bin_array_key, _bump) = derive_bin_array_pda(
+        USDC_USDT_POOL,
+        bin_id_to_bin_array_index(pool_state.active_id)
+            .unwrap()
+            .into(),
+    );
+
+    dlmm_swap_with_price_impact(
+        &DlmmPoolKeys::new(USDC_USDT_POOL, pool_state),
+        user,
+        user_token_x,
+        user_token_y,
+        &[active_bin_array_key],
+        amount_in,
+        Some(pool_state.active_id),
+        max_price_impact_bps,
+    )
+}
+
+#[tokio::test]
+async fn test_dlmm_swap_with_price_impact() {
+    let mock_user = Keypair::new();
+
+    let mut test = setup_cpi_example_program();
+
+    test.prefer_bpf(true);
+    test.add_program("dlmm", dlmm::ID, None);
+
+    let PoolSetupContext {
+        pool_state,
+        user_token_x,
+        user_token_y,
+    } = setup_pool_from_cluster(&mut test, USDC_USDT_POOL, mock_user.pubkey()).await;
+
+    let (mut banks_client, _, _) = test.start().await;
+
+    let instruction = build_swap_with_price_impact_ix(
+        &pool_state,
+        user_token_x,
+        user_token_y,
+        mock_user.pubkey(),
+        1_000_000,
+        100,
+    );
+
+    let user_token_y_before = get_token_balance(&mut banks_client, user_token_y).await;
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            instruction,
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let user_token_y_after = get_token_balance(&mut banks_client, user_token_y).await;
+    assert!(user_token_y_after > user_token_y_before);
+}
+
+#[tokio::test]
+async fn test_dlmm_swap_with_price_impact_exceeded() {
+    let mock_user = Keypair::new();
+
+    let mut test = setup_cpi_example_program();
+
+    test.prefer_bpf(true);
+    test.add_program("dlmm", dlmm::ID, None);
+
+    let PoolSetupContext {
+        pool_state,
+        user_token_x,
+        user_token_y,
+    } = setup_pool_from_cluster(&mut test, USDC_USDT_POOL, mock_user.pubkey()).await;
+
+    let (mut banks_client, _, _) = test.start().await;
+
+    // Swap fee alone moves the effective price away from the active bin, so 0 bps can never be met
+    let instruction = build_swap_with_price_impact_ix(
+        &pool_state,
+        user_token_x,
+        user_token_y,
+        mock_user.pubkey(),
+        1_000_000,
+        0,
+    );
+
+    process_and_assert_err(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            instruction,
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        EXCEEDED_AMOUNT_SLIPPAGE_TOLERANCE,
+    )
+    .await;
+}
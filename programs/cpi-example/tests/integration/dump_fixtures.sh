@@ -1,7 +0,0 @@
-#!/bin/bash
-
-solana program dump LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo ./fixtures/dlmm.so
-solana program dump 24Uqj9JCLxUeoC3hGfh5W3s9FM9uCHDS2SG3LYwBpyTi ./fixtures/dynamic_vault.so
-solana program dump Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB ./fixtures/dynamic_amm.so
-solana program dump metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s ./fixtures/metaplex.so
-solana program dump FEESngU3neckdwib9X3KWqdL7Mjmqk9XNp3uh5JbP4KP ./fixtures/m3m3.so
\ No newline at end of file
@@ -0,0 +1,328 @@
+use crate::helpers;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use cpi_example::dynamic_amm::types::CustomizableParams;
+use cpi_example::errors::CpiExampleError;
+use cpi_example_client::cpi_example_pda::derive_creator_authority;
+use cpi_example_client::dynamic_amm_pda::METAPLEX_PROGRAM_ID;
+use cpi_example_client::instructions::*;
+use helpers::dynamic_amm_utils::{get_pool_info, setup_vault_from_cluster};
+use helpers::*;
+use solana_program_test::*;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction, instruction::Instruction, pubkey::Pubkey,
+    signature::Keypair, signer::Signer,
+};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+const INITIAL_TOKEN_AMOUNT: u64 = 100_000_000;
+
+fn customizable_params() -> CustomizableParams {
+    CustomizableParams {
+        trade_fee_numerator: 10_000,
+        activation_point: None,
+        has_alpha_vault: false,
+        activation_type: 1,
+        padding: [0u8; 90],
+    }
+}
+
+/// JUP-USDC constant product pool created by the mock user, or by the creator authority PDA of the mock user
+async fn setup_pool(pda_creator: bool) -> (BanksClient, Keypair, DynamicAmmPoolKeys) {
+    let mock_user = Keypair::new();
+
+    let mut test = setup_cpi_example_program();
+    add_program_config(&mut test, mock_user.pubkey());
+    test.prefer_bpf(true);
+
+    test.add_program("dynamic_amm", cpi_example::dynamic_amm::ID, None);
+    test.add_program("dynamic_vault", cpi_example::dynamic_vault::ID, None);
+    test.add_program("metaplex", METAPLEX_PROGRAM_ID, None);
+
+    setup_vault_from_cluster(&mut test, JUP, mock_user.pubkey()).await;
+    setup_vault_from_cluster(&mut test, USDC, mock_user.pubkey()).await;
+
+    let (mut banks_client, _, _) = test.start().await;
+
+    let instruction = if pda_creator {
+        initialize_dynamic_amm_customizable_permissionless_pool_pda_creator(
+            JUP,
+            USDC,
+            mock_user.pubkey(),
+            mock_user.pubkey(),
+            INITIAL_TOKEN_AMOUNT,
+            INITIAL_TOKEN_AMOUNT,
+            customizable_params(),
+        )
+    } else {
+        initialize_dynamic_amm_customizable_permissionless_pool(
+            JUP,
+            USDC,
+            mock_user.pubkey(),
+            INITIAL_TOKEN_AMOUNT,
+            INITIAL_TOKEN_AMOUNT,
+            customizable_params(),
+        )
+    };
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            instruction,
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let pool_keys = DynamicAmmPoolKeys::derive_customizable_permissionless(JUP, USDC);
+
+    (banks_client, mock_user, pool_keys)
+}
+
+/// Token amount backing `lp_amount` at the pool token amount and the LP supply
+fn token_amount_of_lp(lp_amount: u64, pool_token_amount: u64, lp_supply: u64) -> u64 {
+    (u128::from(lp_amount) * u128::from(pool_token_amount) / u128::from(lp_supply))
+        .try_into()
+        .unwrap()
+}
+
+fn assert_approx_eq(actual: u64, expected: u64) {
+    // Tolerates the rounding of the pool and the vault conversions
+    assert!(
+        actual.abs_diff(expected) <= 2,
+        "actual {actual} expected {expected}"
+    );
+}
+
+/// Token and LP balances of a liquidity owner
+struct OwnerBalances {
+    pool_lp: u64,
+    token_a: u64,
+    token_b: u64,
+}
+
+impl OwnerBalances {
+    async fn load(
+        banks_client: &mut BanksClient,
+        pool_keys: &DynamicAmmPoolKeys,
+        owner: Pubkey,
+    ) -> Self {
+        Self {
+            pool_lp: get_token_balance(
+                banks_client,
+                get_associated_token_address(&owner, &pool_keys.lp_mint),
+            )
+            .await,
+            token_a: get_token_balance(
+                banks_client,
+                get_associated_token_address(&owner, &pool_keys.token_a_mint),
+            )
+            .await,
+            token_b: get_token_balance(
+                banks_client,
+                get_associated_token_address(&owner, &pool_keys.token_b_mint),
+            )
+            .await,
+        }
+    }
+}
+
+/// Add and then remove 10% of the LP supply, and check the LP minted and burned against the virtual price of the pool
+async fn add_and_remove_balance_liquidity_and_assert(
+    banks_client: &mut BanksClient,
+    mock_user: &Keypair,
+    pool_keys: &DynamicAmmPoolKeys,
+    lp_owner: Pubkey,
+    add_balance_liquidity: impl Fn(u64) -> Instruction,
+    remove_balance_liquidity: impl Fn(u64) -> Instruction,
+) {
+    let pool_info = get_pool_info(banks_client, mock_user, pool_keys).await;
+    let lp_supply = get_mint_supply(banks_client, pool_keys.lp_mint).await;
+    let lp_amount = lp_supply / 10;
+
+    let balances_before = OwnerBalances::load(banks_client, pool_keys, lp_owner).await;
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            add_balance_liquidity(lp_amount),
+        ],
+        mock_user,
+        &[mock_user],
+        banks_client,
+    )
+    .await;
+
+    let balances_after_add = OwnerBalances::load(banks_client, pool_keys, lp_owner).await;
+
+    // Tokens are deposited at the value of the minted LP
+    assert_eq!(
+        balances_after_add.pool_lp - balances_before.pool_lp,
+        lp_amount
+    );
+    assert_approx_eq(
+        balances_before.token_a - balances_after_add.token_a,
+        token_amount_of_lp(lp_amount, pool_info.token_a_amount, lp_supply),
+    );
+    assert_approx_eq(
+        balances_before.token_b - balances_after_add.token_b,
+        token_amount_of_lp(lp_amount, pool_info.token_b_amount, lp_supply),
+    );
+
+    let pool_info_after_add = get_pool_info(banks_client, mock_user, pool_keys).await;
+    assert_eq!(
+        get_mint_supply(banks_client, pool_keys.lp_mint).await,
+        lp_supply + lp_amount
+    );
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            remove_balance_liquidity(lp_amount),
+        ],
+        mock_user,
+        &[mock_user],
+        banks_client,
+    )
+    .await;
+
+    let balances_after_remove = OwnerBalances::load(banks_client, pool_keys, lp_owner).await;
+
+    // Tokens are withdrawn at the value of the burned LP
+    assert_eq!(
+        balances_after_add.pool_lp - balances_after_remove.pool_lp,
+        lp_amount
+    );
+    assert_approx_eq(
+        balances_after_remove.token_a - balances_after_add.token_a,
+        token_amount_of_lp(
+            lp_amount,
+            pool_info_after_add.token_a_amount,
+            lp_supply + lp_amount,
+        ),
+    );
+    assert_approx_eq(
+        balances_after_remove.token_b - balances_after_add.token_b,
+        token_amount_of_lp(
+            lp_amount,
+            pool_info_after_add.token_b_amount,
+            lp_supply + lp_amount,
+        ),
+    );
+
+    // Balanced deposit and withdrawal don't move the virtual price of the pool
+    let pool_info_after_remove = get_pool_info(banks_client, mock_user, pool_keys).await;
+    for virtual_price in [
+        pool_info_after_add.virtual_price,
+        pool_info_after_remove.virtual_price,
+    ] {
+        assert!((virtual_price - pool_info.virtual_price).abs() / pool_info.virtual_price < 1e-6);
+    }
+}
+
+#[tokio::test]
+async fn test_dynamic_amm_balance_liquidity() {
+    let (mut banks_client, mock_user, pool_keys) = setup_pool(false).await;
+
+    add_and_remove_balance_liquidity_and_assert(
+        &mut banks_client,
+        &mock_user,
+        &pool_keys,
+        mock_user.pubkey(),
+        |lp_amount| {
+            dynamic_amm_add_balance_liquidity(
+                &pool_keys,
+                mock_user.pubkey(),
+                lp_amount,
+                u64::MAX,
+                u64::MAX,
+            )
+        },
+        |lp_amount| {
+            dynamic_amm_remove_balance_liquidity(&pool_keys, mock_user.pubkey(), lp_amount, 0, 0)
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_dynamic_amm_balance_liquidity_pda_creator() {
+    let (mut banks_client, mock_user, pool_keys) = setup_pool(true).await;
+    let (creator_authority, _bump) = derive_creator_authority(mock_user.pubkey(), pool_keys.pool);
+
+    // Fund the creator authority PDA with the tokens to deposit
+    let mut instructions = vec![];
+    for mint in [pool_keys.token_a_mint, pool_keys.token_b_mint] {
+        instructions.push(create_associated_token_account_idempotent(
+            &mock_user.pubkey(),
+            &creator_authority,
+            &mint,
+            &anchor_spl::token::ID,
+        ));
+        instructions.push(
+            spl_token::instruction::transfer(
+                &spl_token::ID,
+                &get_associated_token_address(&mock_user.pubkey(), &mint),
+                &get_associated_token_address(&creator_authority, &mint),
+                &mock_user.pubkey(),
+                &[],
+                INITIAL_TOKEN_AMOUNT,
+            )
+            .unwrap(),
+        );
+    }
+
+    process_and_assert_ok(&instructions, &mock_user, &[&mock_user], &mut banks_client).await;
+
+    add_and_remove_balance_liquidity_and_assert(
+        &mut banks_client,
+        &mock_user,
+        &pool_keys,
+        creator_authority,
+        |lp_amount| {
+            dynamic_amm_add_balance_liquidity_pda_creator(
+                &pool_keys,
+                mock_user.pubkey(),
+                mock_user.pubkey(),
+                lp_amount,
+                u64::MAX,
+                u64::MAX,
+            )
+        },
+        |lp_amount| {
+            dynamic_amm_remove_balance_liquidity_pda_creator(
+                &pool_keys,
+                mock_user.pubkey(),
+                mock_user.pubkey(),
+                lp_amount,
+                0,
+                0,
+            )
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_dynamic_amm_balance_liquidity_pda_creator_invalid_admin() {
+    let (mut banks_client, mock_user, pool_keys) = setup_pool(true).await;
+    let non_admin = Keypair::new();
+
+    process_and_assert_err(
+        &[dynamic_amm_remove_balance_liquidity_pda_creator(
+            &pool_keys,
+            mock_user.pubkey(),
+            non_admin.pubkey(),
+            1,
+            0,
+            0,
+        )],
+        &mock_user,
+        &[&mock_user, &non_admin],
+        &mut banks_client,
+        CpiExampleError::InvalidAdmin,
+    )
+    .await;
+}
@@ -0,0 +1,196 @@
+use crate::helpers;
+use anchor_spl::associated_token::get_associated_token_address;
+use cpi_example_client::instructions::*;
+use helpers::dynamic_amm_utils::{setup_constant_product_pool, setup_stable_pool, UserBalances};
+use helpers::*;
+use solana_program_test::*;
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, signature::Keypair, signer::Signer};
+
+// Error code of Dynamic AMM
+const EXCEEDED_SLIPPAGE: u32 = 6004;
+const UNSUPPORTED_OPERATION: u32 = 6025;
+
+const DEPOSIT_AMOUNT: u64 = 1_000_000;
+
+/// Deposit token A only, then withdraw the minted LP as token B only
+async fn add_imbalance_and_remove_single_side_and_assert(
+    banks_client: &mut BanksClient,
+    mock_user: &Keypair,
+    pool_keys: &DynamicAmmPoolKeys,
+) {
+    let balances_before = UserBalances::load(banks_client, pool_keys, mock_user.pubkey()).await;
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dynamic_amm_add_imbalance_liquidity(
+                pool_keys,
+                mock_user.pubkey(),
+                1,
+                DEPOSIT_AMOUNT,
+                0,
+            ),
+        ],
+        mock_user,
+        &[mock_user],
+        banks_client,
+    )
+    .await;
+
+    let balances_after_add = UserBalances::load(banks_client, pool_keys, mock_user.pubkey()).await;
+
+    assert_eq!(
+        balances_before.token_a - balances_after_add.token_a,
+        DEPOSIT_AMOUNT
+    );
+    assert_eq!(balances_before.token_b, balances_after_add.token_b);
+
+    let lp_amount = balances_after_add.pool_lp - balances_before.pool_lp;
+    assert!(lp_amount > 0);
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dynamic_amm_remove_liquidity_single_side(
+                pool_keys,
+                mock_user.pubkey(),
+                pool_keys.token_b_mint,
+                lp_amount,
+                1,
+            ),
+        ],
+        mock_user,
+        &[mock_user],
+        banks_client,
+    )
+    .await;
+
+    let balances_after_remove =
+        UserBalances::load(banks_client, pool_keys, mock_user.pubkey()).await;
+
+    assert_eq!(balances_after_remove.pool_lp, balances_before.pool_lp);
+    assert_eq!(balances_after_remove.token_a, balances_after_add.token_a);
+    assert!(balances_after_remove.token_b > balances_after_add.token_b);
+}
+
+/// Both operations fail when the slippage can't be satisfied
+async fn exceeded_slippage_and_assert(
+    banks_client: &mut BanksClient,
+    mock_user: &Keypair,
+    pool_keys: &DynamicAmmPoolKeys,
+) {
+    process_and_assert_err(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dynamic_amm_add_imbalance_liquidity(
+                pool_keys,
+                mock_user.pubkey(),
+                u64::MAX,
+                DEPOSIT_AMOUNT,
+                0,
+            ),
+        ],
+        mock_user,
+        &[mock_user],
+        banks_client,
+        EXCEEDED_SLIPPAGE,
+    )
+    .await;
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dynamic_amm_add_imbalance_liquidity(
+                pool_keys,
+                mock_user.pubkey(),
+                1,
+                DEPOSIT_AMOUNT,
+                0,
+            ),
+        ],
+        mock_user,
+        &[mock_user],
+        banks_client,
+    )
+    .await;
+
+    let lp_amount = get_token_balance(
+        banks_client,
+        get_associated_token_address(&mock_user.pubkey(), &pool_keys.lp_mint),
+    )
+    .await;
+
+    process_and_assert_err(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dynamic_amm_remove_liquidity_single_side(
+                pool_keys,
+                mock_user.pubkey(),
+                pool_keys.token_a_mint,
+                lp_amount,
+                u64::MAX,
+            ),
+        ],
+        mock_user,
+        &[mock_user],
+        banks_client,
+        EXCEEDED_SLIPPAGE,
+    )
+    .await;
+}
+
+/// Imbalance deposit and single side withdrawal are only supported by the stable pools of Dynamic AMM
+#[tokio::test]
+async fn test_dynamic_amm_imbalance_liquidity_constant_product_pool_unsupported() {
+    let (mut banks_client, mock_user, pool_keys) = setup_constant_product_pool().await;
+
+    process_and_assert_err(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dynamic_amm_add_imbalance_liquidity(
+                &pool_keys,
+                mock_user.pubkey(),
+                1,
+                DEPOSIT_AMOUNT,
+                0,
+            ),
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        UNSUPPORTED_OPERATION,
+    )
+    .await;
+
+    // The mock user holds the LP minted upon pool creation
+    process_and_assert_err(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dynamic_amm_remove_liquidity_single_side(
+                &pool_keys,
+                mock_user.pubkey(),
+                pool_keys.token_b_mint,
+                DEPOSIT_AMOUNT,
+                1,
+            ),
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        UNSUPPORTED_OPERATION,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_dynamic_amm_imbalance_liquidity_stable_pool() {
+    let (mut banks_client, mock_user, pool_keys) = setup_stable_pool().await;
+    add_imbalance_and_remove_single_side_and_assert(&mut banks_client, &mock_user, &pool_keys)
+        .await;
+}
+
+#[tokio::test]
+async fn test_dynamic_amm_imbalance_liquidity_exceeded_slippage_stable_pool() {
+    let (mut banks_client, mock_user, pool_keys) = setup_stable_pool().await;
+    exceeded_slippage_and_assert(&mut banks_client, &mock_user, &pool_keys).await;
+}
@@ -1,17 +1,38 @@
 use crate::helpers;
-use anchor_lang::{solana_program::pubkey::Pubkey, InstructionData, ToAccountMetas};
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address;
+use cpi_example::errors::CpiExampleError;
+use cpi_example::state::{IntegratorFee, IntegratorFeeMode, SwapResult};
+use cpi_example_client::instructions::*;
 use helpers::dynamic_amm_utils::{setup_pool_from_cluster, PoolSetupContext};
-use helpers::{process_and_assert_ok, setup_cpi_example_program};
+use helpers::{
+    get_token_balance, process_and_assert_err, process_and_assert_ok, process_and_get_return_data,
+    setup_cpi_example_program,
+};
 use solana_program_test::*;
 use solana_sdk::{
     compute_budget::ComputeBudgetInstruction, instruction::Instruction, signature::Keypair,
     signer::Signer,
 };
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
 
 const USDC_USDT_POOL: Pubkey = solana_sdk::pubkey!("32D4zRxNc1EssbJieVHfPhZM3rH6CzfUPrWUuWxD9prG");
 
-#[tokio::test]
-async fn test_dynamic_amm_swap() {
+const CONSTRAINT_HAS_ONE: u32 = 2001;
+const CONSTRAINT_ADDRESS: u32 = 2012;
+
+const SWAP_AMOUNT_IN: u64 = 1_000_000;
+const INTEGRATOR_FEE_BPS: u16 = 50;
+
+struct SwapContext {
+    banks_client: BanksClient,
+    mock_user: Keypair,
+    pool_keys: DynamicAmmPoolKeys,
+    user_token_a: Pubkey,
+    user_token_b: Pubkey,
+}
+
+async fn setup_swap() -> SwapContext {
     let mock_user = Keypair::new();
 
     let mut test = setup_cpi_example_program();
@@ -28,41 +49,141 @@ async fn test_dynamic_amm_swap() {
         user_token_b,
     } = setup_pool_from_cluster(&mut test, USDC_USDT_POOL, mock_user.pubkey()).await;
 
-    let (mut banks_client, _, _) = test.start().await;
+    let (banks_client, _, _) = test.start().await;
 
-    let ix_data = cpi_example::instruction::DynamicAmmSwap {
-        amount_in: 1_000_000,
-        min_amount_out: 0,
-    }
-    .data();
-
-    let accounts = cpi_example::accounts::DynamicAmmSwap {
-        pool: USDC_USDT_POOL,
-        a_vault: pool_state.a_vault,
-        b_vault: pool_state.b_vault,
-        a_token_vault: a_vault_state.token_vault,
-        b_token_vault: b_vault_state.token_vault,
-        a_vault_lp: pool_state.a_vault_lp,
-        b_vault_lp: pool_state.b_vault_lp,
-        a_vault_lp_mint: a_vault_state.lp_mint,
-        b_vault_lp_mint: b_vault_state.lp_mint,
-        protocol_token_fee: pool_state.protocol_token_a_fee,
-        user_source_token: user_token_a,
-        user_destination_token: user_token_b,
-        user: mock_user.pubkey(),
-        dynamic_amm_program: cpi_example::dynamic_amm::ID,
-        vault_program: cpi_example::dynamic_vault::ID,
-        token_program: anchor_spl::token::ID,
+    let pool_keys =
+        DynamicAmmPoolKeys::new(USDC_USDT_POOL, &pool_state, &a_vault_state, &b_vault_state);
+
+    SwapContext {
+        banks_client,
+        mock_user,
+        pool_keys,
+        user_token_a,
+        user_token_b,
     }
-    .to_account_metas(None);
+}
+
+/// Swap and check the return data against the balance changes of the user token accounts
+async fn swap_and_assert_return_data(
+    banks_client: &mut BanksClient,
+    mock_user: &Keypair,
+    instruction: Instruction,
+    user_source_token: Pubkey,
+    user_destination_token: Pubkey,
+) {
+    let user_source_token_before = get_token_balance(banks_client, user_source_token).await;
+    let user_destination_token_before =
+        get_token_balance(banks_client, user_destination_token).await;
+
+    let swap_result: SwapResult = process_and_get_return_data(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            instruction,
+        ],
+        mock_user,
+        &[mock_user],
+        banks_client,
+    )
+    .await;
+
+    let user_source_token_after = get_token_balance(banks_client, user_source_token).await;
+    let user_destination_token_after =
+        get_token_balance(banks_client, user_destination_token).await;
+
+    assert_eq!(
+        user_source_token_before - user_source_token_after,
+        swap_result.amount_in
+    );
+    assert_eq!(
+        user_destination_token_after - user_destination_token_before,
+        swap_result.amount_out
+    );
+    assert!(swap_result.amount_out > 0);
+}
 
-    let instruction = Instruction {
-        program_id: cpi_example::id(),
-        data: ix_data,
-        accounts,
+#[tokio::test]
+async fn test_dynamic_amm_swap() {
+    let SwapContext {
+        mut banks_client,
+        mock_user,
+        pool_keys,
+        user_token_a,
+        user_token_b,
+    } = setup_swap().await;
+
+    let instruction = dynamic_amm_swap(
+        &pool_keys,
+        mock_user.pubkey(),
+        user_token_a,
+        user_token_b,
+        true,
+        1_000_000,
+        0,
+        None,
+    );
+
+    swap_and_assert_return_data(
+        &mut banks_client,
+        &mock_user,
+        instruction,
+        user_token_a,
+        user_token_b,
+    )
+    .await;
+}
+
+/// Swap token A to token B with the integrator fee. Returns the swap result, and the balance changes of the user token
+/// accounts and the integrator fee token account.
+async fn swap_with_integrator_fee(mode: IntegratorFeeMode) -> (SwapResult, u64, u64, u64) {
+    let SwapContext {
+        mut banks_client,
+        mock_user,
+        pool_keys,
+        user_token_a,
+        user_token_b,
+    } = setup_swap().await;
+
+    let charged_mint = match mode {
+        IntegratorFeeMode::Input => pool_keys.token_a_mint,
+        IntegratorFeeMode::Output => pool_keys.token_b_mint,
     };
+    let integrator = Pubkey::new_unique();
+    let integrator_fee_token = get_associated_token_address(&integrator, &charged_mint);
 
     process_and_assert_ok(
+        &[create_associated_token_account_idempotent(
+            &mock_user.pubkey(),
+            &integrator,
+            &charged_mint,
+            &anchor_spl::token::ID,
+        )],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let user_token_a_before = get_token_balance(&mut banks_client, user_token_a).await;
+    let user_token_b_before = get_token_balance(&mut banks_client, user_token_b).await;
+
+    let instruction = dynamic_amm_swap(
+        &pool_keys,
+        mock_user.pubkey(),
+        user_token_a,
+        user_token_b,
+        true,
+        SWAP_AMOUNT_IN,
+        0,
+        Some((
+            IntegratorFee {
+                fee_bps: INTEGRATOR_FEE_BPS,
+                mode,
+            },
+            integrator_fee_token,
+        )),
+    );
+
+    let swap_result: SwapResult = process_and_get_return_data(
         &[
             ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
             instruction,
@@ -72,4 +193,147 @@ async fn test_dynamic_amm_swap() {
         &mut banks_client,
     )
     .await;
+
+    let user_token_a_after = get_token_balance(&mut banks_client, user_token_a).await;
+    let user_token_b_after = get_token_balance(&mut banks_client, user_token_b).await;
+
+    (
+        swap_result,
+        user_token_a_before - user_token_a_after,
+        user_token_b_after - user_token_b_before,
+        get_token_balance(&mut banks_client, integrator_fee_token).await,
+    )
+}
+
+#[tokio::test]
+async fn test_dynamic_amm_swap_integrator_fee_input() {
+    let (swap_result, user_token_a_sent, user_token_b_received, integrator_fee_received) =
+        swap_with_integrator_fee(IntegratorFeeMode::Input).await;
+
+    assert_eq!(swap_result.amount_in, SWAP_AMOUNT_IN);
+    assert_eq!(user_token_a_sent, SWAP_AMOUNT_IN);
+    assert_eq!(
+        integrator_fee_received,
+        SWAP_AMOUNT_IN * u64::from(INTEGRATOR_FEE_BPS) / 10_000
+    );
+    assert!(swap_result.amount_out > 0);
+    assert_eq!(user_token_b_received, swap_result.amount_out);
+}
+
+#[tokio::test]
+async fn test_dynamic_amm_swap_integrator_fee_output() {
+    let (swap_result, user_token_a_sent, user_token_b_received, integrator_fee_received) =
+        swap_with_integrator_fee(IntegratorFeeMode::Output).await;
+
+    let swap_amount_out = user_token_b_received + integrator_fee_received;
+
+    assert_eq!(swap_result.amount_in, SWAP_AMOUNT_IN);
+    assert_eq!(user_token_a_sent, SWAP_AMOUNT_IN);
+    assert!(integrator_fee_received > 0);
+    assert_eq!(
+        integrator_fee_received,
+        swap_amount_out * u64::from(INTEGRATOR_FEE_BPS) / 10_000
+    );
+    assert_eq!(user_token_b_received, swap_result.amount_out);
+}
+
+#[tokio::test]
+async fn test_dynamic_amm_swap_strict() {
+    let SwapContext {
+        mut banks_client,
+        mock_user,
+        pool_keys,
+        user_token_a,
+        user_token_b,
+    } = setup_swap().await;
+
+    let instruction = dynamic_amm_swap_strict(
+        &pool_keys,
+        mock_user.pubkey(),
+        user_token_b,
+        user_token_a,
+        false,
+        1_000_000,
+        0,
+    );
+
+    swap_and_assert_return_data(
+        &mut banks_client,
+        &mock_user,
+        instruction,
+        user_token_b,
+        user_token_a,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_dynamic_amm_swap_strict_invalid_accounts() {
+    let SwapContext {
+        mut banks_client,
+        mock_user,
+        pool_keys,
+        user_token_a,
+        user_token_b,
+    } = setup_swap().await;
+
+    let instruction = dynamic_amm_swap_strict(
+        &pool_keys,
+        mock_user.pubkey(),
+        user_token_a,
+        user_token_b,
+        true,
+        1_000_000,
+        0,
+    );
+
+    let invalid_cases = [
+        // Vaults swapped
+        (
+            vec![
+                (pool_keys.a_vault, pool_keys.b_vault),
+                (pool_keys.b_vault, pool_keys.a_vault),
+            ],
+            CONSTRAINT_HAS_ONE,
+        ),
+        // Vault LP swapped
+        (
+            vec![
+                (pool_keys.a_vault_lp, pool_keys.b_vault_lp),
+                (pool_keys.b_vault_lp, pool_keys.a_vault_lp),
+            ],
+            CONSTRAINT_HAS_ONE,
+        ),
+        // Protocol fee token account not owned by the pool
+        (
+            vec![(pool_keys.protocol_token_a_fee, user_token_b)],
+            u32::from(CpiExampleError::InvalidProtocolTokenFee),
+        ),
+        // Unknown vault program
+        (
+            vec![(cpi_example::dynamic_vault::ID, Pubkey::new_unique())],
+            CONSTRAINT_ADDRESS,
+        ),
+    ];
+
+    for (replacements, error_code) in invalid_cases {
+        let mut invalid_instruction = instruction.clone();
+        for account in invalid_instruction.accounts.iter_mut() {
+            if let Some((_, to)) = replacements
+                .iter()
+                .find(|(from, _)| *from == account.pubkey)
+            {
+                account.pubkey = *to;
+            }
+        }
+
+        process_and_assert_err(
+            &[invalid_instruction],
+            &mock_user,
+            &[&mock_user],
+            &mut banks_client,
+            error_code,
+        )
+        .await;
+    }
 }
@@ -0,0 +1,124 @@
+use crate::helpers;
+use anchor_spl::associated_token::get_associated_token_address;
+use cpi_example::errors::CpiExampleError;
+use cpi_example_client::dynamic_amm_pda::derive_lock_escrow_key;
+use cpi_example_client::instructions::*;
+use helpers::dynamic_amm_utils::{setup_constant_product_pool, setup_stable_pool, UserBalances};
+use helpers::*;
+use solana_program_test::*;
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, signature::Keypair, signer::Signer};
+
+const ZAP_IN_AMOUNT: u64 = 1_000_000;
+
+/// Zap in, and check that only dust of the zapped token is left in the user token accounts
+async fn zap_in_and_assert(
+    banks_client: &mut BanksClient,
+    mock_user: &Keypair,
+    pool_keys: &DynamicAmmPoolKeys,
+    a_to_b: bool,
+    lock: bool,
+) {
+    let balances_before = UserBalances::load(banks_client, pool_keys, mock_user.pubkey()).await;
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dynamic_amm_zap_in(
+                pool_keys,
+                mock_user.pubkey(),
+                a_to_b,
+                ZAP_IN_AMOUNT,
+                1,
+                lock,
+            ),
+        ],
+        mock_user,
+        &[mock_user],
+        banks_client,
+    )
+    .await;
+
+    let balances_after = UserBalances::load(banks_client, pool_keys, mock_user.pubkey()).await;
+
+    let (token_in_before, token_in_after, token_out_before, token_out_after) = if a_to_b {
+        (
+            balances_before.token_a,
+            balances_after.token_a,
+            balances_before.token_b,
+            balances_after.token_b,
+        )
+    } else {
+        (
+            balances_before.token_b,
+            balances_after.token_b,
+            balances_before.token_a,
+            balances_after.token_a,
+        )
+    };
+
+    // Both pools are around 1:1, so the leftover of both sides are compared against the zapped amount
+    let dust = ZAP_IN_AMOUNT / 1_000;
+    let leftover_in = ZAP_IN_AMOUNT - (token_in_before - token_in_after);
+    let leftover_out = token_out_after - token_out_before;
+    assert!(leftover_in <= dust, "leftover input {leftover_in}");
+    assert!(leftover_out <= dust, "leftover output {leftover_out}");
+
+    if lock {
+        let lock_escrow = derive_lock_escrow_key(pool_keys.pool, mock_user.pubkey());
+        let escrow_vault = get_associated_token_address(&lock_escrow, &pool_keys.lp_mint);
+
+        assert_eq!(balances_after.pool_lp, balances_before.pool_lp);
+        assert!(get_token_balance(banks_client, escrow_vault).await > 0);
+    } else {
+        assert!(balances_after.pool_lp > balances_before.pool_lp);
+    }
+}
+
+#[tokio::test]
+async fn test_dynamic_amm_zap_in_constant_product_pool() {
+    let (mut banks_client, mock_user, pool_keys) = setup_constant_product_pool().await;
+
+    zap_in_and_assert(&mut banks_client, &mock_user, &pool_keys, true, false).await;
+    zap_in_and_assert(&mut banks_client, &mock_user, &pool_keys, false, false).await;
+}
+
+#[tokio::test]
+async fn test_dynamic_amm_zap_in_stable_pool() {
+    let (mut banks_client, mock_user, pool_keys) = setup_stable_pool().await;
+
+    zap_in_and_assert(&mut banks_client, &mock_user, &pool_keys, true, false).await;
+    zap_in_and_assert(&mut banks_client, &mock_user, &pool_keys, false, false).await;
+}
+
+#[tokio::test]
+async fn test_dynamic_amm_zap_in_and_lock() {
+    let (mut banks_client, mock_user, pool_keys) = setup_constant_product_pool().await;
+
+    // The lock escrow is created by the first zap, and reused by the second zap
+    zap_in_and_assert(&mut banks_client, &mock_user, &pool_keys, true, true).await;
+    zap_in_and_assert(&mut banks_client, &mock_user, &pool_keys, false, true).await;
+}
+
+#[tokio::test]
+async fn test_dynamic_amm_zap_in_exceeded_slippage() {
+    let (mut banks_client, mock_user, pool_keys) = setup_constant_product_pool().await;
+
+    process_and_assert_err(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dynamic_amm_zap_in(
+                &pool_keys,
+                mock_user.pubkey(),
+                true,
+                ZAP_IN_AMOUNT,
+                u64::MAX,
+                false,
+            ),
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        CpiExampleError::ExceededLpSlippage,
+    )
+    .await;
+}
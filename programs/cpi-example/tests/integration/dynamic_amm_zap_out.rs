@@ -0,0 +1,180 @@
+use crate::helpers;
+use cpi_example::errors::CpiExampleError;
+use cpi_example_client::instructions::*;
+use helpers::dynamic_amm_utils::{setup_constant_product_pool, setup_stable_pool, UserBalances};
+use helpers::*;
+use solana_program_test::*;
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, signature::Keypair, signer::Signer};
+
+const ZAP_OUT_LP_AMOUNT: u64 = 1_000_000;
+const DEPOSIT_AMOUNT: u64 = 1_000_000;
+
+/// Zap out, and check that only the output token is received. Returns the amount of output token received.
+async fn zap_out_and_assert(
+    banks_client: &mut BanksClient,
+    mock_user: &Keypair,
+    pool_keys: &DynamicAmmPoolKeys,
+    a_to_b: bool,
+    pool_token_amount: u64,
+) -> u64 {
+    let balances_before = UserBalances::load(banks_client, pool_keys, mock_user.pubkey()).await;
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dynamic_amm_zap_out(pool_keys, mock_user.pubkey(), a_to_b, pool_token_amount, 1),
+        ],
+        mock_user,
+        &[mock_user],
+        banks_client,
+    )
+    .await;
+
+    let balances_after = UserBalances::load(banks_client, pool_keys, mock_user.pubkey()).await;
+
+    assert_eq!(
+        balances_before.pool_lp - balances_after.pool_lp,
+        pool_token_amount
+    );
+
+    let (token_in_before, token_in_after, token_out_before, token_out_after) = if a_to_b {
+        (
+            balances_before.token_a,
+            balances_after.token_a,
+            balances_before.token_b,
+            balances_after.token_b,
+        )
+    } else {
+        (
+            balances_before.token_b,
+            balances_after.token_b,
+            balances_before.token_a,
+            balances_after.token_a,
+        )
+    };
+
+    // The withdrawn token of the other side is swapped entirely
+    assert_eq!(token_in_before, token_in_after);
+
+    let amount_out = token_out_after - token_out_before;
+    assert!(amount_out > 0);
+
+    amount_out
+}
+
+/// Setup the stable pool, where the mock user holds the LP minted by depositing both tokens
+async fn setup_stable_pool_with_liquidity() -> (BanksClient, Keypair, DynamicAmmPoolKeys, u64) {
+    let (mut banks_client, mock_user, pool_keys) = setup_stable_pool().await;
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dynamic_amm_add_imbalance_liquidity(
+                &pool_keys,
+                mock_user.pubkey(),
+                1,
+                DEPOSIT_AMOUNT,
+                DEPOSIT_AMOUNT,
+            ),
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let lp_amount = UserBalances::load(&mut banks_client, &pool_keys, mock_user.pubkey())
+        .await
+        .pool_lp;
+
+    (banks_client, mock_user, pool_keys, lp_amount)
+}
+
+#[tokio::test]
+async fn test_dynamic_amm_zap_out_constant_product_pool() {
+    let (mut banks_client, mock_user, pool_keys) = setup_constant_product_pool().await;
+
+    zap_out_and_assert(
+        &mut banks_client,
+        &mock_user,
+        &pool_keys,
+        true,
+        ZAP_OUT_LP_AMOUNT,
+    )
+    .await;
+    zap_out_and_assert(
+        &mut banks_client,
+        &mock_user,
+        &pool_keys,
+        false,
+        ZAP_OUT_LP_AMOUNT,
+    )
+    .await;
+}
+
+/// Zap out and single side withdrawal of the same LP from the same pool state
+#[tokio::test]
+async fn test_dynamic_amm_zap_out_compared_to_remove_liquidity_single_side() {
+    let (mut banks_client, mock_user, pool_keys, lp_amount) =
+        setup_stable_pool_with_liquidity().await;
+
+    let zap_out_amount =
+        zap_out_and_assert(&mut banks_client, &mock_user, &pool_keys, true, lp_amount).await;
+
+    let (mut banks_client, mock_user, pool_keys, lp_amount) =
+        setup_stable_pool_with_liquidity().await;
+
+    let balances_before =
+        UserBalances::load(&mut banks_client, &pool_keys, mock_user.pubkey()).await;
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dynamic_amm_remove_liquidity_single_side(
+                &pool_keys,
+                mock_user.pubkey(),
+                pool_keys.token_b_mint,
+                lp_amount,
+                1,
+            ),
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let balances_after =
+        UserBalances::load(&mut banks_client, &pool_keys, mock_user.pubkey()).await;
+    let single_side_amount = balances_after.token_b - balances_before.token_b;
+
+    // Both routes end around the deposited value, as the stable pool trades around 1:1
+    let difference = zap_out_amount.abs_diff(single_side_amount);
+    assert!(
+        difference <= single_side_amount / 100,
+        "zap out {zap_out_amount}, single side {single_side_amount}"
+    );
+}
+
+#[tokio::test]
+async fn test_dynamic_amm_zap_out_exceeded_slippage() {
+    let (mut banks_client, mock_user, pool_keys) = setup_constant_product_pool().await;
+
+    process_and_assert_err(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dynamic_amm_zap_out(
+                &pool_keys,
+                mock_user.pubkey(),
+                true,
+                ZAP_OUT_LP_AMOUNT,
+                u64::MAX,
+            ),
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        CpiExampleError::ExceededSlippage,
+    )
+    .await;
+}
@@ -0,0 +1,152 @@
+use crate::helpers;
+use anchor_spl::associated_token::get_associated_token_address;
+use cpi_example::dlmm::types::{
+    LiquidityParameterByStrategyOneSide, StrategyParameters, StrategyType,
+};
+use cpi_example::events::{EvtClaimFee, EvtSwap};
+use cpi_example::state::SwapResult;
+use cpi_example_client::dlmm_pda::derive_bin_array_pda;
+use cpi_example_client::events::CpiExampleEvent;
+use cpi_example_client::instructions::*;
+use helpers::dlmm_launch_pool_utils::*;
+use helpers::{
+    get_token_balance, process_and_assert_ok, process_and_get_return_data, simulate_and_get_events,
+};
+use solana_program_test::*;
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, signer::Signer};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+const ACTIVE_ID: i32 = 0;
+const POSITION_WIDTH: i32 = 70;
+
+#[tokio::test]
+async fn test_dlmm_swap_and_claim_fee_events() {
+    let (mut banks_client, mock_user, launch_pool_context) =
+        setup_launch_pool(ACTIVE_ID, &[0, 1]).await;
+    let pool_keys = &launch_pool_context.pool_keys;
+
+    // Token X is deposited above the active bin, to be bought with token Y
+    let lower_bin_id = ACTIVE_ID + 1;
+    let position_keys = DlmmPositionKeys::derive_pda_creator(
+        pool_keys.lb_pair,
+        mock_user.pubkey(),
+        lower_bin_id,
+        POSITION_WIDTH,
+    );
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dlmm_initialize_position_pda_creator(&position_keys, mock_user.pubkey()),
+            dlmm_add_liquidity_by_strategy_one_side_pda_creator(
+                pool_keys,
+                &position_keys,
+                mock_user.pubkey(),
+                pool_keys.token_x_mint,
+                LiquidityParameterByStrategyOneSide {
+                    amount: 1_000_000_000,
+                    active_id: ACTIVE_ID,
+                    max_active_bin_slippage: 0,
+                    strategy_parameters: StrategyParameters {
+                        min_bin_id: lower_bin_id,
+                        max_bin_id: lower_bin_id + POSITION_WIDTH - 1,
+                        strategy_type: StrategyType::SpotOneSide,
+                        parameteres: [0u8; 64],
+                    },
+                },
+            ),
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let swap_instructions = [
+        ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+        dlmm_swap(
+            pool_keys,
+            mock_user.pubkey(),
+            launch_pool_context.user_token_y,
+            launch_pool_context.user_token_x,
+            &[
+                derive_bin_array_pda(pool_keys.lb_pair, 0).0,
+                derive_bin_array_pda(pool_keys.lb_pair, 1).0,
+            ],
+            1_000_000,
+            0,
+            None,
+        ),
+    ];
+
+    let events = simulate_and_get_events(
+        &swap_instructions,
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let swap_result: SwapResult = process_and_get_return_data(
+        &swap_instructions,
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    assert_eq!(
+        events,
+        vec![CpiExampleEvent::Swap(EvtSwap {
+            pool: pool_keys.lb_pair,
+            user: mock_user.pubkey(),
+            input_mint: pool_keys.token_y_mint,
+            output_mint: pool_keys.token_x_mint,
+            amount_in: swap_result.amount_in,
+            amount_out: swap_result.amount_out,
+        })]
+    );
+
+    // The swap fee in token Y is claimed by the creator authority
+    let creator_token_y =
+        get_associated_token_address(&position_keys.creator_authority, &pool_keys.token_y_mint);
+
+    let claim_fee_instructions = [
+        create_associated_token_account_idempotent(
+            &mock_user.pubkey(),
+            &position_keys.creator_authority,
+            &pool_keys.token_y_mint,
+            &anchor_spl::token::ID,
+        ),
+        dlmm_claim_fee(pool_keys, &position_keys, mock_user.pubkey()),
+    ];
+
+    let events = simulate_and_get_events(
+        &claim_fee_instructions,
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    process_and_assert_ok(
+        &claim_fee_instructions,
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let creator_token_y_amount = get_token_balance(&mut banks_client, creator_token_y).await;
+    assert!(creator_token_y_amount > 0);
+
+    assert_eq!(
+        events,
+        vec![CpiExampleEvent::ClaimFee(EvtClaimFee {
+            pool: pool_keys.lb_pair,
+            owner: position_keys.creator_authority,
+            token_a_amount: 0,
+            token_b_amount: creator_token_y_amount,
+        })]
+    );
+}
@@ -0,0 +1,128 @@
+use super::dlmm_utils::*;
+use super::{
+    add_packable_account, add_program_config, process_and_assert_ok, setup_cpi_example_program,
+    USDC,
+};
+use anchor_lang::{solana_program::pubkey::Pubkey, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token::state::{Account as TokenAccountState, AccountState, Mint};
+use cpi_example::dlmm;
+use cpi_example::dlmm::types::CustomizableParams;
+use cpi_example_client::dlmm_pda::*;
+use cpi_example_client::instructions::*;
+use solana_program_test::BanksClient;
+use solana_sdk::{
+    account::Account, compute_budget::ComputeBudgetInstruction, instruction::Instruction,
+    signature::Keypair, signer::Signer,
+};
+
+/// Pool created locally with creator PDA. Token X is a new token, token Y is USDC.
+pub struct LaunchPoolContext {
+    pub pool_keys: DlmmPoolKeys,
+    pub user_token_x: Pubkey,
+    pub user_token_y: Pubkey,
+}
+
+/// Create the launch pool with creator PDA, and initialize the bin arrays of the given indexes besides the active bin array
+pub async fn setup_launch_pool(
+    active_id: i32,
+    bin_array_indexes: &[i64],
+) -> (BanksClient, Keypair, LaunchPoolContext) {
+    let mock_user = Keypair::new();
+
+    let mut test = setup_cpi_example_program();
+    add_program_config(&mut test, mock_user.pubkey());
+
+    test.prefer_bpf(true);
+    test.add_program("dlmm", dlmm::ID, None);
+
+    let token_mint_x = Pubkey::new_unique();
+    let token_mint_y = USDC;
+
+    test.add_account(
+        mock_user.pubkey(),
+        Account {
+            lamports: u32::MAX.into(),
+            data: vec![],
+            owner: solana_sdk::system_program::ID,
+            ..Default::default()
+        },
+    );
+
+    for mint in [token_mint_x, token_mint_y] {
+        let mint_state = Mint {
+            decimals: 6,
+            supply: u64::MAX,
+            is_initialized: true,
+            ..Default::default()
+        };
+        add_packable_account(&mut test, mint_state, anchor_spl::token::ID, mint);
+
+        let token_state = TokenAccountState {
+            mint,
+            owner: mock_user.pubkey(),
+            amount: u64::MAX / 2,
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+        add_packable_account(
+            &mut test,
+            token_state,
+            anchor_spl::token::ID,
+            get_associated_token_address(&mock_user.pubkey(), &mint),
+        );
+    }
+
+    let (mut banks_client, _, _) = test.start().await;
+
+    let pool_keys = DlmmPoolKeys::derive_customizable_permissionless(token_mint_x, token_mint_y);
+    let lb_pair = pool_keys.lb_pair;
+    let active_bin_array_index: i64 = bin_id_to_bin_array_index(active_id).unwrap().into();
+
+    let mut instructions = vec![
+        ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+        dlmm_initialize_customizable_permissionless_lb_pair_pda_creator(
+            &pool_keys,
+            mock_user.pubkey(),
+            mock_user.pubkey(),
+            CustomizableParams {
+                active_id,
+                bin_step: 10,
+                base_factor: 10_000,
+                activation_type: 0,
+                has_alpha_vault: false,
+                activation_point: None,
+                padding: [0u8; 64],
+            },
+        ),
+    ];
+
+    for index in bin_array_indexes
+        .iter()
+        .filter(|index| **index != active_bin_array_index)
+    {
+        let accounts = dlmm::client::accounts::InitializeBinArray {
+            lb_pair,
+            bin_array: derive_bin_array_pda(lb_pair, *index).0,
+            funder: mock_user.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None);
+
+        instructions.push(Instruction {
+            program_id: dlmm::ID,
+            data: dlmm::client::args::InitializeBinArray { index: *index }.data(),
+            accounts,
+        });
+    }
+
+    process_and_assert_ok(&instructions, &mock_user, &[&mock_user], &mut banks_client).await;
+
+    let launch_pool_context = LaunchPoolContext {
+        pool_keys,
+        user_token_x: get_associated_token_address(&mock_user.pubkey(), &token_mint_x),
+        user_token_y: get_associated_token_address(&mock_user.pubkey(), &token_mint_y),
+    };
+
+    (banks_client, mock_user, launch_pool_context)
+}
@@ -0,0 +1,92 @@
+use super::dlmm_utils::*;
+use super::process_and_assert_ok;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use cpi_example::dlmm::types::{LiquidityParameterByStrategy, StrategyParameters, StrategyType};
+use cpi_example_client::instructions::*;
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+pub const POSITION_WIDTH: i32 = 70;
+
+/// Position centered around the active bin. The position always spans 2 bin arrays.
+pub fn derive_position_keys(lb_pair: Pubkey, owner: Pubkey, active_id: i32) -> DlmmPositionKeys {
+    let mut lower_bin_id = active_id - POSITION_WIDTH / 2;
+    if bin_id_to_bin_array_index(lower_bin_id)
+        == bin_id_to_bin_array_index(lower_bin_id + POSITION_WIDTH - 1)
+    {
+        lower_bin_id -= 1;
+    }
+
+    DlmmPositionKeys::derive_pda_creator(lb_pair, owner, lower_bin_id, POSITION_WIDTH)
+}
+
+/// Load the bin arrays of the position which are not loaded by setup_pool_from_cluster
+pub async fn setup_position_bin_arrays_from_cluster(
+    test: &mut ProgramTest,
+    position_keys: &DlmmPositionKeys,
+    active_id: i32,
+) {
+    let active_bin_array_idx = bin_id_to_bin_array_index(active_id).unwrap();
+    for bin_id in [
+        position_keys.lower_bin_id,
+        position_keys.lower_bin_id + position_keys.width - 1,
+    ] {
+        let bin_array_idx = bin_id_to_bin_array_index(bin_id).unwrap();
+        if bin_array_idx != active_bin_array_idx {
+            setup_bin_array_from_cluster(test, position_keys.lb_pair, bin_array_idx.into()).await;
+        }
+    }
+}
+
+/// Initialize the position and deposit around the active bin
+pub async fn initialize_position_and_add_liquidity(
+    banks_client: &mut BanksClient,
+    mock_user: &Keypair,
+    pool_keys: &DlmmPoolKeys,
+    position_keys: &DlmmPositionKeys,
+    active_id: i32,
+    amount_x: u64,
+    amount_y: u64,
+) {
+    process_and_assert_ok(
+        &[dlmm_initialize_position_pda_creator(
+            position_keys,
+            mock_user.pubkey(),
+        )],
+        mock_user,
+        &[mock_user],
+        banks_client,
+    )
+    .await;
+
+    let liquidity_parameter = LiquidityParameterByStrategy {
+        amount_x,
+        amount_y,
+        active_id,
+        max_active_bin_slippage: 0,
+        strategy_parameters: StrategyParameters {
+            min_bin_id: active_id - 5,
+            max_bin_id: active_id + 5,
+            strategy_type: StrategyType::SpotBalanced,
+            parameteres: [0u8; 64],
+        },
+    };
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            dlmm_add_liquidity_by_strategy_pda_creator(
+                pool_keys,
+                position_keys,
+                mock_user.pubkey(),
+                liquidity_parameter,
+            ),
+        ],
+        mock_user,
+        &[mock_user],
+        banks_client,
+    )
+    .await;
+}
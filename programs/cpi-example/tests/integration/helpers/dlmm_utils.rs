@@ -1,16 +1,15 @@
-use super::dlmm_pda::*;
 use anchor_lang::prelude::Pubkey;
 use anchor_lang::{AccountDeserialize, Discriminator};
 use anchor_spl::associated_token::get_associated_token_address;
 use anchor_spl::token::spl_token::state::AccountState;
 use cpi_example::dlmm::accounts::LbPair;
 use cpi_example::dlmm::types::{ProtocolFee, RewardInfo, StaticParameters, VariableParameters};
-use solana_client::nonblocking::rpc_client::RpcClient;
+use cpi_example_client::dlmm_pda::*;
 use solana_program_test::ProgramTest;
 use solana_sdk::account::Account;
 
+use super::fixtures::add_cluster_account;
 use super::utils::add_packable_account;
-use super::RPC;
 
 struct BorshLbPairWrapper(LbPair);
 
@@ -162,6 +161,11 @@ pub fn bin_id_to_bin_array_index(bin_id: i32) -> Option<i32> {
     }
 }
 
+pub async fn setup_bin_array_from_cluster(test: &mut ProgramTest, pool: Pubkey, index: i64) {
+    let (bin_array_key, _bump) = derive_bin_array_pda(pool, index);
+    add_cluster_account(test, bin_array_key).await;
+}
+
 pub struct PoolSetupContext {
     pub pool_state: LbPair,
     pub user_token_x: Pubkey,
@@ -173,41 +177,26 @@ pub async fn setup_pool_from_cluster(
     pool: Pubkey,
     mock_user: Pubkey,
 ) -> PoolSetupContext {
-    let rpc_client = RpcClient::new(RPC.to_owned());
-
-    let pool_account = rpc_client.get_account(&pool).await.unwrap();
+    let pool_account = add_cluster_account(test, pool).await;
     let pool_state = BorshLbPairWrapper::try_deserialize(&mut pool_account.data.as_ref())
         .unwrap()
         .0;
 
-    test.add_account(pool, pool_account);
-
     let (oracle_key, _bump) = derive_oracle_pda(pool);
-    let oracle_account = rpc_client.get_account(&oracle_key).await.unwrap();
-    test.add_account(oracle_key, oracle_account);
+    add_cluster_account(test, oracle_key).await;
 
     let active_bin_array_idx = bin_id_to_bin_array_index(pool_state.active_id).unwrap();
-    let (active_bin_array_key, _bump) = derive_bin_array_pda(pool, active_bin_array_idx.into());
-
-    let bin_array_account = rpc_client.get_account(&active_bin_array_key).await.unwrap();
-    test.add_account(active_bin_array_key, bin_array_account);
+    setup_bin_array_from_cluster(test, pool, active_bin_array_idx.into()).await;
 
     let mint_keys = vec![pool_state.token_x_mint, pool_state.token_y_mint];
-    let mints = rpc_client.get_multiple_accounts(&mint_keys).await.unwrap();
-
-    for (key, account) in mint_keys.iter().zip(mints) {
-        test.add_account(*key, account.unwrap());
-    }
-
-    let reserve_keys = vec![pool_state.reserve_x, pool_state.reserve_y];
-
-    let tokens = rpc_client
-        .get_multiple_accounts(&reserve_keys)
-        .await
-        .unwrap();
 
-    for (key, account) in reserve_keys.into_iter().zip(tokens) {
-        test.add_account(key, account.unwrap());
+    for key in [
+        pool_state.token_x_mint,
+        pool_state.token_y_mint,
+        pool_state.reserve_x,
+        pool_state.reserve_y,
+    ] {
+        add_cluster_account(test, key).await;
     }
 
     test.add_account(
@@ -1,15 +1,35 @@
 #![allow(dead_code)]
-use super::dynamic_vault_pda::derive_vault_key;
-use super::{utils::add_packable_account, RPC};
-use anchor_lang::AccountDeserialize;
+use super::fixtures::add_cluster_account;
+use super::utils::add_packable_account;
+use super::{get_token_balance, process_and_assert_ok, setup_cpi_example_program, JUP, USDC};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{
+    AccountDeserialize, AnchorDeserialize, Discriminator, InstructionData, ToAccountMetas,
+};
 use anchor_spl::{
     associated_token::get_associated_token_address, token::spl_token::state::AccountState,
 };
+use assert_matches::assert_matches;
+use base64::{prelude::BASE64_STANDARD, Engine};
+use cpi_example::dynamic_amm;
 use cpi_example::dynamic_amm::accounts::Pool;
+use cpi_example::dynamic_amm::events::PoolInfo;
+use cpi_example::dynamic_amm::types::{CurveType, CustomizableParams};
 use cpi_example::dynamic_vault::accounts::Vault;
-use solana_client::nonblocking::rpc_client::RpcClient;
-use solana_program_test::ProgramTest;
-use solana_sdk::{account::Account, pubkey::Pubkey};
+use cpi_example_client::dynamic_amm_pda::METAPLEX_PROGRAM_ID;
+use cpi_example_client::dynamic_vault_pda::derive_vault_key;
+use cpi_example_client::instructions::{
+    initialize_dynamic_amm_customizable_permissionless_pool, DynamicAmmPoolKeys,
+};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::{
+    account::Account,
+    compute_budget::ComputeBudgetInstruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
 
 pub struct VaultSetupContext {
     key: Pubkey,
@@ -18,9 +38,7 @@ pub struct VaultSetupContext {
 }
 
 pub async fn setup_pool_config_from_cluster(test: &mut ProgramTest, config: Pubkey) {
-    let rpc_client = RpcClient::new(RPC.to_owned());
-    let config_account = rpc_client.get_account(&config).await.unwrap();
-    test.add_account(config, config_account);
+    add_cluster_account(test, config).await;
 }
 
 pub async fn setup_vault_from_cluster(
@@ -28,29 +46,13 @@ pub async fn setup_vault_from_cluster(
     mint: Pubkey,
     mock_user: Pubkey,
 ) -> VaultSetupContext {
-    let rpc_client = RpcClient::new(RPC.to_owned());
-
     let vault_key = derive_vault_key(mint);
 
-    let vault_account = rpc_client.get_account(&vault_key).await.unwrap();
+    let vault_account = add_cluster_account(test, vault_key).await;
     let vault_state = Vault::try_deserialize(&mut vault_account.data.as_ref()).unwrap();
 
-    test.add_account(vault_key, vault_account);
-
-    let mint_keys = [mint, vault_state.lp_mint];
-
-    let accounts = rpc_client.get_multiple_accounts(&mint_keys).await.unwrap();
-
-    for (key, account) in mint_keys.iter().zip(accounts) {
-        test.add_account(*key, account.unwrap());
-    }
-
-    let token_keys = vec![vault_state.token_vault];
-
-    let accounts = rpc_client.get_multiple_accounts(&token_keys).await.unwrap();
-
-    for (key, account) in token_keys.iter().zip(accounts) {
-        test.add_account(*key, account.unwrap());
+    for key in [mint, vault_state.lp_mint, vault_state.token_vault] {
+        add_cluster_account(test, key).await;
     }
 
     test.add_account(
@@ -95,22 +97,15 @@ pub async fn setup_pool_from_cluster(
     pool: Pubkey,
     mock_user: Pubkey,
 ) -> PoolSetupContext {
-    let rpc_client = RpcClient::new(RPC.to_owned());
-
-    let pool_account = rpc_client.get_account(&pool).await.unwrap();
+    let pool_account = add_cluster_account(test, pool).await;
     let pool_state = Pool::try_deserialize(&mut pool_account.data.as_ref()).unwrap();
 
-    test.add_account(pool, pool_account);
-
-    let a_vault_account = rpc_client.get_account(&pool_state.a_vault).await.unwrap();
+    let a_vault_account = add_cluster_account(test, pool_state.a_vault).await;
     let a_vault_state = Vault::try_deserialize(&mut a_vault_account.data.as_ref()).unwrap();
 
-    let b_vault_account = rpc_client.get_account(&pool_state.b_vault).await.unwrap();
+    let b_vault_account = add_cluster_account(test, pool_state.b_vault).await;
     let b_vault_state = Vault::try_deserialize(&mut b_vault_account.data.as_ref()).unwrap();
 
-    test.add_account(pool_state.a_vault, a_vault_account);
-    test.add_account(pool_state.b_vault, b_vault_account);
-
     let mint_keys = vec![
         pool_state.token_a_mint,
         pool_state.token_b_mint,
@@ -119,10 +114,8 @@ pub async fn setup_pool_from_cluster(
         b_vault_state.lp_mint,
     ];
 
-    let accounts = rpc_client.get_multiple_accounts(&mint_keys).await.unwrap();
-
-    for (key, account) in mint_keys.iter().zip(accounts) {
-        test.add_account(*key, account.unwrap());
+    for key in mint_keys.iter() {
+        add_cluster_account(test, *key).await;
     }
 
     let token_keys = vec![
@@ -134,10 +127,8 @@ pub async fn setup_pool_from_cluster(
         b_vault_state.token_vault,
     ];
 
-    let accounts = rpc_client.get_multiple_accounts(&token_keys).await.unwrap();
-
-    for (key, account) in token_keys.iter().zip(accounts) {
-        test.add_account(*key, account.unwrap());
+    for key in token_keys {
+        add_cluster_account(test, key).await;
     }
 
     test.add_account(
@@ -175,3 +166,177 @@ pub async fn setup_pool_from_cluster(
         user_token_b: token_ata_key[1],
     }
 }
+
+/// Token amounts and virtual price of the pool, from the event of the get pool info instruction of Dynamic AMM
+pub async fn get_pool_info(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    pool_keys: &DynamicAmmPoolKeys,
+) -> PoolInfo {
+    let accounts = dynamic_amm::client::accounts::GetPoolInfo {
+        pool: pool_keys.pool,
+        lp_mint: pool_keys.lp_mint,
+        a_vault_lp: pool_keys.a_vault_lp,
+        b_vault_lp: pool_keys.b_vault_lp,
+        a_vault: pool_keys.a_vault,
+        b_vault: pool_keys.b_vault,
+        a_vault_lp_mint: pool_keys.a_vault_lp_mint,
+        b_vault_lp_mint: pool_keys.b_vault_lp_mint,
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: dynamic_amm::ID,
+        data: dynamic_amm::client::args::GetPoolInfo {}.data(),
+        accounts,
+    };
+
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer.pubkey()),
+        &[payer],
+        recent_blockhash,
+    );
+
+    let simulation = banks_client.simulate_transaction(tx).await.unwrap();
+    let logs = simulation.simulation_details.unwrap().logs;
+
+    // Dynamic AMM emits the event through the program logs
+    logs.iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .filter_map(|data| BASE64_STANDARD.decode(data).ok())
+        .find_map(|data| {
+            let mut event_data = data.strip_prefix(PoolInfo::DISCRIMINATOR)?;
+            PoolInfo::deserialize(&mut event_data).ok()
+        })
+        .expect("Missing pool info event")
+}
+
+pub const USDC_USDT_STABLE_POOL: Pubkey =
+    solana_sdk::pubkey!("32D4zRxNc1EssbJieVHfPhZM3rH6CzfUPrWUuWxD9prG");
+
+/// Token amount of each side deposited by the mock user upon pool creation
+pub const INITIAL_TOKEN_AMOUNT: u64 = 100_000_000;
+
+/// JUP-USDC constant product pool created by the mock user
+pub async fn setup_constant_product_pool() -> (BanksClient, Keypair, DynamicAmmPoolKeys) {
+    let mock_user = Keypair::new();
+
+    let mut test = setup_cpi_example_program();
+    test.prefer_bpf(true);
+
+    test.add_program("dynamic_amm", cpi_example::dynamic_amm::ID, None);
+    test.add_program("dynamic_vault", cpi_example::dynamic_vault::ID, None);
+    test.add_program("metaplex", METAPLEX_PROGRAM_ID, None);
+
+    setup_vault_from_cluster(&mut test, JUP, mock_user.pubkey()).await;
+    setup_vault_from_cluster(&mut test, USDC, mock_user.pubkey()).await;
+
+    let (mut banks_client, _, _) = test.start().await;
+
+    process_and_assert_ok(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            initialize_dynamic_amm_customizable_permissionless_pool(
+                JUP,
+                USDC,
+                mock_user.pubkey(),
+                INITIAL_TOKEN_AMOUNT,
+                INITIAL_TOKEN_AMOUNT,
+                CustomizableParams {
+                    trade_fee_numerator: 10_000,
+                    activation_point: None,
+                    has_alpha_vault: false,
+                    activation_type: 1,
+                    padding: [0u8; 90],
+                },
+            ),
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let pool_keys = DynamicAmmPoolKeys::derive_customizable_permissionless(JUP, USDC);
+
+    (banks_client, mock_user, pool_keys)
+}
+
+/// USDC-USDT stable pool from the cluster. The mock user holds no LP of the pool.
+pub async fn setup_stable_pool() -> (BanksClient, Keypair, DynamicAmmPoolKeys) {
+    let mock_user = Keypair::new();
+
+    let mut test = setup_cpi_example_program();
+    test.prefer_bpf(true);
+
+    test.add_program("dynamic_amm", cpi_example::dynamic_amm::ID, None);
+    test.add_program("dynamic_vault", cpi_example::dynamic_vault::ID, None);
+
+    let PoolSetupContext {
+        pool_state,
+        a_vault_state,
+        b_vault_state,
+        ..
+    } = setup_pool_from_cluster(&mut test, USDC_USDT_STABLE_POOL, mock_user.pubkey()).await;
+
+    assert_matches!(pool_state.curve_type, CurveType::Stable { .. });
+
+    let (mut banks_client, _, _) = test.start().await;
+
+    process_and_assert_ok(
+        &[create_associated_token_account_idempotent(
+            &mock_user.pubkey(),
+            &mock_user.pubkey(),
+            &pool_state.lp_mint,
+            &anchor_spl::token::ID,
+        )],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let pool_keys = DynamicAmmPoolKeys::new(
+        USDC_USDT_STABLE_POOL,
+        &pool_state,
+        &a_vault_state,
+        &b_vault_state,
+    );
+
+    (banks_client, mock_user, pool_keys)
+}
+
+/// Token and LP balances of the user
+pub struct UserBalances {
+    pub pool_lp: u64,
+    pub token_a: u64,
+    pub token_b: u64,
+}
+
+impl UserBalances {
+    pub async fn load(
+        banks_client: &mut BanksClient,
+        pool_keys: &DynamicAmmPoolKeys,
+        user: Pubkey,
+    ) -> Self {
+        Self {
+            pool_lp: get_token_balance(
+                banks_client,
+                get_associated_token_address(&user, &pool_keys.lp_mint),
+            )
+            .await,
+            token_a: get_token_balance(
+                banks_client,
+                get_associated_token_address(&user, &pool_keys.token_a_mint),
+            )
+            .await,
+            token_b: get_token_balance(
+                banks_client,
+                get_associated_token_address(&user, &pool_keys.token_b_mint),
+            )
+            .await,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use super::RPC;
+use serde::Deserialize;
+use solana_account_decoder::UiAccount;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_program_test::ProgramTest;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use std::path::PathBuf;
+
+/// Account fixture in the format of `solana account <PUBKEY> --output json`
+#[derive(Deserialize)]
+struct AccountFixture {
+    pubkey: String,
+    account: UiAccount,
+}
+
+/// Directory of the account fixtures. One `<PUBKEY>.json` file per account.
+pub fn account_fixtures_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/accounts")
+}
+
+/// Load the account snapshot of the key from the fixtures. Returns `None` when the account is not recorded.
+pub fn load_account_fixture(key: Pubkey) -> Option<Account> {
+    let path = account_fixtures_dir().join(format!("{key}.json"));
+    if !path.exists() {
+        return None;
+    }
+
+    let content = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("Unreadable account fixture {}: {err}", path.display()));
+
+    let fixture: AccountFixture = serde_json::from_str(&content)
+        .unwrap_or_else(|err| panic!("Invalid account fixture {}: {err}", path.display()));
+
+    assert_eq!(
+        fixture.pubkey,
+        key.to_string(),
+        "Account fixture {} belongs to another account",
+        path.display()
+    );
+
+    let account = fixture
+        .account
+        .decode()
+        .unwrap_or_else(|| panic!("Unsupported data encoding in {}", path.display()));
+
+    Some(account)
+}
+
+/// Install the cluster account of the key into the program test. The account is loaded from the fixtures when
+/// recorded, otherwise it is fetched from the RPC.
+pub async fn add_cluster_account(test: &mut ProgramTest, key: Pubkey) -> Account {
+    let account = match load_account_fixture(key) {
+        Some(account) => account,
+        None => RpcClient::new(RPC.to_owned())
+            .get_account(&key)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to fetch account {key}: {err}")),
+    };
+
+    test.add_account(key, account.clone());
+    account
+}
@@ -2,20 +2,19 @@ use anchor_lang::prelude::*;
 use solana_program_test::*;
 use solana_sdk::{entrypoint::ProgramResult, pubkey::Pubkey};
 
-pub mod dlmm_pda;
+pub mod dlmm_launch_pool_utils;
+pub mod dlmm_position_utils;
 pub mod dlmm_utils;
-pub mod dynamic_amm_ix_account_builder;
-pub mod dynamic_amm_pda;
 pub mod dynamic_amm_utils;
-pub mod dynamic_vault_pda;
-pub mod m3m3_pda;
-
-mod dynamic_amm_aux_lp_mint;
-mod dynamic_vault_aux_lp_mint;
+pub mod fixtures;
 
 mod utils;
 
-pub use utils::process_and_assert_ok;
+pub use utils::{
+    add_packable_account, add_program_config, get_mint_supply, get_token_balance,
+    process_and_assert_err, process_and_assert_ok, process_and_get_return_data,
+    simulate_and_get_events,
+};
 const RPC: &str = "https://api.mainnet-beta.solana.com";
 
 pub const JUP: Pubkey = solana_sdk::pubkey!("JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN");
@@ -1,12 +1,17 @@
-use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::instruction::{Instruction, InstructionError};
 use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::{AccountDeserialize, AccountSerialize, AnchorDeserialize};
+use anchor_spl::token::{Mint, TokenAccount};
 use assert_matches::assert_matches;
-use solana_program_test::{BanksClient, ProgramTest};
+use cpi_example::state::ProgramConfig;
+use cpi_example_client::cpi_example_pda::derive_program_config;
+use cpi_example_client::events::{decode_events, CpiExampleEvent};
+use solana_program_test::{BanksClient, BanksClientError, ProgramTest};
 use solana_sdk::{
     account::Account,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
 
 pub async fn process_and_assert_ok(
@@ -30,6 +35,104 @@ pub async fn process_and_assert_ok(
     assert_matches!(banks_client.process_transaction(tx).await, Ok(()));
 }
 
+/// Process the transaction and decode the return data of the last instruction setting it
+pub async fn process_and_get_return_data<T: AnchorDeserialize>(
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+    banks_client: &mut BanksClient,
+) -> T {
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        &all_signers,
+        recent_blockhash,
+    );
+
+    let result = banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+    assert_matches!(result.result, Ok(()));
+
+    let return_data = result.metadata.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, cpi_example::ID);
+
+    T::try_from_slice(&return_data.data).unwrap()
+}
+
+/// Simulate the transaction and decode the events emitted by the CPI example program from the inner instructions
+pub async fn simulate_and_get_events(
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+    banks_client: &mut BanksClient,
+) -> Vec<CpiExampleEvent> {
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        &all_signers,
+        recent_blockhash,
+    );
+    let account_keys = tx.message.account_keys.clone();
+
+    let simulation = banks_client.simulate_transaction(tx).await.unwrap();
+    assert_matches!(simulation.result, Some(Ok(())));
+
+    let inner_instructions = simulation
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap();
+
+    decode_events(
+        &account_keys,
+        inner_instructions
+            .iter()
+            .flatten()
+            .map(|inner_instruction| &inner_instruction.instruction),
+    )
+}
+
+pub async fn process_and_assert_err(
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+    banks_client: &mut BanksClient,
+    error_code: impl Into<u32>,
+) {
+    let error_code = error_code.into();
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        &all_signers,
+        recent_blockhash,
+    );
+
+    assert_matches!(
+        banks_client.process_transaction(tx).await,
+        Err(BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code)
+        ))) if code == error_code
+    );
+}
+
 pub fn add_packable_account<T: Pack>(
     test: &mut ProgramTest,
     account: T,
@@ -49,3 +152,47 @@ pub fn add_packable_account<T: Pack>(
         },
     );
 }
+
+/// Install the program config with the admin, as if initialized by the upgrade authority
+pub fn add_program_config(test: &mut ProgramTest, admin: Pubkey) {
+    let (program_config, bump) = derive_program_config();
+
+    let state = ProgramConfig {
+        admin,
+        pending_admin: Pubkey::default(),
+        bump,
+    };
+
+    let mut data = vec![];
+    state.try_serialize(&mut data).unwrap();
+
+    test.add_account(
+        program_config,
+        Account {
+            lamports: u32::MAX.into(),
+            data,
+            owner: cpi_example::ID,
+            ..Default::default()
+        },
+    );
+}
+
+pub async fn get_token_balance(banks_client: &mut BanksClient, token_account: Pubkey) -> u64 {
+    let account = banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap();
+
+    TokenAccount::try_deserialize(&mut account.data.as_ref())
+        .unwrap()
+        .amount
+}
+
+pub async fn get_mint_supply(banks_client: &mut BanksClient, mint: Pubkey) -> u64 {
+    let account = banks_client.get_account(mint).await.unwrap().unwrap();
+
+    Mint::try_deserialize(&mut account.data.as_ref())
+        .unwrap()
+        .supply
+}
@@ -1,8 +1,22 @@
 mod helpers;
 
+mod dlmm_add_liquidity_one_side;
+mod dlmm_claim_fee;
+mod dlmm_creator_authority;
+mod dlmm_initialize_lb_pair;
+mod dlmm_position;
 mod dlmm_swap;
+mod dlmm_swap_exact_out;
+mod dlmm_swap_with_price_impact;
+mod dynamic_amm_balance_liquidity;
 mod dynamic_amm_claim_fee;
+mod dynamic_amm_imbalance_liquidity;
 mod dynamic_amm_init_pool;
 mod dynamic_amm_lock_liquidity;
 mod dynamic_amm_swap;
+mod dynamic_amm_zap_in;
+mod dynamic_amm_zap_out;
+mod events;
 mod m3m3_initialize_vault;
+mod program_config;
+mod sweep_creator_authority;
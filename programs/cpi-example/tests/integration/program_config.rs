@@ -0,0 +1,190 @@
+use crate::helpers;
+use anchor_lang::solana_program::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use anchor_lang::AccountDeserialize;
+use cpi_example::errors::CpiExampleError;
+use cpi_example::state::ProgramConfig;
+use cpi_example_client::cpi_example_pda::derive_program_config;
+use cpi_example_client::instructions::*;
+use helpers::dlmm_launch_pool_utils::setup_launch_pool;
+use helpers::{
+    add_program_config, process_and_assert_err, process_and_assert_ok, setup_cpi_example_program,
+};
+use solana_program_test::*;
+use solana_sdk::{account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+fn add_funded_account(test: &mut ProgramTest, key: Pubkey) {
+    test.add_account(
+        key,
+        Account {
+            lamports: u32::MAX.into(),
+            data: vec![],
+            owner: solana_sdk::system_program::ID,
+            ..Default::default()
+        },
+    );
+}
+
+/// Install the program data account of the CPI example program, as deployed by the upgradeable loader
+fn add_program_data(test: &mut ProgramTest, upgrade_authority: Pubkey) {
+    let state = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(upgrade_authority),
+    };
+
+    test.add_account(
+        bpf_loader_upgradeable::get_program_data_address(&cpi_example::ID),
+        Account {
+            lamports: u32::MAX.into(),
+            data: bincode::serialize(&state).unwrap(),
+            owner: bpf_loader_upgradeable::ID,
+            ..Default::default()
+        },
+    );
+}
+
+async fn get_program_config(banks_client: &mut BanksClient) -> ProgramConfig {
+    let account = banks_client
+        .get_account(derive_program_config().0)
+        .await
+        .unwrap()
+        .unwrap();
+
+    ProgramConfig::try_deserialize(&mut account.data.as_ref()).unwrap()
+}
+
+#[tokio::test]
+async fn test_initialize_config() {
+    let upgrade_authority = Keypair::new();
+    let admin = Pubkey::new_unique();
+
+    let mut test = setup_cpi_example_program();
+    add_funded_account(&mut test, upgrade_authority.pubkey());
+    add_program_data(&mut test, upgrade_authority.pubkey());
+
+    let (mut banks_client, _, _) = test.start().await;
+
+    process_and_assert_ok(
+        &[initialize_config(upgrade_authority.pubkey(), admin)],
+        &upgrade_authority,
+        &[&upgrade_authority],
+        &mut banks_client,
+    )
+    .await;
+
+    let program_config = get_program_config(&mut banks_client).await;
+    assert_eq!(program_config.admin, admin);
+    assert_eq!(program_config.pending_admin, Pubkey::default());
+    assert_eq!(program_config.bump, derive_program_config().1);
+}
+
+#[tokio::test]
+async fn test_initialize_config_invalid_upgrade_authority() {
+    let upgrade_authority = Keypair::new();
+    let mock_user = Keypair::new();
+
+    let mut test = setup_cpi_example_program();
+    add_funded_account(&mut test, mock_user.pubkey());
+    add_program_data(&mut test, upgrade_authority.pubkey());
+
+    let (mut banks_client, _, _) = test.start().await;
+
+    process_and_assert_err(
+        &[initialize_config(mock_user.pubkey(), mock_user.pubkey())],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        CpiExampleError::InvalidUpgradeAuthority,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_transfer_admin() {
+    let admin = Keypair::new();
+    let new_admin = Keypair::new();
+    let mock_user = Keypair::new();
+
+    let mut test = setup_cpi_example_program();
+    add_program_config(&mut test, admin.pubkey());
+    for key in [admin.pubkey(), new_admin.pubkey(), mock_user.pubkey()] {
+        add_funded_account(&mut test, key);
+    }
+
+    let (mut banks_client, _, _) = test.start().await;
+
+    // Only the admin can propose
+    process_and_assert_err(
+        &[propose_admin(mock_user.pubkey(), mock_user.pubkey())],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        CpiExampleError::InvalidAdmin,
+    )
+    .await;
+
+    process_and_assert_ok(
+        &[propose_admin(admin.pubkey(), new_admin.pubkey())],
+        &admin,
+        &[&admin],
+        &mut banks_client,
+    )
+    .await;
+
+    let program_config = get_program_config(&mut banks_client).await;
+    assert_eq!(program_config.admin, admin.pubkey());
+    assert_eq!(program_config.pending_admin, new_admin.pubkey());
+
+    // Only the pending admin can accept
+    process_and_assert_err(
+        &[accept_admin(mock_user.pubkey())],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        CpiExampleError::InvalidPendingAdmin,
+    )
+    .await;
+
+    process_and_assert_ok(
+        &[accept_admin(new_admin.pubkey())],
+        &new_admin,
+        &[&new_admin],
+        &mut banks_client,
+    )
+    .await;
+
+    let program_config = get_program_config(&mut banks_client).await;
+    assert_eq!(program_config.admin, new_admin.pubkey());
+    assert_eq!(program_config.pending_admin, Pubkey::default());
+
+    // The previous admin lost its permission
+    process_and_assert_err(
+        &[propose_admin(admin.pubkey(), admin.pubkey())],
+        &admin,
+        &[&admin],
+        &mut banks_client,
+        CpiExampleError::InvalidAdmin,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_pda_creator_instruction_invalid_admin() {
+    let (mut banks_client, mock_user, launch_pool_context) = setup_launch_pool(0, &[]).await;
+    let pool_keys = &launch_pool_context.pool_keys;
+
+    let position_keys =
+        DlmmPositionKeys::derive_pda_creator(pool_keys.lb_pair, mock_user.pubkey(), 0, 70);
+    let non_admin = Keypair::new();
+
+    process_and_assert_err(
+        &[dlmm_initialize_position_pda_creator(
+            &position_keys,
+            non_admin.pubkey(),
+        )],
+        &mock_user,
+        &[&non_admin],
+        &mut banks_client,
+        CpiExampleError::InvalidAdmin,
+    )
+    .await;
+}
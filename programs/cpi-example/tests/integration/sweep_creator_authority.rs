@@ -0,0 +1,167 @@
+use crate::helpers;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use cpi_example::errors::CpiExampleError;
+use cpi_example_client::cpi_example_pda::derive_creator_authority;
+use cpi_example_client::instructions::*;
+use helpers::dlmm_launch_pool_utils::*;
+use helpers::{get_token_balance, process_and_assert_err, process_and_assert_ok};
+use solana_program_test::*;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction};
+
+const SWEPT_TOKEN_AMOUNT: u64 = 1_000_000;
+const SWEPT_LAMPORTS: u64 = 1_000_000_000;
+
+#[tokio::test]
+async fn test_sweep_creator_authority() {
+    let (mut banks_client, mock_user, launch_pool_context) = setup_launch_pool(0, &[]).await;
+    let pool_keys = &launch_pool_context.pool_keys;
+
+    let (creator_authority, _bump) =
+        derive_creator_authority(mock_user.pubkey(), pool_keys.lb_pair);
+    let creator_token_x = get_associated_token_address(&creator_authority, &pool_keys.token_x_mint);
+
+    // Leave some token X and SOL in the creator authority PDA
+    process_and_assert_ok(
+        &[
+            spl_token::instruction::transfer(
+                &spl_token::ID,
+                &launch_pool_context.user_token_x,
+                &creator_token_x,
+                &mock_user.pubkey(),
+                &[],
+                SWEPT_TOKEN_AMOUNT,
+            )
+            .unwrap(),
+            system_instruction::transfer(&mock_user.pubkey(), &creator_authority, SWEPT_LAMPORTS),
+        ],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let creator_token_x_balance = get_token_balance(&mut banks_client, creator_token_x).await;
+    let creator_token_x_rent = banks_client.get_balance(creator_token_x).await.unwrap();
+    let creator_authority_lamports = banks_client.get_balance(creator_authority).await.unwrap();
+    let user_token_x_before =
+        get_token_balance(&mut banks_client, launch_pool_context.user_token_x).await;
+
+    let destination = Pubkey::new_unique();
+
+    process_and_assert_ok(
+        &[sweep_creator_authority(
+            mock_user.pubkey(),
+            pool_keys.lb_pair,
+            mock_user.pubkey(),
+            destination,
+            Some((pool_keys.token_x_mint, launch_pool_context.user_token_x)),
+            true,
+        )],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+    )
+    .await;
+
+    let user_token_x_after =
+        get_token_balance(&mut banks_client, launch_pool_context.user_token_x).await;
+    assert_eq!(
+        user_token_x_after - user_token_x_before,
+        creator_token_x_balance
+    );
+
+    let creator_token_x_account = banks_client.get_account(creator_token_x).await.unwrap();
+    assert!(creator_token_x_account.is_none());
+
+    // The creator authority PDA keeps the rent exempt minimum
+    let rent_exempt_lamports = banks_client.get_rent().await.unwrap().minimum_balance(0);
+    assert_eq!(
+        banks_client.get_balance(creator_authority).await.unwrap(),
+        rent_exempt_lamports
+    );
+    assert_eq!(
+        banks_client.get_balance(destination).await.unwrap(),
+        creator_authority_lamports - rent_exempt_lamports + creator_token_x_rent
+    );
+}
+
+#[tokio::test]
+async fn test_sweep_creator_authority_invalid_admin() {
+    let (mut banks_client, mock_user, launch_pool_context) = setup_launch_pool(0, &[]).await;
+    let pool_keys = &launch_pool_context.pool_keys;
+
+    let non_admin = Keypair::new();
+
+    process_and_assert_err(
+        &[sweep_creator_authority(
+            mock_user.pubkey(),
+            pool_keys.lb_pair,
+            non_admin.pubkey(),
+            non_admin.pubkey(),
+            None,
+            false,
+        )],
+        &mock_user,
+        &[&mock_user, &non_admin],
+        &mut banks_client,
+        CpiExampleError::InvalidAdmin,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_sweep_creator_authority_missing_destination_token() {
+    let (mut banks_client, mock_user, launch_pool_context) = setup_launch_pool(0, &[]).await;
+    let pool_keys = &launch_pool_context.pool_keys;
+
+    let mut instruction = sweep_creator_authority(
+        mock_user.pubkey(),
+        pool_keys.lb_pair,
+        mock_user.pubkey(),
+        mock_user.pubkey(),
+        Some((pool_keys.token_x_mint, launch_pool_context.user_token_x)),
+        false,
+    );
+
+    // Omit the optional destination token account
+    let destination_token = instruction
+        .accounts
+        .iter_mut()
+        .find(|account| account.pubkey == launch_pool_context.user_token_x)
+        .unwrap();
+    destination_token.pubkey = cpi_example::ID;
+    destination_token.is_writable = false;
+
+    process_and_assert_err(
+        &[instruction],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        CpiExampleError::MissingSweepTokenAccount,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_sweep_creator_authority_invalid_destination_token() {
+    let (mut banks_client, mock_user, launch_pool_context) = setup_launch_pool(0, &[]).await;
+    let pool_keys = &launch_pool_context.pool_keys;
+
+    // Token Y account as the destination of the creator token X account
+    process_and_assert_err(
+        &[sweep_creator_authority(
+            mock_user.pubkey(),
+            pool_keys.lb_pair,
+            mock_user.pubkey(),
+            mock_user.pubkey(),
+            Some((pool_keys.token_x_mint, launch_pool_context.user_token_y)),
+            false,
+        )],
+        &mock_user,
+        &[&mock_user],
+        &mut banks_client,
+        CpiExampleError::InvalidSweepDestinationToken,
+    )
+    .await;
+}